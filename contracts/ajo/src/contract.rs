@@ -2,10 +2,23 @@ use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
 
 use crate::errors::AjoError;
 use crate::events;
+use crate::fees;
+use crate::history;
+use crate::obligations;
 use crate::pausable;
+use crate::rate_limits;
+use crate::renewal;
+use crate::security;
+use crate::statement;
 use crate::storage;
-use crate::types::{Group, GroupMetadata, GroupStatus};
+use crate::templates;
+use crate::types::{
+    ContractConfig, CycleSchedule, Group, GroupMetadata, GroupStatus, GroupTemplate, PauseFlag,
+    PauseState, RateLimits, TemplateOverrides, TemplateParams,
+};
 use crate::utils;
+use crate::validation;
+use crate::vault;
 
 /// The main Ajo contract
 #[contract]
@@ -32,6 +45,7 @@ impl AjoContract {
             return Err(AjoError::AlreadyInitialized);
         }
         storage::store_admin(&env, &admin);
+        events::emit_contract_initialized(&env, &admin);
         Ok(())
     }
 
@@ -52,14 +66,15 @@ impl AjoContract {
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), AjoError> {
         let admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
         admin.require_auth();
+        events::emit_contract_upgraded(&env, &admin, &new_wasm_hash);
         env.deployer().update_current_contract_wasm(new_wasm_hash);
         Ok(())
     }
 
     /// Pause the contract to prevent state-mutating operations.
     ///
-    /// This emergency function allows the admin to temporarily halt all state-mutating
-    /// operations (create_group, join_group, contribute, execute_payout) while keeping
+    /// This emergency function allows the admin to temporarily halt every class of
+    /// state-mutating operation (see [`pausable`](crate::pausable)) while keeping
     /// query functions and admin functions operational. This is useful during security
     /// incidents, detected vulnerabilities, or maintenance periods.
     ///
@@ -109,6 +124,341 @@ impl AjoContract {
         pausable::unpause(&env)
     }
 
+    /// Pause a single class of operations.
+    ///
+    /// Lets the admin isolate an incident in one area (for example insurance)
+    /// while the rest of the contract keeps working. See the
+    /// [`pausable`](crate::pausable) module for the entry points each flag blocks.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `flag` - The operation class to pause
+    ///
+    /// # Errors
+    /// * `UnauthorizedPause` - If the caller is not the admin
+    pub fn pause_operation(env: Env, flag: PauseFlag) -> Result<(), AjoError> {
+        pausable::pause_operation(&env, flag)
+    }
+
+    /// Unpause a single class of operations.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `flag` - The operation class to unpause
+    ///
+    /// # Errors
+    /// * `UnauthorizedUnpause` - If the caller is not the admin
+    pub fn unpause_operation(env: Env, flag: PauseFlag) -> Result<(), AjoError> {
+        pausable::unpause_operation(&env, flag)
+    }
+
+    /// Get the current pause switches for every operation class.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    ///
+    /// # Returns
+    /// A `PauseState` where `true` means that operation class is blocked
+    pub fn get_pause_state(env: Env) -> PauseState {
+        pausable::get_pause_state(&env)
+    }
+
+    /// Set the platform fee taken from every payout.
+    ///
+    /// Applies to all groups from their next payout. If a group's organizer fee
+    /// would push the combined rate above 10%, the organizer fee is reduced.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `fee_bps` - The new platform fee in basis points
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `InvalidFee` - If fee_bps exceeds 10%
+    pub fn set_platform_fee(env: Env, fee_bps: u32) -> Result<(), AjoError> {
+        let admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        admin.require_auth();
+
+        fees::validate_fees(fee_bps, 0)?;
+        storage::set_platform_fee_bps(&env, fee_bps);
+        events::emit_platform_fee_set(&env, fee_bps);
+
+        Ok(())
+    }
+
+    /// Get the platform fee taken from every payout, in basis points.
+    pub fn get_platform_fee(env: Env) -> u32 {
+        storage::get_platform_fee_bps(&env)
+    }
+
+    /// Appoint the fee manager allowed to withdraw platform fees.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `manager` - The new fee manager
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If the caller is not the admin
+    pub fn set_fee_manager(env: Env, manager: Address) -> Result<(), AjoError> {
+        let admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        admin.require_auth();
+
+        storage::set_fee_manager(&env, &manager);
+        events::emit_fee_manager_set(&env, &manager);
+
+        Ok(())
+    }
+
+    /// Get the fee manager, which is the admin until another address is appointed.
+    ///
+    /// # Returns
+    /// `None` if the contract has not been initialized
+    pub fn get_fee_manager(env: Env) -> Option<Address> {
+        fees::get_fee_manager(&env)
+    }
+
+    /// Set the keeper reward paid for payouts executed through `poke`.
+    ///
+    /// The reward is a share of the platform fee taken from the payout, so it is
+    /// only paid while a platform fee is set.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `reward_bps` - The keeper's share of the platform fee, in basis points
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `InvalidFee` - If reward_bps exceeds 100%
+    pub fn set_keeper_reward(env: Env, reward_bps: u32) -> Result<(), AjoError> {
+        let admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        admin.require_auth();
+
+        if reward_bps > 10_000 {
+            return Err(AjoError::InvalidFee);
+        }
+        storage::set_keeper_reward_bps(&env, reward_bps);
+        events::emit_keeper_reward_set(&env, reward_bps);
+
+        Ok(())
+    }
+
+    /// Get the keeper's share of the platform fee, in basis points.
+    pub fn get_keeper_reward(env: Env) -> u32 {
+        storage::get_keeper_reward_bps(&env)
+    }
+
+    /// Add a token to the registry, or update the limits of a registered token.
+    ///
+    /// Only registered tokens can be used to create groups. The token's symbol
+    /// and decimals are read from the token contract, which also checks that the
+    /// address implements the SEP-41 token interface.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `token` - Address of the token contract
+    /// * `min_contribution` - Smallest contribution amount allowed, in the token's units
+    /// * `max_contribution` - Largest contribution amount allowed, in the token's units
+    ///
+    /// # Returns
+    /// The stored [`TokenInfo`](crate::types::TokenInfo)
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `ContributionOutOfRange` - If min_contribution <= 0 or max_contribution < min_contribution
+    /// * `InvalidTokenAddress` - If the address does not answer the SEP-41 interface
+    pub fn add_supported_token(
+        env: Env,
+        token: Address,
+        min_contribution: i128,
+        max_contribution: i128,
+    ) -> Result<crate::types::TokenInfo, AjoError> {
+        let admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        admin.require_auth();
+
+        if min_contribution <= 0 || max_contribution < min_contribution {
+            return Err(AjoError::ContributionOutOfRange);
+        }
+
+        let (symbol, decimals) = crate::token::read_token_metadata(&env, &token)?;
+        let info = crate::types::TokenInfo {
+            address: token,
+            symbol,
+            decimals,
+            min_contribution,
+            max_contribution,
+        };
+        storage::store_supported_token(&env, &info);
+        events::emit_token_supported(&env, &info);
+
+        Ok(info)
+    }
+
+    /// Remove a token from the registry.
+    ///
+    /// Existing groups using the token are unaffected; only new groups are blocked.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `token` - Address of the token contract
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `InvalidTokenAddress` - If the token is not registered
+    pub fn remove_supported_token(env: Env, token: Address) -> Result<(), AjoError> {
+        let admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        admin.require_auth();
+
+        if storage::get_supported_token(&env, &token).is_none() {
+            return Err(AjoError::InvalidTokenAddress);
+        }
+        storage::remove_supported_token(&env, &token);
+        events::emit_token_removed(&env, &token);
+
+        Ok(())
+    }
+
+    /// Get the registry entry for a token.
+    ///
+    /// # Returns
+    /// `None` if the token is not registered
+    pub fn get_supported_token(env: Env, token: Address) -> Option<crate::types::TokenInfo> {
+        storage::get_supported_token(&env, &token)
+    }
+
+    /// List every registered token, in registration order.
+    pub fn list_supported_tokens(env: Env) -> Vec<crate::types::TokenInfo> {
+        let mut tokens = Vec::new(&env);
+        for token in storage::get_supported_token_list(&env).iter() {
+            if let Some(info) = storage::get_supported_token(&env, &token) {
+                tokens.push_back(info);
+            }
+        }
+        tokens
+    }
+
+    /// Approve or revoke a vault contract for parking group funds.
+    ///
+    /// Revoking approval stops organizers from selecting the vault; groups
+    /// already using it keep it until their organizers change it.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `vault` - Address of a contract implementing [`VaultInterface`](crate::vault::VaultInterface)
+    /// * `approved` - `true` to approve, `false` to revoke
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If the caller is not the admin
+    pub fn set_vault_approved(env: Env, vault: Address, approved: bool) -> Result<(), AjoError> {
+        let admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        admin.require_auth();
+
+        storage::set_vault_approved(&env, &vault, approved);
+        events::emit_vault_approval(&env, &vault, approved);
+
+        Ok(())
+    }
+
+    /// Check whether the admin has approved a vault.
+    pub fn is_vault_approved(env: Env, vault: Address) -> bool {
+        storage::is_vault_approved(&env, &vault)
+    }
+
+    /// Replace the validation limits on group parameters, metadata and refund voting.
+    ///
+    /// The bounds are checked against each other and against the contract's
+    /// hard limits before the config is stored. See the
+    /// [`validation`](crate::validation) module for details.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `config` - The new limits
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * Any error [`check_config`](crate::validation::check_config) returns for
+    ///   inconsistent bounds
+    pub fn set_config(env: Env, config: ContractConfig) -> Result<(), AjoError> {
+        let admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        admin.require_auth();
+
+        validation::check_config(&config)?;
+        storage::set_config(&env, &config);
+        events::emit_config_updated(&env, &config);
+
+        Ok(())
+    }
+
+    /// Get the validation limits on group parameters, metadata and refund voting.
+    pub fn get_config(env: Env) -> ContractConfig {
+        validation::get_config(&env)
+    }
+
+    /// Set the anti-spam limits on creating and joining groups.
+    ///
+    /// A limit of `0` is not enforced. Tightened limits apply to new groups and
+    /// joins only; nobody is removed from groups they are already in. See the
+    /// [`rate_limits`](crate::rate_limits) module for details.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `limits` - The new limits
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If the caller is not the admin
+    pub fn set_rate_limits(env: Env, limits: RateLimits) -> Result<(), AjoError> {
+        let admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        admin.require_auth();
+
+        storage::set_rate_limits(&env, &limits);
+        events::emit_rate_limits_set(&env, &limits);
+
+        Ok(())
+    }
+
+    /// Get the anti-spam limits on creating and joining groups.
+    pub fn get_rate_limits(env: Env) -> RateLimits {
+        storage::get_rate_limits(&env)
+    }
+
+    /// Set the most a member may still have to pay in across their running groups
+    /// in a token.
+    ///
+    /// A group counts its per-cycle contribution, for all of the member's seats,
+    /// for every cycle left to pay out if it fills to `max_members`, less the
+    /// current one once it is paid.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `token` - Address of the token contract
+    /// * `limit` - The largest outstanding total in the token's units; `0` removes the limit
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `ContributionOutOfRange` - If limit is negative
+    pub fn set_exposure_limit(env: Env, token: Address, limit: i128) -> Result<(), AjoError> {
+        let admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        admin.require_auth();
+
+        if limit < 0 {
+            return Err(AjoError::ContributionOutOfRange);
+        }
+        storage::set_exposure_limit(&env, &token, limit);
+        events::emit_exposure_limit_set(&env, &token, limit);
+
+        Ok(())
+    }
+
+    /// Get the per-member exposure limit for a token, or `0` if none is set.
+    pub fn get_exposure_limit(env: Env, token: Address) -> i128 {
+        storage::get_exposure_limit(&env, &token)
+    }
+
+    /// Get what a member may still have to pay in, for all of their seats, across their
+    /// running groups in a token.
+    pub fn get_member_exposure(env: Env, member: Address, token: Address) -> i128 {
+        rate_limits::exposure(&env, &member, &token)
+    }
+
     /// Create a new Ajo group.
     ///
     /// Initializes a new rotating savings group with the specified parameters.
@@ -120,10 +470,14 @@ impl AjoContract {
     /// * `creator` - Address of the group creator (automatically becomes first member)
     /// * `token_address` - Address of the token contract (SAC) for contributions and payouts
     /// * `contribution_amount` - Fixed amount each member contributes per cycle (in token units, must be > 0)
-    /// * `cycle_duration` - Duration of each cycle in seconds (must be > 0)
-    /// * `max_members` - Maximum number of members allowed in the group (must be >= 2 and <= 100)
+    /// * `schedule` - When each cycle ends: a fixed duration in seconds (must be > 0),
+    ///   or a weekly or monthly calendar schedule
+    /// * `max_members` - Maximum number of members allowed in the group, within the
+    ///   configured bounds (2 to 100 by default)
     /// * `grace_period` - Grace period duration in seconds after cycle ends (default: 86400 = 24 hours)
     /// * `penalty_rate` - Penalty rate as percentage for late contributions (0-100, default: 5)
+    /// * `insurance_rate_bps` - Share of each contribution paid into the insurance pool, in basis points
+    /// * `organizer_fee_bps` - Fee paid to the creator from each payout, in basis points
     ///
     /// # Returns
     /// The unique group ID assigned to the new group
@@ -131,28 +485,55 @@ impl AjoContract {
     /// # Errors
     /// * `ContributionAmountZero` - If contribution_amount == 0
     /// * `ContributionAmountNegative` - If contribution_amount < 0
-    /// * `CycleDurationZero` - If cycle_duration == 0
-    /// * `MaxMembersBelowMinimum` - If max_members < 2
-    /// * `MaxMembersAboveLimit` - If max_members > 100
-    /// * `InvalidGracePeriod` - If grace_period > 7 days
-    /// * `InvalidPenaltyRate` - If penalty_rate > 100
+    /// * `CycleDurationZero` - If the schedule's nominal cycle length is outside the
+    ///   configured bounds
+    /// * `InvalidSchedule` - If a calendar schedule's fields are out of range
+    /// * `MaxMembersBelowMinimum` - If max_members is below the configured minimum
+    /// * `MaxMembersAboveLimit` - If max_members is above the configured maximum
+    /// * `InvalidGracePeriod` - If grace_period exceeds the configured maximum, or
+    ///   is not shorter than one cycle
+    /// * `InvalidPenaltyRate` - If penalty_rate exceeds the configured maximum
+    /// * `InvalidFee` - If organizer_fee_bps plus the platform fee exceeds 10%
+    /// * `InvalidTokenAddress` - If the token is not registered or no longer answers
+    ///   the SEP-41 interface
+    /// * `ContributionOutOfRange` - If contribution_amount is outside the token's bounds
+    /// * `LimitExceeded` - If the creator has created too many groups recently,
+    ///   already runs or is already in too many groups, or their outstanding
+    ///   contributions would exceed the token's exposure limit
     pub fn create_group(
         env: Env,
         creator: Address,
         token_address: Address,
         contribution_amount: i128,
-        cycle_duration: u64,
+        schedule: CycleSchedule,
         max_members: u32,
         grace_period: u64,
         penalty_rate: u32,
         insurance_rate_bps: u32,
+        organizer_fee_bps: u32,
     ) -> Result<u64, AjoError> {
+        // Check if paused
+        pausable::ensure_not_paused(&env, PauseFlag::GroupCreation)?;
+
         // Validate parameters
-        utils::validate_group_params(contribution_amount, cycle_duration, max_members)?;
-        utils::validate_penalty_params(grace_period, penalty_rate)?;
+        let config = validation::get_config(&env);
+        let cycle_duration = utils::validate_schedule(&schedule)?;
+        validation::validate_group_params(
+            &config,
+            contribution_amount,
+            cycle_duration,
+            max_members,
+        )?;
+        validation::validate_penalty_params(
+            &config,
+            utils::shortest_cycle_duration(&schedule),
+            grace_period,
+            penalty_rate,
+        )?;
+        fees::validate_fees(storage::get_platform_fee_bps(&env), organizer_fee_bps)?;
 
-        // Check if paused
-        pausable::ensure_not_paused(&env)?;
+        // Only registered, working tokens within their contribution bounds
+        Self::validate_token(&env, &token_address, contribution_amount)?;
 
         // Require authentication
         creator.require_auth();
@@ -160,22 +541,19 @@ impl AjoContract {
         // Generate new group ID
         let group_id = storage::get_next_group_id(&env);
 
-        // Initialize members list with creator
-        let mut members = Vec::new(&env);
-        members.push_back(creator.clone());
-
         // Get current timestamp
         let now = utils::get_current_timestamp(&env);
 
         // Create group
-        let group = Group {
+        let mut group = Group {
             id: group_id,
             creator: creator.clone(),
             token_address,
             contribution_amount,
             cycle_duration,
             max_members,
-            members,
+            member_count: 0,
+            seat_count: 0,
             current_cycle: 1,
             payout_index: 0,
             created_at: now,
@@ -188,91 +566,287 @@ impl AjoContract {
                 rate_bps: insurance_rate_bps,
                 is_enabled: insurance_rate_bps > 0,
             },
+            organizer_fee_bps,
+            schedule,
         };
 
+        // The creator takes the first seat
+        storage::add_group_member(&env, &mut group, &creator, 1, 0);
+
+        // Enforce the creator's anti-spam limits
+        rate_limits::admit_creator(&env, &group)?;
+
         // Store group
         storage::store_group(&env, group_id, &group);
 
         // Emit event
-        events::emit_group_created(&env, group_id, &creator, contribution_amount, max_members);
+        events::emit_group_created(&env, &group);
 
         Ok(group_id)
     }
 
-    /// Get group information.
-    ///
-    /// Retrieves the complete group data including all members, cycle information,
-    /// and metadata.
+    /// Publish a group template for all organizers to use.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The unique group identifier
+    /// * `name` - Template name, also used as the name of groups created from it
+    /// * `params` - Parameters for groups created from the template
+    /// * `rules` - Default rules text for groups created from the template
     ///
     /// # Returns
-    /// The group data containing group configuration and current state
+    /// The new template's ID
     ///
     /// # Errors
-    /// * `GroupNotFound` - If the group does not exist
-    pub fn get_group(env: Env, group_id: u64) -> Result<Group, AjoError> {
-        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `MetadataTooLong` - If the name or rules exceed the metadata limits
+    /// * Any error [`AjoContract::create_group`] returns for invalid parameters
+    ///
+    /// # Authorization
+    /// Only the contract admin can call this function.
+    pub fn publish_template(
+        env: Env,
+        name: soroban_sdk::String,
+        params: TemplateParams,
+        rules: soroban_sdk::String,
+    ) -> Result<u64, AjoError> {
+        let admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        admin.require_auth();
+
+        Self::store_template(&env, None, name, params, rules)
     }
 
-    /// Get list of all members in a group.
+    /// Save a personal group template.
     ///
-    /// Returns the ordered list of all member addresses currently in the group.
-    /// Members are ordered by join time, with the creator being the first member.
+    /// Saved templates are listed alongside the published ones and anyone may
+    /// create groups from them.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The unique group identifier
+    /// * `owner` - Address saving the template (must authenticate)
+    /// * `name` - Template name, also used as the name of groups created from it
+    /// * `params` - Parameters for groups created from the template
+    /// * `rules` - Default rules text for groups created from the template
     ///
     /// # Returns
-    /// Vector of member addresses in join order
+    /// The new template's ID
     ///
     /// # Errors
-    /// * `GroupNotFound` - If the group does not exist
-    pub fn list_members(env: Env, group_id: u64) -> Result<Vec<Address>, AjoError> {
-        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-        Ok(group.members)
+    /// * `MetadataTooLong` - If the name or rules exceed the metadata limits
+    /// * Any error [`AjoContract::create_group`] returns for invalid parameters
+    pub fn save_template(
+        env: Env,
+        owner: Address,
+        name: soroban_sdk::String,
+        params: TemplateParams,
+        rules: soroban_sdk::String,
+    ) -> Result<u64, AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::GroupCreation)?;
+        owner.require_auth();
+
+        Self::store_template(&env, Some(owner), name, params, rules)
     }
 
-    /// Join an existing group.
+    /// Remove a group template.
     ///
-    /// Adds a new member to an active group if space is available.
-    /// The member's authentication is required. The member cannot join if they
-    /// are already a member, the group is full, or the group has completed all cycles.
+    /// Groups already created from the template are not affected.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `member` - Address of the member joining (must authenticate)
-    /// * `group_id` - The group to join
-    ///
-    /// # Returns
-    /// `Ok(())` on successful group join
+    /// * `caller` - The template's owner or the admin (must authenticate)
+    /// * `template_id` - The template to remove
     ///
     /// # Errors
-    /// * `GroupNotFound` - If the group does not exist
-    /// * `MaxMembersExceeded` - If the group has reached max members
-    /// * `AlreadyMember` - If the address is already a member
-    /// * `GroupComplete` - If the group has completed all cycles
-    pub fn join_group(env: Env, member: Address, group_id: u64) -> Result<(), AjoError> {
-        // Check if paused
-        pausable::ensure_not_paused(&env)?;
-
-        // Require authentication
-        member.require_auth();
+    /// * `TemplateNotFound` - If the template does not exist
+    /// * `Unauthorized` - If the caller neither owns the template nor is the admin
+    pub fn remove_template(env: Env, caller: Address, template_id: u64) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::GroupCreation)?;
+        caller.require_auth();
+
+        let template =
+            storage::get_template(&env, template_id).ok_or(AjoError::TemplateNotFound)?;
+        let is_admin = storage::get_admin(&env) == Some(caller.clone());
+        if template.owner != Some(caller) && !is_admin {
+            return Err(AjoError::Unauthorized);
+        }
 
-        // Get group
-        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        storage::remove_template(&env, template_id);
+        events::emit_template_removed(&env, template_id);
 
-        // Cache member count for comparisons
-        let member_count = group.members.len() as u32;
-        let max_members = group.max_members;
+        Ok(())
+    }
 
-        // Check if group is complete
-        if group.is_complete {
-            return Err(AjoError::GroupComplete);
-        }
+    /// Get a group template.
+    ///
+    /// # Errors
+    /// * `TemplateNotFound` - If the template does not exist
+    pub fn get_template(env: Env, template_id: u64) -> Result<GroupTemplate, AjoError> {
+        storage::get_template(&env, template_id).ok_or(AjoError::TemplateNotFound)
+    }
+
+    /// List published and saved group templates in ID order.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `start_id` - The first template ID to include
+    /// * `limit` - Maximum number of templates to return, capped at
+    ///   [`MAX_PAGE_SIZE`](crate::security::limits::MAX_PAGE_SIZE)
+    ///
+    /// # Returns
+    /// Templates with IDs from `start_id` on; removed templates are skipped
+    pub fn list_templates(env: Env, start_id: u64, limit: u32) -> Vec<GroupTemplate> {
+        templates::list(&env, start_id, limit)
+    }
+
+    /// Create a group from a template.
+    ///
+    /// The template's parameters are used, replacing any set in `overrides`, and
+    /// the group is created and validated exactly as by
+    /// [`AjoContract::create_group`]. The template's name and rules become the
+    /// group's metadata.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - Address of the group creator (must authenticate)
+    /// * `template_id` - The template to create the group from
+    /// * `overrides` - Template parameters to replace for this group
+    ///
+    /// # Returns
+    /// The unique group ID
+    ///
+    /// # Errors
+    /// * `TemplateNotFound` - If the template does not exist
+    /// * Any error [`AjoContract::create_group`] returns
+    pub fn create_group_from_template(
+        env: Env,
+        creator: Address,
+        template_id: u64,
+        overrides: TemplateOverrides,
+    ) -> Result<u64, AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::GroupCreation)?;
+
+        let template =
+            storage::get_template(&env, template_id).ok_or(AjoError::TemplateNotFound)?;
+        let params = templates::apply(template.params, overrides);
+
+        let group_id = Self::create_group(
+            env.clone(),
+            creator,
+            params.token_address,
+            params.contribution_amount,
+            params.schedule,
+            params.max_members,
+            params.grace_period,
+            params.penalty_rate,
+            params.insurance_rate_bps,
+            params.organizer_fee_bps,
+        )?;
+
+        let metadata = GroupMetadata {
+            name: template.name,
+            description: soroban_sdk::String::from_str(&env, ""),
+            rules: template.rules,
+        };
+        storage::store_group_metadata(&env, group_id, &metadata);
+        events::emit_metadata_updated(&env, group_id, &metadata);
+
+        Ok(group_id)
+    }
+
+    /// Get group information.
+    ///
+    /// Retrieves the complete group data including all members, cycle information,
+    /// and metadata.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// The group data containing group configuration and current state
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_group(env: Env, group_id: u64) -> Result<Group, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)
+    }
+
+    /// Get list of all members in a group.
+    ///
+    /// Returns the ordered list of all member addresses currently in the group.
+    /// Members are ordered by join time, with the creator being the first member.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// Vector of member addresses in join order
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn list_members(env: Env, group_id: u64) -> Result<Vec<Address>, AjoError> {
+        if storage::get_group(&env, group_id).is_none() {
+            return Err(AjoError::GroupNotFound);
+        }
+        Ok(storage::get_group_members(&env, group_id))
+    }
+
+    /// Join an existing group.
+    ///
+    /// Adds a new member to an active group with one or more seats ("hands")
+    /// if space is available. Each seat contributes every cycle and receives one
+    /// payout; the member's seats take consecutive positions at the end of the
+    /// payout rotation. The member's authentication is required. The member cannot
+    /// join if they are already a member, the group lacks enough free seats, or the
+    /// group has completed all cycles.
+    ///
+    /// A group created by [`AjoContract::renew_group`] only admits the invited
+    /// previous members during its opt-in window, and their seats take their
+    /// place in the rotated payout order instead of going to the end.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - Address of the member joining (must authenticate)
+    /// * `group_id` - The group to join
+    /// * `seats` - Number of seats to take; at least 1
+    ///
+    /// # Returns
+    /// `Ok(())` on successful group join
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `NoMembers` - If `seats` is zero
+    /// * `MaxMembersExceeded` - If the group has fewer than `seats` free seats
+    /// * `AlreadyMember` - If the address is already a member
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupFrozen` - If the group has been frozen by its organizers
+    /// * `Unauthorized` - If the group is in its renewal opt-in window and the
+    ///   member was not invited
+    /// * `LimitExceeded` - If the member is already in too many groups, or their
+    ///   outstanding contributions in the group token would exceed its exposure limit
+    pub fn join_group(
+        env: Env,
+        member: Address,
+        group_id: u64,
+        seats: u32,
+    ) -> Result<(), AjoError> {
+        // Check if paused
+        pausable::ensure_not_paused(&env, PauseFlag::Joins)?;
+
+        // Require authentication
+        member.require_auth();
+
+        // Get group
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Cache seat count for comparisons
+        let seat_count = group.seat_count;
+        let max_members = group.max_members;
+
+        // Check if group is complete
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
 
         // Check if group is cancelled
         if group.state == crate::types::GroupState::Cancelled {
@@ -289,24 +863,39 @@ impl AjoContract {
             return Err(AjoError::GroupCancelled);
         }
 
+        // Check if group is frozen by its organizers
+        if group.state == crate::types::GroupState::Frozen {
+            return Err(AjoError::GroupFrozen);
+        }
+
         // Check if already a member
-        if utils::is_member(&group.members, &member) {
+        if storage::is_group_member(&env, group.id, &member) {
             return Err(AjoError::AlreadyMember);
         }
 
-        // Check if group is full
-        if member_count >= max_members {
+        // Check the requested seats fit in the group
+        if seats == 0 {
+            return Err(AjoError::NoMembers);
+        }
+        if seats > max_members.saturating_sub(seat_count) {
             return Err(AjoError::MaxMembersExceeded);
         }
 
-        // Add member
-        group.members.push_back(member.clone());
+        // A renewed group is reserved for its invitees during the opt-in window
+        let position = renewal::seat_position(&env, &group, &member)?;
+
+        // Add member and their seats
+        storage::add_group_member(&env, &mut group, &member, seats, position);
+
+        // Enforce the member's anti-spam limits
+        rate_limits::admit_member(&env, &member, &group, seats)?;
 
         // Update storage
         storage::store_group(&env, group_id, &group);
+        renewal::carry_reliability(&env, group_id, &member);
 
         // Emit event
-        events::emit_member_joined(&env, group_id, &member);
+        events::emit_member_joined(&env, group_id, &member, seats, position);
 
         Ok(())
     }
@@ -327,7 +916,7 @@ impl AjoContract {
     /// * `GroupNotFound` - If the group does not exist
     pub fn is_member(env: Env, group_id: u64, address: Address) -> Result<bool, AjoError> {
         let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-        Ok(utils::is_member(&group.members, &address))
+        Ok(storage::is_group_member(&env, group.id, &address))
     }
 
     /// Contribute to the current cycle.
@@ -336,8 +925,8 @@ impl AjoContract {
     /// the member to the contract. Each member can contribute once per cycle.
     /// Authentication is required.
     ///
-    /// The function transfers the contribution amount from the member's token balance
-    /// to the contract. Late contributions (after cycle ends but within grace period)
+    /// The function transfers the contribution amount for each of the member's seats
+    /// from the member's token balance to the contract. Late contributions (after cycle ends but within grace period)
     /// incur penalties. Contributions after grace period are rejected.
     ///
     /// # Arguments
@@ -353,1030 +942,3020 @@ impl AjoContract {
     /// * `NotMember` - If the address is not a member
     /// * `AlreadyContributed` - If already contributed this cycle
     /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupFrozen` - If the group has been frozen by its organizers
     /// * `GracePeriodExpired` - If contribution is too late (after grace period)
     /// * `InsufficientBalance` - If member doesn't have enough tokens
     /// * `TransferFailed` - If the token transfer fails
     pub fn contribute(env: Env, member: Address, group_id: u64) -> Result<(), AjoError> {
         // Check if paused
-        pausable::ensure_not_paused(&env)?;
+        pausable::ensure_not_paused(&env, PauseFlag::Contributions)?;
 
         // Require authentication
         member.require_auth();
 
-        // Get group (single fetch)
-        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Self::contribute_to(&env, &member, group_id)
+    }
 
-        // Cache frequently accessed values
-        let group_id_cached = group.id;
-        let current_cycle = group.current_cycle;
-        let contribution_amount = group.contribution_amount;
+    /// Contribute to the current cycle of several groups with one authorization.
+    ///
+    /// Works like [`AjoContract::contribute`] for each group in turn. A group
+    /// that rejects the contribution (for example `AlreadyContributed` or
+    /// `InsufficientBalance`) is reported in its result and does not stop the
+    /// others. A token that moves a different amount than requested aborts the
+    /// whole batch with `TransferFailed`.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - Address making the contributions (must authenticate)
+    /// * `group_ids` - The groups to contribute to, at most
+    ///   [`CONTRIBUTE_BATCH_SIZE`](crate::security::limits::CONTRIBUTE_BATCH_SIZE)
+    ///
+    /// # Returns
+    /// One [`BatchResult`](crate::types::BatchResult) per group, in the order given
+    ///
+    /// # Errors
+    /// * `LimitExceeded` - If more than `CONTRIBUTE_BATCH_SIZE` groups are given
+    /// * `TransferFailed` - If a group token moved a different amount than requested
+    pub fn contribute_many(
+        env: Env,
+        member: Address,
+        group_ids: Vec<u64>,
+    ) -> Result<Vec<crate::types::BatchResult>, AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::Contributions)?;
+        Self::ensure_batch_size(&group_ids, crate::security::limits::CONTRIBUTE_BATCH_SIZE)?;
+        member.require_auth();
 
-        // Check if group is complete
-        if group.is_complete {
-            return Err(AjoError::GroupComplete);
+        let mut results = Vec::new(&env);
+        for group_id in group_ids.iter() {
+            let result = Self::contribute_to(&env, &member, group_id);
+            results.push_back(Self::batch_result(group_id, result)?);
         }
 
-        // Check if group is cancelled
-        if group.state == crate::types::GroupState::Cancelled {
-            return Err(AjoError::GroupCancelled);
+        Ok(results)
+    }
+
+    /// Pay any amount toward the current and future cycles.
+    ///
+    /// The payment is held as credit for the member. As soon as the credit
+    /// covers the amount due for the current cycle (including the late penalty
+    /// inside the grace period), the cycle is marked as contributed. Smaller
+    /// payments act as installments and only count once the full amount is
+    /// reached; any excess carries forward and is applied automatically when
+    /// each following cycle opens.
+    ///
+    /// Prepayment is limited to the cycles of the seats taken so far, not
+    /// `max_members`: a group may start before it fills, and credit beyond its
+    /// last cycle would have nothing to settle. Members of a group that is
+    /// still filling can top up again as seats are taken.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - Address making the payment (must authenticate)
+    /// * `group_id` - The group to pay into
+    /// * `amount` - Amount to transfer, in token units
+    ///
+    /// # Returns
+    /// The member's remaining credit after the current cycle has been settled
+    ///
+    /// # Errors
+    /// * `ContributionAmountZero` - If amount == 0
+    /// * `ContributionAmountNegative` - If amount < 0
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `NotMember` - If the address is not a member
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupFrozen` - If the group has been frozen by its organizers
+    /// * `GracePeriodExpired` - If the current cycle is unpaid and its grace period is over
+    /// * `ContributionOutOfRange` - If the payment would exceed everything still
+    ///   owed for the remaining cycles of the rotation
+    /// * `InsufficientBalance` - If member doesn't have enough tokens
+    /// * `TransferFailed` - If the token transfer fails
+    pub fn contribute_amount(
+        env: Env,
+        member: Address,
+        group_id: u64,
+        amount: i128,
+    ) -> Result<i128, AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::Contributions)?;
+        member.require_auth();
+
+        if amount == 0 {
+            return Err(AjoError::ContributionAmountZero);
+        }
+        if amount < 0 {
+            return Err(AjoError::ContributionAmountNegative);
         }
 
-        // Check if member
-        if !utils::is_member(&group.members, &member) {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Self::ensure_accepting_contributions(&group)?;
+        if !storage::is_group_member(&env, group.id, &member) {
             return Err(AjoError::NotMember);
         }
 
-        // Check if already contributed
-        if storage::has_contributed(&env, group_id_cached, current_cycle, &member) {
-            return Err(AjoError::AlreadyContributed);
+        // Work out what is still owed for the seats taken so far
+        let now = utils::get_current_timestamp(&env);
+        let paid_current = storage::has_contributed(&env, group_id, group.current_cycle, &member);
+        let due_now = if paid_current {
+            0
+        } else {
+            if now > utils::get_grace_period_end(&group) {
+                return Err(AjoError::GracePeriodExpired);
+            }
+            utils::amount_due(&env, &group, &member, now)
+        };
+        let future_cycles = group.seat_count.saturating_sub(group.current_cycle);
+        let outstanding =
+            due_now + utils::cycle_contribution(&env, &group, &member) * future_cycles as i128;
+
+        let credit = storage::get_member_credit(&env, group_id, &member);
+        if credit + amount > outstanding {
+            return Err(AjoError::ContributionOutOfRange);
         }
 
-        // Get contract address for token transfer
+        // Move the payment into the contract and hold it as credit
         let contract_address = env.current_contract_address();
-
-        // Check member balance before transfer
-        crate::token::check_balance(&env, &group.token_address, &member, contribution_amount)?;
-
-        // Transfer tokens from member to contract
+        crate::token::check_balance(&env, &group.token_address, &member, amount)?;
         crate::token::transfer_token(
             &env,
             &group.token_address,
             &member,
             &contract_address,
-            contribution_amount,
+            amount,
         )?;
+        storage::set_member_credit(&env, group_id, &member, credit + amount);
+        events::emit_credit_deposited(&env, group_id, &member, amount, credit + amount);
 
-        // Record contribution
-        storage::store_contribution(&env, group_id_cached, current_cycle, &member, true);
-
-        // Insurance logic: Deduct premium if enabled
-        if group.insurance_config.is_enabled {
-            let premium = crate::insurance::calculate_premium(contribution_amount, group.insurance_config.rate_bps);
-            if premium > 0 {
-                crate::insurance::deposit_to_pool(&env, &group.token_address, premium);
-            }
+        // Settle the current cycle once the credit covers it
+        if !paid_current && credit + amount >= due_now {
+            Self::collect_contribution(&env, &group, &member, false)?;
         }
 
-        // Emit event
-        events::emit_contribution_made(
-            &env,
-            group_id_cached,
-            &member,
-            current_cycle,
-            contribution_amount,
-        );
+        // Queue credit covering a whole cycle for roll-over at the next payout
+        let remaining = storage::get_member_credit(&env, group_id, &member);
+        if remaining >= utils::cycle_contribution(&env, &group, &member) {
+            storage::add_prepaid_member(&env, group_id, &member);
+        }
 
-        Ok(())
+        Ok(remaining)
     }
 
-    /// Get contribution status for all members in a specific cycle.
+    /// Get the unapplied credit a member holds in a group.
     ///
-    /// Returns an ordered list of all members paired with their contribution status
-    /// for the specified cycle. Member order matches the group's member list order.
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group to check
+    /// * `member` - The member's address
+    ///
+    /// # Returns
+    /// Installments toward the current cycle plus prepayments for future cycles
+    pub fn get_member_credit(env: Env, group_id: u64, member: Address) -> i128 {
+        storage::get_member_credit(&env, group_id, &member)
+    }
+
+    /// Get a member's payment progress for the current cycle.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
     /// * `group_id` - The group to check
-    /// * `cycle_number` - The cycle to check (typically use current_cycle from group)
+    /// * `member` - The member's address
     ///
     /// # Returns
-    /// Vector of (Address, bool) tuples where `true` indicates the member has contributed
+    /// The member's [`ContributionProgress`](crate::types::ContributionProgress)
     ///
     /// # Errors
     /// * `GroupNotFound` - If the group does not exist
-    pub fn get_contribution_status(
+    /// * `NotMember` - If the address is not a member
+    pub fn get_contribution_progress(
         env: Env,
         group_id: u64,
-        cycle_number: u32,
-    ) -> Result<Vec<(Address, bool)>, AjoError> {
+        member: Address,
+    ) -> Result<crate::types::ContributionProgress, AjoError> {
         let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-        Ok(storage::get_cycle_contributions(
-            &env,
-            group_id,
-            cycle_number,
-            &group.members,
-        ))
+        if !storage::is_group_member(&env, group.id, &member) {
+            return Err(AjoError::NotMember);
+        }
+
+        let contributed = storage::has_contributed(&env, group_id, group.current_cycle, &member);
+        let credit = storage::get_member_credit(&env, group_id, &member);
+
+        // Credit first settles the current cycle; the rest prepays future ones
+        let due_now = if contributed {
+            0
+        } else {
+            utils::amount_due(&env, &group, &member, utils::get_current_timestamp(&env))
+        };
+        let amount_due = (due_now - credit).max(0);
+        let surplus = (credit - due_now).max(0);
+
+        Ok(crate::types::ContributionProgress {
+            cycle: group.current_cycle,
+            contributed,
+            amount_due,
+            credit,
+            prepaid_cycles: (surplus / utils::cycle_contribution(&env, &group, &member)) as u32,
+        })
     }
 
-    /// Execute payout for the current cycle.
-    ///
-    /// This is the core function that rotates payouts through group members.
-    /// It verifies that all members have contributed, calculates the total payout
-    /// (including any penalties collected), transfers tokens from the contract to
-    /// the recipient, and advances the cycle. When all members have received their
-    /// payout, the group is marked complete.
-    ///
-    /// Payout can only be executed after the grace period expires to ensure all
-    /// late contributions are collected.
+    /// Opt in to auto-debit contributions for a group.
     ///
-    /// Process:
-    /// 1. Verifies all members have contributed in the current cycle
-    /// 2. Ensures grace period has expired
-    /// 3. Calculates total payout (contribution_amount × member_count + penalties)
-    /// 4. Verifies contract has sufficient token balance
-    /// 5. Transfers tokens from contract to recipient
-    /// 6. Records payout to the current recipient
-    /// 7. Emits payout event with penalty bonus
-    /// 8. Advances to next cycle (or marks complete if done)
+    /// Once opted in, anyone can call [`AjoContract::collect_due`] to pull the
+    /// member's contribution for the current cycle. The member must also grant
+    /// this contract an allowance on the group token through the SEP-41 `approve`
+    /// function; the allowance amount and expiration ledger bound what can be pulled.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The group to execute payout for
-    ///
-    /// # Returns
-    /// `Ok(())` on successful payout execution and token transfer
+    /// * `member` - Address of the member opting in (must authenticate)
+    /// * `group_id` - The group to opt in for
     ///
     /// # Errors
     /// * `GroupNotFound` - If the group does not exist
-    /// * `IncompleteContributions` - If not all members have contributed
-    /// * `GroupComplete` - If the group has already completed all payouts
-    /// * `NoMembers` - If the group has no members (should never happen)
-    /// * `OutsideCycleWindow` - If grace period has not expired yet
-    /// * `InsufficientContractBalance` - If contract doesn't have enough tokens
-    /// * `TransferFailed` - If the token transfer fails
-    pub fn execute_payout(env: Env, group_id: u64) -> Result<(), AjoError> {
-        // Check if paused
-        pausable::ensure_not_paused(&env)?;
-
-        // Get group (single fetch)
-        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+    /// * `NotMember` - If the address is not a member
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    pub fn enable_auto_debit(env: Env, member: Address, group_id: u64) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::Contributions)?;
+        member.require_auth();
 
-        // Check if group is cancelled
-        if group.state == crate::types::GroupState::Cancelled {
-            return Err(AjoError::GroupCancelled);
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if !storage::is_group_member(&env, group.id, &member) {
+            return Err(AjoError::NotMember);
         }
-
-        // Check if group is complete
         if group.is_complete {
             return Err(AjoError::GroupComplete);
         }
-
-        // Cache frequently accessed values
-        let group_id_cached = group.id;
-        let current_cycle = group.current_cycle;
-        let member_count = group.members.len();
-
-        // Check if all members have contributed
-        if !utils::all_members_contributed(&env, &group) {
-            return Err(AjoError::IncompleteContributions);
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
         }
 
-        // Ensure grace period has expired before executing payout
-        let current_time = utils::get_current_timestamp(&env);
-        let grace_end = utils::get_grace_period_end(&group);
-        if current_time < grace_end {
-            // Still within grace period - delay payout
-            return Err(AjoError::OutsideCycleWindow);
-        }
-
-        // Get payout recipient
-        let payout_recipient = group
-            .members
-            .get(group.payout_index)
-            .ok_or(AjoError::NoMembers)?;
-
-        // Calculate payout amounts: base payout + collected penalties for this cycle
-        let base_payout = group.contribution_amount * (member_count as i128);
-        let penalty_bonus = storage::get_cycle_penalty_pool(&env, group_id_cached, current_cycle);
-        let payout_amount = base_payout + penalty_bonus;
+        storage::set_auto_debit(&env, group_id, &member, true);
+        events::emit_auto_debit_changed(&env, group_id, &member, true);
 
-        // Get contract address for token transfer
-        let contract_address = env.current_contract_address();
-
-        // Verify contract has sufficient balance
-        crate::token::check_contract_balance(
-            &env,
-            &group.token_address,
-            &contract_address,
-            payout_amount,
-        )?;
+        Ok(())
+    }
 
-        // Transfer tokens from contract to recipient
-        crate::token::transfer_token(
-            &env,
-            &group.token_address,
-            &contract_address,
-            &payout_recipient,
-            payout_amount,
-        )?;
+    /// Opt out of auto-debit contributions for a group.
+    ///
+    /// Opting out is never blocked by the pause switches. Revoking the token
+    /// allowance has the same effect on future collections.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - Address of the member opting out (must authenticate)
+    /// * `group_id` - The group to opt out for
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn disable_auto_debit(env: Env, member: Address, group_id: u64) -> Result<(), AjoError> {
+        member.require_auth();
 
-        // Mark payout as received
-        storage::mark_payout_received(&env, group_id_cached, &payout_recipient);
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
 
-        // Emit payout event with penalty information
-        if penalty_bonus > 0 {
-            events::emit_penalty_distributed(
-                &env,
-                group_id,
-                &payout_recipient,
-                group.current_cycle,
-                base_payout,
-                penalty_bonus,
-            );
-        }
+        storage::set_auto_debit(&env, group_id, &member, false);
+        events::emit_auto_debit_changed(&env, group_id, &member, false);
 
-        events::emit_payout_executed(
-            &env,
-            group_id_cached,
-            &payout_recipient,
-            current_cycle,
-            payout_amount,
-        );
+        Ok(())
+    }
 
-        // Advance payout index
-        group.payout_index += 1;
+    /// Check whether a member has opted in to auto-debit contributions.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group to check
+    /// * `member` - The member address to check
+    ///
+    /// # Returns
+    /// `true` if the member has opted in, `false` otherwise
+    pub fn is_auto_debit_enabled(env: Env, group_id: u64, member: Address) -> bool {
+        storage::is_auto_debit_enabled(&env, group_id, &member)
+    }
 
-        // Check if all members have received payout
-        if group.payout_index >= member_count as u32 {
-            // All members have received payout - mark complete
-            group.is_complete = true;
-            events::emit_group_completed(&env, group_id_cached);
-        } else {
-            // Advance to next cycle
-            group.current_cycle += 1;
-            group.cycle_start_time = utils::get_current_timestamp(&env);
-        }
+    /// Pull due contributions from the next batch of opted-in members who
+    /// haven't paid.
+    ///
+    /// This function is permissionless so that keepers can drive collections.
+    /// Up to [`COLLECTION_BATCH_SIZE`](crate::security::limits::COLLECTION_BATCH_SIZE)
+    /// opted-in members who have not contributed to the current cycle are
+    /// charged through `transfer_from` on their allowance, including the late
+    /// penalty when collected inside the grace period. Each call continues in
+    /// member order from where the previous one stopped, and starts again from
+    /// the first member once it has passed the last. A failure for one member
+    /// (for example `InsufficientAllowance` or `InsufficientBalance`) is
+    /// reported in that member's result and does not abort the batch. A token
+    /// that moves a different amount than requested aborts the whole collection
+    /// with `TransferFailed`.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group to collect for
+    ///
+    /// # Returns
+    /// One [`CollectionResult`](crate::types::CollectionResult) per member
+    /// charged in this batch, in member order
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `GroupFrozen` - If the group has been frozen by its organizers
+    /// * `TransferFailed` - If the group token moved a different amount than requested
+    pub fn collect_due(
+        env: Env,
+        group_id: u64,
+    ) -> Result<Vec<crate::types::CollectionResult>, AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::Contributions)?;
 
-        // Update storage (single write)
-        storage::store_group(&env, group_id, &group);
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Self::ensure_accepting_contributions(&group)?;
 
-        Ok(())
+        Self::collect_auto_debits(&env, &group)
     }
 
-    /// Check if a group has completed all cycles.
+    /// Get contribution status for all members in a specific cycle.
     ///
-    /// Returns whether the group has completed its full rotation,
-    /// meaning all members have received at least one payout.
+    /// Returns an ordered list of all members paired with their contribution status
+    /// for the specified cycle. Member order matches the group's member list order.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
     /// * `group_id` - The group to check
+    /// * `cycle_number` - The cycle to check (typically use current_cycle from group)
     ///
     /// # Returns
-    /// `true` if the group has completed all payouts, `false` otherwise
+    /// Vector of (Address, bool) tuples where `true` indicates the member has contributed
     ///
     /// # Errors
     /// * `GroupNotFound` - If the group does not exist
-    pub fn is_complete(env: Env, group_id: u64) -> Result<bool, AjoError> {
-        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-        Ok(group.is_complete)
+    pub fn get_contribution_status(
+        env: Env,
+        group_id: u64,
+        cycle_number: u32,
+    ) -> Result<Vec<(Address, bool)>, AjoError> {
+        if storage::get_group(&env, group_id).is_none() {
+            return Err(AjoError::GroupNotFound);
+        }
+        Ok(storage::get_cycle_contributions(
+            &env,
+            group_id,
+            cycle_number,
+            &storage::get_group_members(&env, group_id),
+        ))
     }
 
-    /// Get comprehensive group status.
-    ///
-    /// Returns a detailed snapshot of the group's current state, including cycle
-    /// information, contribution status, payout progress, and timing data.
-    /// This is the primary function for checking a group's overall progress.
+    /// Get the member-by-cycle contribution grid for a range of cycles and members.
     ///
-    /// Returns information about:
-    /// - Current cycle number and progress
-    /// - Next recipient for payout
-    /// - Members who have contributed and those who are pending
-    /// - Cycle timing (start time, end time, whether cycle is active)
-    /// - Whether the group is complete
+    /// Each row holds one [`ContributionState`](crate::types::ContributionState)
+    /// per member, in the group's member list order: paid on time, paid late,
+    /// missed, covered by a paid insurance claim, or still pending while the
+    /// cycle's grace period runs. See the [`history`](crate::history) module.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The unique group identifier
+    /// * `group_id` - The group to inspect
+    /// * `from_cycle` - The first cycle to include
+    /// * `to_cycle` - The last cycle to include, clipped to the current cycle and
+    ///   to [`MATRIX_PAGE_CYCLES`](crate::security::limits::MATRIX_PAGE_CYCLES) cycles
+    /// * `start_member` - Index in the member list of the first member to include
+    /// * `member_limit` - Maximum number of members to include, capped at
+    ///   [`MAX_PAGE_SIZE`](crate::security::limits::MAX_PAGE_SIZE)
     ///
     /// # Returns
-    /// A `GroupStatus` struct containing comprehensive group state information
+    /// One [`CycleContributions`](crate::types::CycleContributions) per cycle, in
+    /// order, each holding the states of the members from `start_member` on
     ///
     /// # Errors
     /// * `GroupNotFound` - If the group does not exist
-    pub fn get_group_status(env: Env, group_id: u64) -> Result<GroupStatus, AjoError> {
-        // Get the group data (single fetch)
+    pub fn get_contribution_matrix(
+        env: Env,
+        group_id: u64,
+        from_cycle: u32,
+        to_cycle: u32,
+        start_member: u32,
+        member_limit: u32,
+    ) -> Result<Vec<crate::types::CycleContributions>, AjoError> {
         let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-
-        // Cache frequently accessed values
-        let current_time = utils::get_current_timestamp(&env);
-        let member_count = group.members.len();
-        let group_id_cached = group.id;
-        let current_cycle = group.current_cycle;
-
-        // Calculate cycle timing
-        let cycle_end_time = group.cycle_start_time + group.cycle_duration;
-        let grace_period_end_time = utils::get_grace_period_end(&group);
-        let is_cycle_active = current_time < cycle_end_time;
-        let is_in_grace_period = utils::is_within_grace_period(&group, current_time);
-
-        // Get penalty pool for current cycle
-        let cycle_penalty_pool = storage::get_cycle_penalty_pool(&env, group_id, group.current_cycle);
-
-        // Build pending_contributors list
-        let mut contributions_received: u32 = 0;
-        let mut pending_contributors = Vec::new(&env);
-
-        // Single pass through members to check contributions
-        for member in group.members.iter() {
-            if storage::has_contributed(&env, group_id_cached, current_cycle, &member) {
-                contributions_received += 1;
-            } else {
-                pending_contributors.push_back(member);
-            }
-        }
-
-        // Determine next recipient
-        let (has_next_recipient, next_recipient) = if group.is_complete {
-            // Use placeholder (creator) when complete
-            (false, group.creator.clone())
-        } else {
-            // Get the member at payout_index
-            let recipient = group
-                .members
-                .get(group.payout_index)
-                .unwrap_or_else(|| group.creator.clone());
-            (true, recipient)
-        };
-
-        // Build and return status
-        Ok(GroupStatus {
-            group_id: group.id,
-            current_cycle: group.current_cycle,
-            has_next_recipient,
-            next_recipient,
-            contributions_received,
-            total_members: group.members.len() as u32,
-            pending_contributors,
-            is_complete: group.is_complete,
-            is_cycle_active,
-            cycle_start_time: group.cycle_start_time,
-            cycle_end_time,
-            current_time,
-            cycle_penalty_pool,
-            is_in_grace_period,
-            grace_period_end_time,
-        })
+        Ok(history::contribution_matrix(
+            &env,
+            &group,
+            from_cycle,
+            to_cycle,
+            start_member,
+            member_limit,
+        ))
     }
 
-    /// Set or update metadata for an Ajo group.
+    /// Get the totals of a completed cycle.
     ///
-    /// Only the group creator can set or update metadata.
+    /// Reports what the cycle collected in contributions and penalties, its
+    /// penalty pool, and who was paid out, how much and when.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The unique group identifier
-    /// * `name` - The name of the group
-    /// * `description` - The description of the group
-    /// * `rules` - The rules of the group
+    /// * `group_id` - The group to inspect
+    /// * `cycle` - The cycle to summarise
     ///
     /// # Errors
     /// * `GroupNotFound` - If the group does not exist
-    /// * `Unauthorized` - If the caller is not the group creator
-    /// * `MetadataTooLong` - If any field exceeds its length limit
-    pub fn set_group_metadata(
+    /// * `OutsideCycleWindow` - If the cycle has not been paid out
+    pub fn get_cycle_summary(
         env: Env,
         group_id: u64,
-        name: soroban_sdk::String,
-        description: soroban_sdk::String,
-        rules: soroban_sdk::String,
-    ) -> Result<(), AjoError> {
-        // Validate lengths
-        if name.len() > crate::types::MAX_NAME_LENGTH
-            || description.len() > crate::types::MAX_DESCRIPTION_LENGTH
-            || rules.len() > crate::types::MAX_RULES_LENGTH
-        {
-            return Err(AjoError::MetadataTooLong);
-        }
-
-        // Get group to verify existence and check creator
+        cycle: u32,
+    ) -> Result<crate::types::CycleSummary, AjoError> {
         let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        history::cycle_summary(&env, &group, cycle)
+    }
 
-        // Require creator authentication
-        group.creator.require_auth();
+    /// Get the payout rotation for a group, one entry per seat.
+    ///
+    /// Members holding several seats appear once per seat, so each entry maps a
+    /// payout position to the member who receives it and the cycle it falls in.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group to inspect
+    ///
+    /// # Returns
+    /// Vector of [`PayoutPosition`](crate::types::PayoutPosition) in payout order
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_payout_schedule(
+        env: Env,
+        group_id: u64,
+    ) -> Result<Vec<crate::types::PayoutPosition>, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
 
-        // Create and store metadata
-        let metadata = GroupMetadata {
-            name,
-            description,
-            rules,
-        };
-        storage::store_group_metadata(&env, group_id, &metadata);
+        let mut schedule: Vec<crate::types::PayoutPosition> = Vec::new(&env);
+        let seats = storage::get_group_seats(&env, group_id);
+        for (position, member) in seats.iter().enumerate() {
+            let position = position as u32;
+            // A member's seats are taken together on joining, so they are adjacent
+            let seat = match schedule.last() {
+                Some(previous) if previous.member == member => previous.seat + 1,
+                _ => 1,
+            };
+            schedule.push_back(crate::types::PayoutPosition {
+                position,
+                member,
+                seat,
+                cycle: position + 1,
+                paid_out: position < group.payout_index,
+            });
+        }
 
-        Ok(())
+        Ok(schedule)
     }
 
-    /// Get metadata for an Ajo group.
+    /// Get the payouts a group has made, in cycle order.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The unique group identifier
+    /// * `group_id` - The group to inspect
+    /// * `start` - The first cycle to include
+    /// * `limit` - Maximum number of records to return, capped at
+    ///   [`MAX_PAGE_SIZE`](crate::security::limits::MAX_PAGE_SIZE)
     ///
     /// # Returns
-    /// The group metadata
+    /// One [`PayoutRecord`](crate::types::PayoutRecord) per paid cycle from `start` on
     ///
     /// # Errors
-    /// * `GroupNotFound` - If metadata for the group doesn't exist
-    pub fn get_group_metadata(env: Env, group_id: u64) -> Result<GroupMetadata, AjoError> {
-        storage::get_group_metadata(&env, group_id).ok_or(AjoError::GroupNotFound)
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_payout_history(
+        env: Env,
+        group_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<crate::types::PayoutRecord>, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        let limit = limit.min(crate::security::limits::MAX_PAGE_SIZE);
+
+        // One payout is made per cycle, so cycles 1..=payout_index have been paid
+        let mut history = Vec::new(&env);
+        let mut cycle = start.max(1);
+        while cycle <= group.payout_index && history.len() < limit {
+            if let Some(record) = storage::get_payout_record(&env, group_id, cycle) {
+                history.push_back(record);
+            }
+            cycle += 1;
+        }
+
+        Ok(history)
     }
 
-    /// Get member penalty statistics.
-    ///
-    /// Returns the penalty record for a member in a specific group, including
-    /// late contribution count, on-time count, total penalties paid, and reliability score.
+    /// Get the payouts a member has received from a group.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The unique group identifier
+    /// * `group_id` - The group to inspect
     /// * `member` - The member's address
     ///
     /// # Returns
-    /// The member's penalty record
+    /// One [`PayoutRecord`](crate::types::PayoutRecord) per seat paid so far, in
+    /// cycle order; empty if the member has not been paid yet
     ///
     /// # Errors
-    /// * `GroupNotFound` - If the group doesn't exist
+    /// * `GroupNotFound` - If the group does not exist
     /// * `NotMember` - If the address is not a member of the group
-    pub fn get_member_penalty_record(
+    pub fn get_payout_for_member(
         env: Env,
         group_id: u64,
         member: Address,
-    ) -> Result<crate::types::MemberPenaltyRecord, AjoError> {
-        // Verify group exists
+    ) -> Result<Vec<crate::types::PayoutRecord>, AjoError> {
         let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-
-        // Verify member
-        if !utils::is_member(&group.members, &member) {
+        if !storage::is_group_member(&env, group.id, &member) {
             return Err(AjoError::NotMember);
         }
 
-        // Get penalty record or return default
-        Ok(storage::get_member_penalty(&env, group_id, &member).unwrap_or(
-            crate::types::MemberPenaltyRecord {
-                member: member.clone(),
-                group_id,
-                late_count: 0,
-                on_time_count: 0,
-                total_penalties: 0,
-                reliability_score: 100,
-            },
-        ))
+        let mut payouts = Vec::new(&env);
+        for cycle in storage::get_member_payout_cycles(&env, group_id, &member).iter() {
+            if let Some(record) = storage::get_payout_record(&env, group_id, cycle) {
+                payouts.push_back(record);
+            }
+        }
+
+        Ok(payouts)
     }
 
-    /// Get detailed contribution record for a member in a specific cycle.
+    /// Get a member's full ledger for a group.
     ///
-    /// Returns the full contribution record including timing, penalty information,
-    /// and whether the contribution was late.
+    /// Lists every contribution with its lateness and penalty, the cycles still
+    /// unpaid, the payouts and refund received and the totals, including the
+    /// insurance premiums taken, the credit held, what is still owed for the
+    /// current cycle and the member's net position. See the
+    /// [`statement`](crate::statement) module.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The unique group identifier
-    /// * `cycle` - The cycle number
+    /// * `group_id` - The group to inspect
     /// * `member` - The member's address
     ///
-    /// # Returns
-    /// The detailed contribution record
-    ///
     /// # Errors
-    /// * `GroupNotFound` - If the group doesn't exist or contribution record doesn't exist
-    pub fn get_contribution_detail(
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `NotMember` - If the address is not a member of the group
+    pub fn get_member_statement(
         env: Env,
         group_id: u64,
-        cycle: u32,
         member: Address,
-    ) -> Result<crate::types::ContributionRecord, AjoError> {
-        storage::get_contribution_detail(&env, group_id, cycle, &member)
-            .ok_or(AjoError::GroupNotFound)
+    ) -> Result<crate::types::MemberStatement, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if !storage::is_group_member(&env, group.id, &member) {
+            return Err(AjoError::NotMember);
+        }
+
+        Ok(statement::build(&env, &group, &member))
     }
 
-    /// Get the penalty pool for a specific cycle.
+    /// Get a member's statement totals for several groups at once.
     ///
-    /// Returns the total penalties collected during a cycle, which will be
-    /// distributed to the payout recipient along with regular contributions.
+    /// Groups may use different tokens, so totals are reported per group rather
+    /// than added up.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The unique group identifier
-    /// * `cycle` - The cycle number
+    /// * `member` - The member's address
+    /// * `group_ids` - Groups the address belongs to, at most
+    ///   [`MAX_BATCH_SIZE`](crate::security::limits::MAX_BATCH_SIZE)
     ///
     /// # Returns
-    /// Total penalty amount in stroops
+    /// One [`StatementTotals`](crate::types::StatementTotals) per group, in the
+    /// order given
     ///
     /// # Errors
-    /// * `GroupNotFound` - If the group doesn't exist
-    pub fn get_cycle_penalty_pool(env: Env, group_id: u64, cycle: u32) -> Result<i128, AjoError> {
-        // Verify group exists
-        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+    /// * `LimitExceeded` - If more than `MAX_BATCH_SIZE` groups are given
+    /// * `GroupNotFound` - If any group does not exist
+    /// * `NotMember` - If the address is not a member of one of the groups
+    pub fn get_member_summary(
+        env: Env,
+        member: Address,
+        group_ids: Vec<u64>,
+    ) -> Result<Vec<crate::types::StatementTotals>, AjoError> {
+        Self::ensure_batch_size(&group_ids, crate::security::limits::MAX_BATCH_SIZE)?;
+
+        let mut summary = Vec::new(&env);
+        for group_id in group_ids.iter() {
+            let statement = Self::get_member_statement(env.clone(), group_id, member.clone())?;
+            summary.push_back(statement.totals);
+        }
 
-        Ok(storage::get_cycle_penalty_pool(&env, group_id, cycle))
+        Ok(summary)
     }
 
-    /// Cancel a group and refund all members.
+    /// Get what a member owes next in each of their running groups.
     ///
-    /// Only the group creator can cancel a group, and only before the first payout.
-    /// All members who have contributed will receive their token contributions back.
+    /// For every group the member has joined that is neither cancelled nor
+    /// complete, lists the next contribution due, including the late penalty if
+    /// paying now falls in the grace period, its deadline and grace end, and the
+    /// member's next payout position with an estimate of when it is paid. See
+    /// the [`obligations`](crate::obligations) module.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `creator` - Address of the group creator
-    /// * `group_id` - The unique group identifier
+    /// * `member` - The member's address
+    /// * `start_id` - The first group ID to include
+    /// * `limit` - Maximum number of obligations to return, capped at
+    ///   [`OBLIGATION_PAGE_SIZE`](crate::security::limits::OBLIGATION_PAGE_SIZE)
     ///
     /// # Returns
-    /// `Ok(())` on successful cancellation and refunds
+    /// One [`MemberObligation`](crate::types::MemberObligation) per running
+    /// group from `start_id` on, in group ID order. Pass the last group ID plus
+    /// one as `start_id` to get the next page.
+    pub fn get_member_obligations(
+        env: Env,
+        member: Address,
+        start_id: u64,
+        limit: u32,
+    ) -> Vec<crate::types::MemberObligation> {
+        obligations::list(&env, &member, start_id, limit)
+    }
+
+    /// Audit a group's bookkeeping and state.
+    ///
+    /// Anyone may call this. The report lists expected against received
+    /// contributions for the last
+    /// [`AUDIT_CYCLES`](crate::security::limits::AUDIT_CYCLES) cycles, payout
+    /// records against `payout_index`,
+    /// what the group holds in escrow against the contract's token balance, and
+    /// flags parameter anomalies and a group stuck past its grace period with
+    /// members still to pay. See [`security::audit_group`].
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group to audit
+    ///
+    /// # Returns
+    /// A [`GroupAudit`](crate::types::GroupAudit) whose `issues` are empty for
+    /// a healthy group
     ///
     /// # Errors
-    /// * `GroupNotFound` - If the group doesn't exist
-    /// * `OnlyCreatorCanCancel` - If the caller is not the group creator
-    /// * `CannotCancelAfterPayout` - If any payout has been executed
-    /// * `GroupCancelled` - If the group is already cancelled
-    /// * `GroupComplete` - If the group is already complete
-    /// * `TransferFailed` - If any token refund transfer fails
-    pub fn cancel_group(env: Env, creator: Address, group_id: u64) -> Result<(), AjoError> {
-        pausable::ensure_not_paused(&env)?;
-        creator.require_auth();
-
-        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-
-        // Verify creator
-        if group.creator != creator {
-            return Err(AjoError::OnlyCreatorCanCancel);
-        }
-
-        // Check if already cancelled or complete
-        if group.state == crate::types::GroupState::Cancelled {
-            return Err(AjoError::GroupCancelled);
-        }
-        if group.state == crate::types::GroupState::Complete {
-            return Err(AjoError::GroupComplete);
-        }
-
-        // Cannot cancel after first payout
-        if group.payout_index > 0 {
-            return Err(AjoError::CannotCancelAfterPayout);
-        }
-
-        // Calculate refunds for each member who contributed
-        let contract_address = env.current_contract_address();
-        
-        for member in group.members.iter() {
-            if storage::has_contributed(&env, group_id, group.current_cycle, &member) {
-                let refund_amount = group.contribution_amount;
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn audit_group(env: Env, group_id: u64) -> Result<crate::types::GroupAudit, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(security::audit_group(&env, &group))
+    }
 
-                // Transfer tokens back to member
-                crate::token::transfer_token(
-                    &env,
-                    &group.token_address,
-                    &contract_address,
-                    &member,
-                    refund_amount,
-                )?;
+    /// Audit many groups in group ID order. Admin only.
+    ///
+    /// A call stops before the group that would take its estimated ledger
+    /// reads past [`AUDIT_BATCH_READS`](crate::security::limits::AUDIT_BATCH_READS),
+    /// but always audits at least one group.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `cursor` - The first group ID to audit; pass the last returned ID plus
+    ///   one to continue
+    /// * `limit` - Maximum number of groups to audit, capped at
+    ///   [`MAX_BATCH_SIZE`](crate::security::limits::MAX_BATCH_SIZE)
+    ///
+    /// # Returns
+    /// One [`GroupAudit`](crate::types::GroupAudit) per group with an ID from
+    /// `cursor` on; removed groups are skipped
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If the contract has not been initialized
+    pub fn audit_all(
+        env: Env,
+        cursor: u64,
+        limit: u32,
+    ) -> Result<Vec<crate::types::GroupAudit>, AjoError> {
+        let admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        admin.require_auth();
 
-                // Store refund record
-                let refund_record = crate::types::RefundRecord {
-                    group_id,
-                    member: member.clone(),
-                    amount: refund_amount,
-                    timestamp: utils::get_current_timestamp(&env),
-                    reason: crate::types::RefundReason::CreatorCancellation,
-                };
-                storage::store_refund_record(&env, group_id, &member, &refund_record);
-
-                // Emit refund event
-                events::emit_refund_processed(&env, group_id, &member, refund_amount, 0);
+        let limit = limit.min(crate::security::limits::MAX_BATCH_SIZE);
+        let last_id = storage::get_group_count(&env);
+        let mut audits = Vec::new(&env);
+        let mut group_id = cursor.max(1);
+        let mut reads = 0u32;
+        while group_id <= last_id && audits.len() < limit {
+            reads += 1;
+            if let Some(group) = storage::get_group(&env, group_id) {
+                reads += security::audit_reads(&group);
+                if !audits.is_empty() && reads > crate::security::limits::AUDIT_BATCH_READS {
+                    break;
+                }
+                audits.push_back(security::audit_group(&env, &group));
             }
+            group_id += 1;
         }
 
-        // Update group state
-        group.state = crate::types::GroupState::Cancelled;
-        storage::store_group(&env, group_id, &group);
-
-        // Emit cancellation event
-        events::emit_group_cancelled(
-            &env,
-            group_id,
-            &creator,
-            group.members.len(),
-            group.contribution_amount,
-        );
-
-        Ok(())
+        Ok(audits)
     }
 
-    /// Request a refund for a group that has failed to complete.
+    /// Execute payout for the current cycle.
     ///
-    /// Any member can request a refund if the cycle deadline has passed and
-    /// not all members have contributed. This initiates a voting period where
-    /// members can vote on whether to approve the refund.
+    /// This is the core function that rotates payouts through group members.
+    /// It verifies that all members have contributed, calculates the total payout
+    /// (including any penalties collected), transfers tokens from the contract to
+    /// the recipient, and advances the cycle. When all members have received their
+    /// payout, the group is marked complete.
+    ///
+    /// Payout can only be executed after the grace period expires to ensure all
+    /// late contributions are collected.
+    ///
+    /// Process:
+    /// 1. Verifies all members have contributed in the current cycle
+    /// 2. Ensures grace period has expired
+    /// 3. Calculates total payout (contribution_amount × seat_count + penalties)
+    /// 4. Recalls any funds parked in the group's vault and shares out the yield
+    /// 5. Verifies contract has sufficient token balance
+    /// 6. Takes the platform and organizer fees and transfers the rest, plus any
+    ///    vault yield owed to the recipient, to the member holding the current seat
+    /// 7. Records payout to the current recipient
+    /// 8. Emits payout event with penalty bonus and fees
+    /// 9. Advances to next cycle (or marks complete once every seat is paid, paying
+    ///    out any vault yield held for the members)
+    ///
+    /// The next cycle starts when the current one was scheduled to end, not when
+    /// the payout runs, so a late payout does not shift later cycles. If the
+    /// payout is so late that one or more further cycle windows have also ended,
+    /// those windows are skipped: the next cycle starts at the most recent
+    /// scheduled boundary, keeping the group on its original schedule without
+    /// leaving members a window that has already closed.
+    /// 10. Applies members' prepaid credit to the newly opened cycle
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `requester` - Address of the member requesting the refund
-    /// * `group_id` - The unique group identifier
+    /// * `group_id` - The group to execute payout for
     ///
     /// # Returns
-    /// `Ok(())` on successful request creation
+    /// `Ok(())` on successful payout execution and token transfer
     ///
     /// # Errors
-    /// * `GroupNotFound` - If the group doesn't exist
-    /// * `NotMember` - If the requester is not a member
-    /// * `GroupCancelled` - If the group is already cancelled
-    /// * `GroupComplete` - If the group is already complete
-    /// * `CycleNotExpired` - If the cycle deadline hasn't passed
-    /// * `RefundRequestExists` - If a refund request already exists
-    pub fn request_refund(env: Env, requester: Address, group_id: u64) -> Result<(), AjoError> {
-        pausable::ensure_not_paused(&env)?;
-        requester.require_auth();
-
-        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-
-        // Verify member
-        if !utils::is_member(&group.members, &requester) {
-            return Err(AjoError::NotMember);
-        }
-
-        // Check group state
-        if group.state == crate::types::GroupState::Cancelled {
-            return Err(AjoError::GroupCancelled);
-        }
-        if group.state == crate::types::GroupState::Complete {
-            return Err(AjoError::GroupComplete);
-        }
-
-        // Check if cycle has expired (past grace period)
-        let now = utils::get_current_timestamp(&env);
-        let grace_end = utils::get_grace_period_end(&group);
-        if now <= grace_end {
-            return Err(AjoError::CycleNotExpired);
-        }
-
-        // Check if refund request already exists
-        if storage::has_refund_request(&env, group_id) {
-            return Err(AjoError::RefundRequestExists);
-        }
-
-        // Create refund request
-        let voting_deadline = now + crate::types::VOTING_PERIOD;
-        let request = crate::types::RefundRequest {
-            group_id,
-            requester: requester.clone(),
-            created_at: now,
-            voting_deadline,
-            votes_for: 0,
-            votes_against: 0,
-            executed: false,
-            approved: false,
-        };
-
-        storage::store_refund_request(&env, group_id, &request);
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `IncompleteContributions` - If not all members have contributed
+    /// * `GroupComplete` - If the group has already completed all payouts
+    /// * `GroupFrozen` - If the group has been frozen by its organizers
+    /// * `NoMembers` - If the group has no members (should never happen)
+    /// * `OutsideCycleWindow` - If grace period has not expired yet
+    /// * `InsufficientContractBalance` - If contract doesn't have enough tokens
+    /// * `TransferFailed` - If the token transfer fails, or the group's vault cannot
+    ///   return the parked principal
+    pub fn execute_payout(env: Env, group_id: u64) -> Result<(), AjoError> {
+        // Check if paused
+        pausable::ensure_not_paused(&env, PauseFlag::Payouts)?;
 
-        // Emit event
-        events::emit_refund_requested(&env, group_id, &requester, voting_deadline);
+        Self::payout(&env, group_id)?;
 
         Ok(())
     }
 
-    /// Vote on a refund request.
-    ///
-    /// Members can vote in favor or against a refund request during the voting period.
-    /// Each member can only vote once.
+    /// Drive a group forward by carrying out whatever is due on its clock.
+    ///
+    /// Anyone may call this. Work that large groups spread over several calls
+    /// is finished first, and a poke that does some of it does nothing else:
+    /// paying out member yield (`Payouts`), refunding a cancelled group
+    /// (`Refunds`), or rolling prepaid credit into the current cycle
+    /// (`Contributions`).
+    ///
+    /// Otherwise, for an active group that still has seats to pay, it runs these
+    /// steps in order, skipping any whose operation class is paused:
+    /// 1. Collects the current cycle's contribution from the next batch of
+    ///    opted-in auto-debit members who still owe it, as `collect_due` does,
+    ///    and stops there if it collected any (`Contributions`)
+    /// 2. Once the grace period has ended with members unpaid, marks them in
+    ///    default for the cycle and emits a `defaults` event, once per cycle
+    /// 3. Executes the payout once every seat has paid and the grace period has
+    ///    ended, completing the group on its last seat (`Payouts`)
+    /// 4. Otherwise parks collected contributions in the group's vault, if it
+    ///    has one (`Payouts`)
+    ///
+    /// Marking defaults does not cover them: poke never pays out a short pot,
+    /// so a cycle with defaulters stays open until the group is cancelled and
+    /// refunded. Members can recover a default through an insurance claim.
+    ///
+    /// When the payout runs, the keeper receives the keeper reward set by the
+    /// admin: a share of the platform fee taken from that payout. A group with
+    /// nothing due is left untouched.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `voter` - Address of the voting member
-    /// * `group_id` - The unique group identifier
-    /// * `in_favor` - true to vote in favor, false to vote against
+    /// * `keeper` - Address that receives any keeper reward
+    /// * `group_id` - The group to drive forward
     ///
     /// # Returns
-    /// `Ok(())` on successful vote
+    /// A [`PokeResult`](crate::types::PokeResult) listing the transitions carried out
     ///
     /// # Errors
-    /// * `GroupNotFound` - If the group doesn't exist
-    /// * `NotMember` - If the voter is not a member
-    /// * `NoRefundRequest` - If no refund request exists
-    /// * `AlreadyVoted` - If the member has already voted
-    /// * `VotingPeriodEnded` - If the voting period has ended
-    pub fn vote_refund(
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `TransferFailed` - If a token transfer or the group's vault fails
+    /// * Any error from the payout itself, such as `InsufficientContractBalance`
+    pub fn poke(
         env: Env,
-        voter: Address,
+        keeper: Address,
         group_id: u64,
-        in_favor: bool,
-    ) -> Result<(), AjoError> {
-        pausable::ensure_not_paused(&env)?;
-        voter.require_auth();
-
-        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-
-        // Verify member
-        if !utils::is_member(&group.members, &voter) {
-            return Err(AjoError::NotMember);
-        }
-
-        // Get refund request
-        let mut request = storage::get_refund_request(&env, group_id)
-            .ok_or(AjoError::NoRefundRequest)?;
-
-        // Check if already voted
-        if storage::has_voted(&env, group_id, &voter) {
-            return Err(AjoError::AlreadyVoted);
-        }
-
-        // Check voting period
-        let now = utils::get_current_timestamp(&env);
-        if now > request.voting_deadline {
-            return Err(AjoError::VotingPeriodEnded);
-        }
-
-        // Record vote
-        let vote = crate::types::RefundVote {
-            group_id,
-            voter: voter.clone(),
-            in_favor,
-            timestamp: now,
-        };
-        storage::store_refund_vote(&env, group_id, &voter, &vote);
+    ) -> Result<crate::types::PokeResult, AjoError> {
+        Self::poke_group(&env, &keeper, group_id)
+    }
 
-        // Update vote counts
-        if in_favor {
-            request.votes_for += 1;
-        } else {
-            request.votes_against += 1;
+    /// Drive several groups forward in one call.
+    ///
+    /// Pokes each group in turn, exactly as [`AjoContract::poke`] does, and
+    /// fails as a whole if any group fails. One poke can use most of a
+    /// transaction's budget, so the call stops after the first group it carried
+    /// out a transition for; groups with nothing due cost only a few reads.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `keeper` - Address that receives any keeper rewards
+    /// * `group_ids` - The groups to drive forward, at most
+    ///   [`POKE_BATCH_SIZE`](crate::security::limits::POKE_BATCH_SIZE)
+    ///
+    /// # Returns
+    /// One [`PokeResult`](crate::types::PokeResult) per group poked, in the
+    /// order given; the groups after the first with a transition are left for
+    /// the next call
+    ///
+    /// # Errors
+    /// * `LimitExceeded` - If more than `POKE_BATCH_SIZE` groups are given
+    /// * Any error from [`AjoContract::poke`] for one of the groups
+    pub fn poke_many(
+        env: Env,
+        keeper: Address,
+        group_ids: Vec<u64>,
+    ) -> Result<Vec<crate::types::PokeResult>, AjoError> {
+        Self::ensure_batch_size(&group_ids, crate::security::limits::POKE_BATCH_SIZE)?;
+
+        let mut results = Vec::new(&env);
+        for group_id in group_ids.iter() {
+            let result = Self::poke_group(&env, &keeper, group_id)?;
+            let acted = !result.actions.is_empty();
+            results.push_back(result);
+            if acted {
+                break;
+            }
         }
-        storage::store_refund_request(&env, group_id, &request);
-
-        // Emit event
-        events::emit_refund_vote(&env, group_id, &voter, in_favor);
 
-        Ok(())
+        Ok(results)
     }
 
-    /// Execute a refund after voting period ends.
+    /// Claim the due payouts of several groups with one authorization.
     ///
-    /// Can be called by any member after the voting period ends. If the refund
-    /// is approved (>51% votes in favor), all members receive token refunds
-    /// based on their contributions.
+    /// For each group where the member holds the seat being paid this cycle,
+    /// executes the payout exactly as [`AjoContract::execute_payout`] does. A
+    /// group whose payout is not due to the member (`NotMember`,
+    /// `Unauthorized`, `IncompleteContributions`, `OutsideCycleWindow`, ...) is
+    /// reported in its result and does not stop the others. A payout that fails
+    /// once started, for example with `TransferFailed`, aborts the whole batch.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `executor` - Address executing the refund
-    /// * `group_id` - The unique group identifier
+    /// * `member` - Address claiming its payouts (must authenticate)
+    /// * `group_ids` - The groups to claim from, at most
+    ///   [`CLAIM_BATCH_SIZE`](crate::security::limits::CLAIM_BATCH_SIZE)
     ///
     /// # Returns
-    /// `Ok(())` on successful execution and token refunds
+    /// One [`BatchResult`](crate::types::BatchResult) per group, in the order given
     ///
     /// # Errors
-    /// * `GroupNotFound` - If the group doesn't exist
-    /// * `NoRefundRequest` - If no refund request exists
-    /// * `VotingPeriodActive` - If the voting period hasn't ended
-    /// * `RefundNotApproved` - If the refund wasn't approved
-    /// * `RefundAlreadyExecuted` - If the refund has already been executed
-    /// * `TransferFailed` - If any token refund transfer fails
-    pub fn execute_refund(env: Env, executor: Address, group_id: u64) -> Result<(), AjoError> {
-        pausable::ensure_not_paused(&env)?;
-        executor.require_auth();
-
-        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-        let mut request = storage::get_refund_request(&env, group_id)
-            .ok_or(AjoError::NoRefundRequest)?;
+    /// * `LimitExceeded` - If more than `CLAIM_BATCH_SIZE` groups are given
+    /// * Any error from a payout that fails after it has started
+    pub fn claim_many(
+        env: Env,
+        member: Address,
+        group_ids: Vec<u64>,
+    ) -> Result<Vec<crate::types::BatchResult>, AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::Payouts)?;
+        Self::ensure_batch_size(&group_ids, crate::security::limits::CLAIM_BATCH_SIZE)?;
+        member.require_auth();
 
-        // Check if already executed
-        if request.executed {
-            return Err(AjoError::RefundAlreadyExecuted);
+        let mut results = Vec::new(&env);
+        for group_id in group_ids.iter() {
+            let result = match Self::ensure_claimable(&env, &member, group_id) {
+                // Checks passed, so any failure from here on aborts the batch
+                Ok(()) => Ok(Self::payout(&env, group_id).map(|_| ())?),
+                Err(error) => Err(error),
+            };
+            results.push_back(Self::batch_result(group_id, result)?);
         }
 
-        // Check voting period ended
-        let now = utils::get_current_timestamp(&env);
-        if now <= request.voting_deadline {
-            return Err(AjoError::VotingPeriodActive);
-        }
+        Ok(results)
+    }
 
-        // Calculate approval percentage
-        let total_votes = request.votes_for + request.votes_against;
-        let approval_percentage = if total_votes > 0 {
-            (request.votes_for * 100) / total_votes
-        } else {
-            0
-        };
+    /// Check if a group has completed all cycles.
+    ///
+    /// Returns whether the group has completed its full rotation,
+    /// meaning all members have received at least one payout.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group to check
+    ///
+    /// # Returns
+    /// `true` if the group has completed all payouts, `false` otherwise
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn is_complete(env: Env, group_id: u64) -> Result<bool, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(group.is_complete)
+    }
 
-        // Check if approved
-        if approval_percentage < crate::types::REFUND_APPROVAL_THRESHOLD {
-            request.executed = true;
-            request.approved = false;
-            storage::store_refund_request(&env, group_id, &request);
-            return Err(AjoError::RefundNotApproved);
-        }
+    /// Get comprehensive group status.
+    ///
+    /// Returns a detailed snapshot of the group's current state, including cycle
+    /// information, contribution status, payout progress, and timing data.
+    /// This is the primary function for checking a group's overall progress.
+    ///
+    /// Returns information about:
+    /// - Current cycle number and progress
+    /// - Next recipient for payout
+    /// - Members who have contributed and those who are pending
+    /// - Cycle timing (start time, end time, whether cycle is active)
+    /// - Whether the group is complete
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// A `GroupStatus` struct containing comprehensive group state information
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_group_status(env: Env, group_id: u64) -> Result<GroupStatus, AjoError> {
+        // Get the group data (single fetch)
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
 
-        // Process refunds for all members who contributed
-        let contract_address = env.current_contract_address();
-        
-        for member in group.members.iter() {
-            if storage::has_contributed(&env, group_id, group.current_cycle, &member) {
-                let refund_amount = group.contribution_amount;
+        // Cache frequently accessed values
+        let current_time = utils::get_current_timestamp(&env);
+        let group_id_cached = group.id;
+        let current_cycle = group.current_cycle;
 
-                // Transfer tokens back to member
-                crate::token::transfer_token(
-                    &env,
-                    &group.token_address,
-                    &contract_address,
-                    &member,
-                    refund_amount,
-                )?;
+        // Calculate cycle timing
+        let cycle_end_time = utils::cycle_end_time(&group);
+        let grace_period_end_time = utils::get_grace_period_end(&group);
+        let is_cycle_active = current_time < cycle_end_time;
+        let is_in_grace_period = utils::is_within_grace_period(&group, current_time);
 
-                // Store refund record
-                let refund_record = crate::types::RefundRecord {
-                    group_id,
-                    member: member.clone(),
-                    amount: refund_amount,
-                    timestamp: now,
-                    reason: crate::types::RefundReason::MemberVote,
-                };
-                storage::store_refund_record(&env, group_id, &member, &refund_record);
-
-                // Emit refund event
-                events::emit_refund_processed(&env, group_id, &member, refund_amount, 1);
+        // Get penalty pool for current cycle
+        let cycle_penalty_pool =
+            storage::get_cycle_penalty_pool(&env, group_id, group.current_cycle);
+
+        // Build pending_contributors list
+        let mut contributions_received: u32 = 0;
+        let mut pending_contributors = Vec::new(&env);
+
+        // Single pass through members against the cycle's contribution bitmap
+        let bitmap = storage::get_contribution_bitmap(&env, group_id_cached, current_cycle);
+        let members = storage::get_group_members(&env, group_id_cached);
+        for (index, member) in members.iter().enumerate() {
+            if storage::is_bit_set(bitmap, index as u32) {
+                contributions_received += 1;
+            } else {
+                pending_contributors.push_back(member);
             }
         }
 
-        // Update request and group state
-        request.executed = true;
-        request.approved = true;
-        storage::store_refund_request(&env, group_id, &request);
-
-        group.state = crate::types::GroupState::Cancelled;
-        storage::store_group(&env, group_id, &group);
+        // Determine next recipient
+        let (has_next_recipient, next_recipient) = if group.is_complete {
+            // Use placeholder (creator) when complete
+            (false, group.creator.clone())
+        } else {
+            // Get the member holding the seat at payout_index
+            let recipient = storage::get_seat_holder(&env, group_id_cached, group.payout_index)
+                .unwrap_or_else(|| group.creator.clone());
+            (true, recipient)
+        };
 
-        Ok(())
+        // Build and return status
+        Ok(GroupStatus {
+            group_id: group.id,
+            current_cycle: group.current_cycle,
+            has_next_recipient,
+            next_recipient,
+            contributions_received,
+            total_members: group.member_count,
+            total_seats: group.seat_count,
+            next_payout_position: group.payout_index,
+            pending_contributors,
+            is_complete: group.is_complete,
+            is_cycle_active,
+            cycle_start_time: group.cycle_start_time,
+            cycle_end_time,
+            current_time,
+            cycle_penalty_pool,
+            is_in_grace_period,
+            grace_period_end_time,
+        })
     }
 
-    /// Emergency refund by admin.
+    /// Get the status of several groups in one call.
     ///
-    /// Allows the contract admin to force a refund in case of disputes or emergencies.
-    /// All members who have contributed receive their token contributions back.
+    /// Builds each entry exactly as [`AjoContract::get_group_status`] does.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `admin` - Address of the contract admin
-    /// * `group_id` - The unique group identifier
+    /// * `group_ids` - The groups to describe, at most 10
     ///
     /// # Returns
-    /// `Ok(())` on successful emergency refund and token transfers
+    /// One `GroupStatus` per group, in the order given
     ///
     /// # Errors
-    /// * `Unauthorized` - If the caller is not the admin
-    /// * `GroupNotFound` - If the group doesn't exist
-    /// * `GroupCancelled` - If the group is already cancelled
-    /// * `TransferFailed` - If any token refund transfer fails
-    pub fn emergency_refund(env: Env, admin: Address, group_id: u64) -> Result<(), AjoError> {
-        admin.require_auth();
-
-        // Verify admin
-        let stored_admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
-        if admin != stored_admin {
-            return Err(AjoError::Unauthorized);
+    /// * `LimitExceeded` - If more than 10 groups are given
+    /// * `GroupNotFound` - If any of the groups does not exist
+    pub fn get_group_statuses(env: Env, group_ids: Vec<u64>) -> Result<Vec<GroupStatus>, AjoError> {
+        Self::ensure_batch_size(&group_ids, crate::security::limits::MAX_BATCH_SIZE)?;
+
+        let mut statuses = Vec::new(&env);
+        for group_id in group_ids.iter() {
+            statuses.push_back(Self::get_group_status(env.clone(), group_id)?);
         }
 
-        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-
-        // Check if already cancelled
-        if group.state == crate::types::GroupState::Cancelled {
-            return Err(AjoError::GroupCancelled);
-        }
+        Ok(statuses)
+    }
 
-        let now = utils::get_current_timestamp(&env);
-        let mut total_refunded = 0i128;
-        let contract_address = env.current_contract_address();
+    /// Set or update metadata for an Ajo group.
+    ///
+    /// Only the group creator or one of its co-organizers can set or update metadata.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `caller` - Address of the creator or co-organizer (must authenticate)
+    /// * `group_id` - The unique group identifier
+    /// * `name` - The name of the group
+    /// * `description` - The description of the group
+    /// * `rules` - The rules of the group
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator or a co-organizer
+    /// * `MetadataTooLong` - If any field exceeds its length limit
+    pub fn set_group_metadata(
+        env: Env,
+        caller: Address,
+        group_id: u64,
+        name: soroban_sdk::String,
+        description: soroban_sdk::String,
+        rules: soroban_sdk::String,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::GroupCreation)?;
 
-        // Process refunds for all members who contributed
-        for member in group.members.iter() {
-            if storage::has_contributed(&env, group_id, group.current_cycle, &member) {
-                let refund_amount = group.contribution_amount;
-                total_refunded += refund_amount;
+        // Validate lengths
+        validation::validate_metadata_lengths(
+            &validation::get_config(&env),
+            name.len(),
+            description.len(),
+            rules.len(),
+        )?;
 
-                // Transfer tokens back to member
-                crate::token::transfer_token(
-                    &env,
-                    &group.token_address,
-                    &contract_address,
-                    &member,
-                    refund_amount,
-                )?;
+        // Require caller authentication
+        caller.require_auth();
 
-                // Store refund record
-                let refund_record = crate::types::RefundRecord {
-                    group_id,
-                    member: member.clone(),
-                    amount: refund_amount,
-                    timestamp: now,
-                    reason: crate::types::RefundReason::EmergencyRefund,
-                };
-                storage::store_refund_record(&env, group_id, &member, &refund_record);
-
-                // Emit refund event
-                events::emit_refund_processed(&env, group_id, &member, refund_amount, 2);
-            }
+        // Get group to verify existence and check organizer role
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if !utils::is_organizer(&env, &group, &caller) {
+            return Err(AjoError::Unauthorized);
         }
 
-        // Update group state
-        group.state = crate::types::GroupState::Cancelled;
-        storage::store_group(&env, group_id, &group);
-
-        // Emit emergency refund event
-        events::emit_emergency_refund(&env, group_id, &admin, total_refunded);
+        // Create and store metadata
+        let metadata = GroupMetadata {
+            name,
+            description,
+            rules,
+        };
+        storage::store_group_metadata(&env, group_id, &metadata);
+        events::emit_metadata_updated(&env, group_id, &metadata);
 
         Ok(())
     }
 
-    /// Get refund request for a group.
-    ///
-    /// Returns the current refund request if one exists.
+    /// Get metadata for an Ajo group.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
     /// * `group_id` - The unique group identifier
     ///
     /// # Returns
-    /// The refund request
+    /// The group metadata
     ///
     /// # Errors
-    /// * `NoRefundRequest` - If no refund request exists
-    pub fn get_refund_request(
-        env: Env,
-        group_id: u64,
-    ) -> Result<crate::types::RefundRequest, AjoError> {
-        storage::get_refund_request(&env, group_id).ok_or(AjoError::NoRefundRequest)
+    /// * `GroupNotFound` - If metadata for the group doesn't exist
+    pub fn get_group_metadata(env: Env, group_id: u64) -> Result<GroupMetadata, AjoError> {
+        storage::get_group_metadata(&env, group_id).ok_or(AjoError::GroupNotFound)
     }
 
-    /// Get refund record for a member.
+    /// Appoint a co-organizer for a group.
     ///
-    /// Returns the refund record if the member has received a refund.
+    /// Co-organizers share the organizer powers of the creator: they can update
+    /// metadata, cancel the group while cancellation is allowed, and freeze or
+    /// start the group. Only the creator can manage the co-organizer list.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
     /// * `group_id` - The unique group identifier
-    /// * `member` - The member's address
-    ///
-    /// # Returns
-    /// The refund record
+    /// * `co_organizer` - The address to appoint
     ///
     /// # Errors
-    /// * `GroupNotFound` - If no refund record exists
-    pub fn get_refund_record(
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `AlreadyMember` - If the address is the creator or already a co-organizer
+    /// * `MaxMembersExceeded` - If the group already has `MAX_CO_ORGANIZERS` co-organizers
+    pub fn add_co_organizer(
         env: Env,
         group_id: u64,
-        member: Address,
-    ) -> Result<crate::types::RefundRecord, AjoError> {
-        storage::get_refund_record(&env, group_id, &member).ok_or(AjoError::GroupNotFound)
+        co_organizer: Address,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::GroupCreation)?;
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Require creator authentication
+        group.creator.require_auth();
+
+        if utils::is_organizer(&env, &group, &co_organizer) {
+            return Err(AjoError::AlreadyMember);
+        }
+
+        let mut co_organizers = storage::get_co_organizers(&env, group_id);
+        if co_organizers.len() >= crate::types::MAX_CO_ORGANIZERS {
+            return Err(AjoError::MaxMembersExceeded);
+        }
+
+        co_organizers.push_back(co_organizer.clone());
+        storage::store_co_organizers(&env, group_id, &co_organizers);
+
+        events::emit_co_organizer_added(&env, group_id, &co_organizer);
+
+        Ok(())
     }
 
-    /// Get the contract's token balance for a specific token.
+    /// Remove a co-organizer from a group.
     ///
-    /// Returns the amount of tokens held by the contract for a given token address.
-    /// Useful for checking if the contract has sufficient funds for payouts.
+    /// Only the creator can manage the co-organizer list.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `token_address` - Address of the token contract
+    /// * `group_id` - The unique group identifier
+    /// * `co_organizer` - The address to remove
     ///
-    /// # Returns
-    /// The token balance held by the contract
-    pub fn get_contract_balance(env: Env, token_address: Address) -> i128 {
-        let contract_address = env.current_contract_address();
-        crate::token::get_balance(&env, &token_address, &contract_address)
-    }
-
-    /// File an insurance claim for non-payment.
-    pub fn file_insurance_claim(
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `NotMember` - If the address is not a co-organizer
+    pub fn remove_co_organizer(
         env: Env,
-        claimant: Address,
         group_id: u64,
-        cycle: u32,
-        defaulter: Address,
-        amount: i128,
-    ) -> Result<u64, AjoError> {
-        claimant.require_auth();
-        crate::insurance::file_claim(&env, group_id, cycle, claimant, defaulter, amount)
+        co_organizer: Address,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::GroupCreation)?;
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Require creator authentication
+        group.creator.require_auth();
+
+        let mut co_organizers = storage::get_co_organizers(&env, group_id);
+        let index = co_organizers
+            .first_index_of(&co_organizer)
+            .ok_or(AjoError::NotMember)?;
+        co_organizers.remove(index);
+        storage::store_co_organizers(&env, group_id, &co_organizers);
+
+        events::emit_co_organizer_removed(&env, group_id, &co_organizer);
+
+        Ok(())
     }
 
-    /// Process (approve/reject) an insurance claim.
-    /// Only the contract admin can process claims.
-    pub fn process_insurance_claim(
+    /// Get the co-organizers of a group.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// Co-organizer addresses in the order they were appointed (excluding the creator)
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_co_organizers(env: Env, group_id: u64) -> Result<Vec<Address>, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(storage::get_co_organizers(&env, group_id))
+    }
+
+    /// Freeze an active group.
+    ///
+    /// While frozen, joins, contributions and payouts are rejected with
+    /// `GroupFrozen`. Cancellation and refunds remain available so members can
+    /// still recover their funds. Freezing is never blocked by the pause switches.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `caller` - Address of the creator or a co-organizer (must authenticate)
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not an organizer of the group
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupFrozen` - If the group is already frozen
+    pub fn freeze_group(env: Env, caller: Address, group_id: u64) -> Result<(), AjoError> {
+        caller.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if !utils::is_organizer(&env, &group, &caller) {
+            return Err(AjoError::Unauthorized);
+        }
+
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if group.is_complete || group.state == crate::types::GroupState::Complete {
+            return Err(AjoError::GroupComplete);
+        }
+        if group.state == crate::types::GroupState::Frozen {
+            return Err(AjoError::GroupFrozen);
+        }
+
+        group.state = crate::types::GroupState::Frozen;
+        storage::store_group(&env, group_id, &group);
+
+        events::emit_group_frozen(&env, group_id, &caller);
+
+        Ok(())
+    }
+
+    /// Start a frozen group.
+    ///
+    /// Returns the group to the active state. The current cycle window restarts
+    /// at the moment the group is started, so members are not penalised for the
+    /// time the group spent frozen.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `caller` - Address of the creator or a co-organizer (must authenticate)
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not an organizer of the group
+    /// * `InvalidGroupState` - If the group is not frozen
+    pub fn start_group(env: Env, caller: Address, group_id: u64) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::GroupCreation)?;
+        caller.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if !utils::is_organizer(&env, &group, &caller) {
+            return Err(AjoError::Unauthorized);
+        }
+
+        if group.state != crate::types::GroupState::Frozen {
+            return Err(AjoError::InvalidGroupState);
+        }
+
+        group.state = crate::types::GroupState::Active;
+        group.cycle_start_time = utils::get_current_timestamp(&env);
+        storage::store_group(&env, group_id, &group);
+
+        events::emit_group_started(&env, group_id, &caller, group.cycle_start_time);
+
+        Ok(())
+    }
+
+    /// Offer ownership of a group to a new creator.
+    ///
+    /// The transfer only takes effect once the new creator calls
+    /// [`AjoContract::accept_group_ownership`]. A new offer replaces any
+    /// previous pending offer.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `new_creator` - The address that will become the creator on acceptance
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `AlreadyMember` - If `new_creator` is already the creator
+    pub fn transfer_group_ownership(
         env: Env,
-        admin: Address,
-        claim_id: u64,
-        approved: bool,
+        group_id: u64,
+        new_creator: Address,
     ) -> Result<(), AjoError> {
-        let contract_admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
-        contract_admin.require_auth();
-        crate::insurance::process_claim(&env, claim_id, approved)
+        pausable::ensure_not_paused(&env, PauseFlag::GroupCreation)?;
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Require creator authentication
+        group.creator.require_auth();
+
+        if group.creator == new_creator {
+            return Err(AjoError::AlreadyMember);
+        }
+
+        storage::store_pending_owner(&env, group_id, &new_creator);
+
+        events::emit_ownership_offered(&env, group_id, &group.creator, &new_creator);
+
+        Ok(())
     }
 
-    /// Get insurance pool details for a specific token.
-    pub fn get_insurance_pool(env: Env, token_address: Address) -> Result<crate::types::InsurancePool, AjoError> {
-        storage::get_insurance_pool(&env, &token_address).ok_or(AjoError::PoolNotFound)
+    /// Accept a pending ownership transfer and become the group creator.
+    ///
+    /// If the new creator was a co-organizer, they are removed from the
+    /// co-organizer list. The previous creator loses all organizer powers.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `new_creator` - The address the group was offered to (must authenticate)
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the group has not been offered to `new_creator`
+    /// * `LimitExceeded` - If `new_creator` already runs too many groups
+    pub fn accept_group_ownership(
+        env: Env,
+        new_creator: Address,
+        group_id: u64,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::GroupCreation)?;
+        new_creator.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        match storage::get_pending_owner(&env, group_id) {
+            Some(pending) if pending == new_creator => {}
+            _ => return Err(AjoError::Unauthorized),
+        }
+        rate_limits::admit_owner(&env, &new_creator, &group)?;
+
+        // The new creator no longer needs a co-organizer seat
+        let mut co_organizers = storage::get_co_organizers(&env, group_id);
+        if let Some(index) = co_organizers.first_index_of(&new_creator) {
+            co_organizers.remove(index);
+            storage::store_co_organizers(&env, group_id, &co_organizers);
+        }
+
+        let previous_creator = group.creator.clone();
+        group.creator = new_creator.clone();
+        storage::store_group(&env, group_id, &group);
+        storage::remove_pending_owner(&env, group_id);
+
+        events::emit_ownership_transferred(&env, group_id, &previous_creator, &new_creator);
+
+        Ok(())
     }
 
-    /// Get insurance claim details.
-    pub fn get_insurance_claim(env: Env, claim_id: u64) -> Result<crate::types::InsuranceClaim, AjoError> {
-        storage::get_insurance_claim(&env, claim_id).ok_or(AjoError::InvalidClaim)
+    /// Get the address a group's ownership has been offered to, if any.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_pending_owner(env: Env, group_id: u64) -> Result<Option<Address>, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(storage::get_pending_owner(&env, group_id))
     }
 
-    /// Get risk score for a member.
-    pub fn get_member_risk_score(env: Env, member: Address) -> u32 {
-        crate::insurance::get_member_risk_score(&env, &member)
+    /// Renew a completed group into a new rotation.
+    ///
+    /// Creates a successor with the same parameters, metadata, co-organizers and
+    /// vault, with the creator as its first member. The other previous members
+    /// are invited back and have
+    /// [`RENEWAL_WINDOW`](crate::security::limits::RENEWAL_WINDOW) to opt in with
+    /// [`AjoContract::join_group`]; until then nobody else may join. The payout
+    /// order rotates by one member so the same person is not always paid first.
+    /// See the [`renewal`](crate::renewal) module for details.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `caller` - A group organizer (must authenticate)
+    /// * `group_id` - The completed group to renew
+    /// * `carry_reliability` - Whether members bring their reliability record
+    ///   into the successor when they opt in
+    ///
+    /// # Returns
+    /// The successor group's ID
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not an organizer of the group
+    /// * `InvalidGroupState` - If the group has not completed its rotation
+    /// * `AlreadyInitialized` - If the group has already been renewed
+    /// * `InvalidTokenAddress` - If the group token is no longer registered
+    /// * `ContributionOutOfRange` - If the contribution no longer fits the token's bounds
+    /// * `InvalidFee` - If the organizer fee no longer fits with the platform fee
+    /// * `LimitExceeded` - If the creator's anti-spam limits are reached, as for
+    ///   [`AjoContract::create_group`]
+    pub fn renew_group(
+        env: Env,
+        caller: Address,
+        group_id: u64,
+        carry_reliability: bool,
+    ) -> Result<u64, AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::GroupCreation)?;
+        caller.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if !utils::is_organizer(&env, &group, &caller) {
+            return Err(AjoError::Unauthorized);
+        }
+        if !group.is_complete {
+            return Err(AjoError::InvalidGroupState);
+        }
+        if storage::get_successor(&env, group_id).is_some() {
+            return Err(AjoError::AlreadyInitialized);
+        }
+
+        // The successor is a new group, so it must pass today's registry and fees
+        fees::validate_fees(storage::get_platform_fee_bps(&env), group.organizer_fee_bps)?;
+        Self::validate_token(&env, &group.token_address, group.contribution_amount)?;
+
+        let successor_id = storage::get_next_group_id(&env);
+        let now = utils::get_current_timestamp(&env);
+        let successor = renewal::successor(&env, &group, successor_id, now);
+        let link = renewal::invite(&env, &group, carry_reliability, now);
+        rate_limits::admit_creator(&env, &successor)?;
+
+        storage::store_group(&env, successor_id, &successor);
+        renewal::copy_settings(&env, group_id, successor_id);
+        storage::store_renewal(&env, successor_id, &link);
+        storage::set_successor(&env, group_id, successor_id);
+        renewal::carry_reliability(&env, successor_id, &successor.creator);
+
+        events::emit_group_created(&env, &successor);
+        events::emit_group_renewed(&env, group_id, successor_id, &link);
+
+        Ok(successor_id)
     }
 
-    /// Get risk rating for a group.
-    pub fn get_group_risk_rating(env: Env, group_id: u64) -> Result<u32, AjoError> {
+    /// Get how a group was renewed from its predecessor.
+    ///
+    /// # Returns
+    /// `None` if the group was not created by [`AjoContract::renew_group`]
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_renewal(env: Env, group_id: u64) -> Result<Option<crate::types::Renewal>, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(storage::get_renewal(&env, group_id))
+    }
+
+    /// Get the ID of the group a completed group was renewed into.
+    ///
+    /// # Returns
+    /// `None` if the group has not been renewed
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_successor(env: Env, group_id: u64) -> Result<Option<u64>, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(storage::get_successor(&env, group_id))
+    }
+
+    /// Get member penalty statistics.
+    ///
+    /// Returns the penalty record for a member in a specific group, including
+    /// late contribution count, on-time count, total penalties paid, and reliability score.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `member` - The member's address
+    ///
+    /// # Returns
+    /// The member's penalty record
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group doesn't exist
+    /// * `NotMember` - If the address is not a member of the group
+    pub fn get_member_penalty_record(
+        env: Env,
+        group_id: u64,
+        member: Address,
+    ) -> Result<crate::types::MemberPenaltyRecord, AjoError> {
+        // Verify group exists
         let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-        Ok(crate::insurance::get_group_risk_rating(&env, &group))
+
+        // Verify member
+        if !storage::is_group_member(&env, group.id, &member) {
+            return Err(AjoError::NotMember);
+        }
+
+        // Get penalty record or return default
+        Ok(
+            storage::get_member_penalty(&env, group_id, &member).unwrap_or(
+                crate::types::MemberPenaltyRecord {
+                    member: member.clone(),
+                    group_id,
+                    late_count: 0,
+                    on_time_count: 0,
+                    total_penalties: 0,
+                    reliability_score: 100,
+                },
+            ),
+        )
+    }
+
+    /// Get detailed contribution record for a member in a specific cycle.
+    ///
+    /// Returns the full contribution record including timing, penalty information,
+    /// and whether the contribution was late.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `cycle` - The cycle number
+    /// * `member` - The member's address
+    ///
+    /// # Returns
+    /// The detailed contribution record
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group doesn't exist or contribution record doesn't exist
+    pub fn get_contribution_detail(
+        env: Env,
+        group_id: u64,
+        cycle: u32,
+        member: Address,
+    ) -> Result<crate::types::ContributionRecord, AjoError> {
+        storage::get_contribution_detail(&env, group_id, cycle, &member)
+            .ok_or(AjoError::GroupNotFound)
+    }
+
+    /// Get the penalty pool for a specific cycle.
+    ///
+    /// Returns the total penalties collected during a cycle, which will be
+    /// distributed to the payout recipient along with regular contributions.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `cycle` - The cycle number
+    ///
+    /// # Returns
+    /// Total penalty amount in stroops
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group doesn't exist
+    pub fn get_cycle_penalty_pool(env: Env, group_id: u64, cycle: u32) -> Result<i128, AjoError> {
+        // Verify group exists
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        Ok(storage::get_cycle_penalty_pool(&env, group_id, cycle))
+    }
+
+    /// Cancel a group and refund all members.
+    ///
+    /// Only the group creator or a co-organizer can cancel a group, and only before
+    /// the first payout. Frozen groups can also be cancelled. All members who have
+    /// contributed will receive their token contributions back. The first
+    /// [`SETTLEMENT_BATCH_SIZE`](crate::security::limits::SETTLEMENT_BATCH_SIZE)
+    /// members are refunded straight away and the rest by
+    /// [`AjoContract::process_refunds`].
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `caller` - Address of the group creator or a co-organizer
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// `Ok(())` on successful cancellation and refunds
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group doesn't exist
+    /// * `OnlyCreatorCanCancel` - If the caller is not the group creator or a co-organizer
+    /// * `CannotCancelAfterPayout` - If any payout has been executed
+    /// * `GroupCancelled` - If the group is already cancelled
+    /// * `GroupComplete` - If the group is already complete
+    /// * `TransferFailed` - If any token refund transfer fails
+    pub fn cancel_group(env: Env, caller: Address, group_id: u64) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::Refunds)?;
+        caller.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Verify organizer role
+        if !utils::is_organizer(&env, &group, &caller) {
+            return Err(AjoError::OnlyCreatorCanCancel);
+        }
+
+        // Check if already cancelled or complete
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if group.state == crate::types::GroupState::Complete {
+            return Err(AjoError::GroupComplete);
+        }
+
+        // Cannot cancel after first payout
+        if group.payout_index > 0 {
+            return Err(AjoError::CannotCancelAfterPayout);
+        }
+
+        // Refund every member's contribution and credit
+        Self::start_refunds(
+            &env,
+            &group,
+            crate::types::RefundReason::CreatorCancellation,
+            &caller,
+        )?;
+
+        // Update group state
+        group.state = crate::types::GroupState::Cancelled;
+        storage::store_group(&env, group_id, &group);
+
+        // Emit cancellation event
+        events::emit_group_cancelled(
+            &env,
+            group_id,
+            &caller,
+            group.member_count,
+            group.contribution_amount,
+        );
+
+        Ok(())
+    }
+
+    /// Request a refund for a group that has failed to complete.
+    ///
+    /// Any member can request a refund if the cycle deadline has passed and
+    /// not all members have contributed. This initiates a voting period where
+    /// members can vote on whether to approve the refund.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `requester` - Address of the member requesting the refund
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// `Ok(())` on successful request creation
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group doesn't exist
+    /// * `NotMember` - If the requester is not a member
+    /// * `GroupCancelled` - If the group is already cancelled
+    /// * `GroupComplete` - If the group is already complete
+    /// * `CycleNotExpired` - If the cycle deadline hasn't passed
+    /// * `RefundRequestExists` - If a refund request already exists
+    pub fn request_refund(env: Env, requester: Address, group_id: u64) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::Refunds)?;
+        requester.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Verify member
+        if !storage::is_group_member(&env, group.id, &requester) {
+            return Err(AjoError::NotMember);
+        }
+
+        // Check group state
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if group.state == crate::types::GroupState::Complete {
+            return Err(AjoError::GroupComplete);
+        }
+
+        // Check if cycle has expired (past grace period)
+        let now = utils::get_current_timestamp(&env);
+        let grace_end = utils::get_grace_period_end(&group);
+        if now <= grace_end {
+            return Err(AjoError::CycleNotExpired);
+        }
+
+        // Check if refund request already exists
+        if storage::has_refund_request(&env, group_id) {
+            return Err(AjoError::RefundRequestExists);
+        }
+
+        // Create refund request
+        let voting_deadline = now + validation::get_config(&env).voting_period;
+        let request = crate::types::RefundRequest {
+            group_id,
+            requester: requester.clone(),
+            created_at: now,
+            voting_deadline,
+            votes_for: 0,
+            votes_against: 0,
+            executed: false,
+            approved: false,
+        };
+
+        storage::store_refund_request(&env, group_id, &request);
+
+        // Emit event
+        events::emit_refund_requested(&env, group_id, &requester, voting_deadline);
+
+        Ok(())
+    }
+
+    /// Vote on a refund request.
+    ///
+    /// Members can vote in favor or against a refund request during the voting period.
+    /// Each member can only vote once.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `voter` - Address of the voting member
+    /// * `group_id` - The unique group identifier
+    /// * `in_favor` - true to vote in favor, false to vote against
+    ///
+    /// # Returns
+    /// `Ok(())` on successful vote
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group doesn't exist
+    /// * `NotMember` - If the voter is not a member
+    /// * `NoRefundRequest` - If no refund request exists
+    /// * `AlreadyVoted` - If the member has already voted
+    /// * `VotingPeriodEnded` - If the voting period has ended
+    pub fn vote_refund(
+        env: Env,
+        voter: Address,
+        group_id: u64,
+        in_favor: bool,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::Refunds)?;
+        voter.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Verify member
+        if !storage::is_group_member(&env, group.id, &voter) {
+            return Err(AjoError::NotMember);
+        }
+
+        // Get refund request
+        let mut request =
+            storage::get_refund_request(&env, group_id).ok_or(AjoError::NoRefundRequest)?;
+
+        // Check if already voted
+        if storage::has_voted(&env, group_id, &voter) {
+            return Err(AjoError::AlreadyVoted);
+        }
+
+        // Check voting period
+        let now = utils::get_current_timestamp(&env);
+        if now > request.voting_deadline {
+            return Err(AjoError::VotingPeriodEnded);
+        }
+
+        // Record vote
+        let vote = crate::types::RefundVote {
+            group_id,
+            voter: voter.clone(),
+            in_favor,
+            timestamp: now,
+        };
+        storage::store_refund_vote(&env, group_id, &voter, &vote);
+
+        // Update vote counts
+        if in_favor {
+            request.votes_for += 1;
+        } else {
+            request.votes_against += 1;
+        }
+        storage::store_refund_request(&env, group_id, &request);
+
+        // Emit event
+        events::emit_refund_vote(&env, group_id, &voter, in_favor);
+
+        Ok(())
+    }
+
+    /// Execute a refund after voting period ends.
+    ///
+    /// Can be called by any member after the voting period ends. If the refund
+    /// is approved (by default at least 51% of votes in favor), all members receive token refunds
+    /// based on their contributions. Refunds of large groups are completed by
+    /// [`AjoContract::process_refunds`], and the refund event is emitted once
+    /// the last member is refunded.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `executor` - Address executing the refund
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// `Ok(())` on successful execution and token refunds
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group doesn't exist
+    /// * `NoRefundRequest` - If no refund request exists
+    /// * `VotingPeriodActive` - If the voting period hasn't ended
+    /// * `RefundNotApproved` - If the refund wasn't approved
+    /// * `RefundAlreadyExecuted` - If the refund has already been executed
+    /// * `TransferFailed` - If any token refund transfer fails
+    pub fn execute_refund(env: Env, executor: Address, group_id: u64) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::Refunds)?;
+        executor.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        let mut request =
+            storage::get_refund_request(&env, group_id).ok_or(AjoError::NoRefundRequest)?;
+
+        // Check if already executed
+        if request.executed {
+            return Err(AjoError::RefundAlreadyExecuted);
+        }
+
+        // Check voting period ended
+        let now = utils::get_current_timestamp(&env);
+        if now <= request.voting_deadline {
+            return Err(AjoError::VotingPeriodActive);
+        }
+
+        // Calculate approval percentage
+        let total_votes = request.votes_for + request.votes_against;
+        let approval_percentage = (request.votes_for * 100)
+            .checked_div(total_votes)
+            .unwrap_or(0);
+
+        // Check if approved
+        if approval_percentage < validation::get_config(&env).refund_approval_threshold {
+            request.executed = true;
+            request.approved = false;
+            storage::store_refund_request(&env, group_id, &request);
+            return Err(AjoError::RefundNotApproved);
+        }
+
+        // Refund every member's contribution and credit
+        Self::start_refunds(
+            &env,
+            &group,
+            crate::types::RefundReason::MemberVote,
+            &executor,
+        )?;
+
+        // Update request and group state
+        request.executed = true;
+        request.approved = true;
+        storage::store_refund_request(&env, group_id, &request);
+
+        group.state = crate::types::GroupState::Cancelled;
+        storage::store_group(&env, group_id, &group);
+
+        Ok(())
+    }
+
+    /// Emergency refund by admin.
+    ///
+    /// Allows the contract admin to force a refund in case of disputes or emergencies.
+    /// All members who have contributed receive their token contributions back.
+    /// Refunds of large groups are completed by [`AjoContract::process_refunds`],
+    /// and the refund event is emitted once the last member is refunded.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `admin` - Address of the contract admin
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// `Ok(())` on successful emergency refund and token transfers
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If the caller is not the admin
+    /// * `GroupNotFound` - If the group doesn't exist
+    /// * `GroupCancelled` - If the group is already cancelled
+    /// * `TransferFailed` - If any token refund transfer fails
+    pub fn emergency_refund(env: Env, admin: Address, group_id: u64) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::Refunds)?;
+        admin.require_auth();
+
+        // Verify admin
+        let stored_admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        if admin != stored_admin {
+            return Err(AjoError::Unauthorized);
+        }
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Check if already cancelled
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+
+        // Refund every member's contribution and credit
+        Self::start_refunds(
+            &env,
+            &group,
+            crate::types::RefundReason::EmergencyRefund,
+            &admin,
+        )?;
+
+        // Update group state
+        group.state = crate::types::GroupState::Cancelled;
+        storage::store_group(&env, group_id, &group);
+
+        Ok(())
+    }
+
+    /// Continue refunding a cancelled group.
+    ///
+    /// Cancelling or refunding a group pays its first
+    /// [`SETTLEMENT_BATCH_SIZE`](crate::security::limits::SETTLEMENT_BATCH_SIZE)
+    /// members straight away, so that every call fits in a transaction. Anyone
+    /// may call this to refund the next batch; funds only ever go to the members.
+    /// `poke` does the same for cancelled groups.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// How many members are still waiting for their refund
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group doesn't exist
+    /// * `NoRefundRequest` - If the group has no refunds left to pay
+    /// * `TransferFailed` - If any token refund transfer fails
+    pub fn process_refunds(env: Env, group_id: u64) -> Result<u32, AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::Refunds)?;
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        let progress =
+            storage::get_refund_progress(&env, group_id).ok_or(AjoError::NoRefundRequest)?;
+
+        Self::refund_batch(&env, &group, progress, true)
+    }
+
+    /// Get the progress of a cancelled group's refunds.
+    ///
+    /// # Returns
+    /// `None` unless some members are still waiting for their refund
+    pub fn get_refund_progress(env: Env, group_id: u64) -> Option<crate::types::RefundProgress> {
+        storage::get_refund_progress(&env, group_id)
+    }
+
+    /// Get refund request for a group.
+    ///
+    /// Returns the current refund request if one exists.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// The refund request
+    ///
+    /// # Errors
+    /// * `NoRefundRequest` - If no refund request exists
+    pub fn get_refund_request(
+        env: Env,
+        group_id: u64,
+    ) -> Result<crate::types::RefundRequest, AjoError> {
+        storage::get_refund_request(&env, group_id).ok_or(AjoError::NoRefundRequest)
+    }
+
+    /// Get refund record for a member.
+    ///
+    /// Returns the refund record if the member has received a refund.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `member` - The member's address
+    ///
+    /// # Returns
+    /// The refund record
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If no refund record exists
+    pub fn get_refund_record(
+        env: Env,
+        group_id: u64,
+        member: Address,
+    ) -> Result<crate::types::RefundRecord, AjoError> {
+        storage::get_refund_record(&env, group_id, &member).ok_or(AjoError::GroupNotFound)
+    }
+
+    /// Get the contract's token balance for a specific token.
+    ///
+    /// Returns the amount of tokens held by the contract for a given token address.
+    /// Useful for checking if the contract has sufficient funds for payouts.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `token_address` - Address of the token contract
+    ///
+    /// # Returns
+    /// The token balance held by the contract
+    pub fn get_contract_balance(env: Env, token_address: Address) -> i128 {
+        let contract_address = env.current_contract_address();
+        crate::token::get_balance(&env, &token_address, &contract_address)
+    }
+
+    /// Withdraw all accrued platform fees for a token.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `token` - The token to withdraw
+    /// * `to` - Address receiving the fees
+    ///
+    /// # Returns
+    /// The amount withdrawn
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If the caller is not the fee manager
+    pub fn withdraw_fees(env: Env, token: Address, to: Address) -> Result<i128, AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::Payouts)?;
+        let manager = fees::get_fee_manager(&env).ok_or(AjoError::Unauthorized)?;
+        manager.require_auth();
+
+        let amount = storage::get_treasury_balance(&env, &token);
+        if amount > 0 {
+            storage::set_treasury_balance(&env, &token, 0);
+            crate::token::transfer_token(
+                &env,
+                &token,
+                &env.current_contract_address(),
+                &to,
+                amount,
+            )?;
+            events::emit_fees_withdrawn(&env, &manager, &token, &to, amount);
+        }
+
+        Ok(amount)
+    }
+
+    /// Get the platform fees accrued for a token.
+    pub fn get_treasury_balance(env: Env, token: Address) -> i128 {
+        storage::get_treasury_balance(&env, &token)
+    }
+
+    /// Withdraw all organizer fees accrued to an organizer for a token.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `organizer` - The organizer withdrawing (must authenticate)
+    /// * `token` - The token to withdraw
+    /// * `to` - Address receiving the fees
+    ///
+    /// # Returns
+    /// The amount withdrawn
+    pub fn withdraw_organizer_fees(
+        env: Env,
+        organizer: Address,
+        token: Address,
+        to: Address,
+    ) -> Result<i128, AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::Payouts)?;
+        organizer.require_auth();
+
+        let amount = storage::get_organizer_fees(&env, &organizer, &token);
+        if amount > 0 {
+            storage::set_organizer_fees(&env, &organizer, &token, 0);
+            crate::token::transfer_token(
+                &env,
+                &token,
+                &env.current_contract_address(),
+                &to,
+                amount,
+            )?;
+            events::emit_fees_withdrawn(&env, &organizer, &token, &to, amount);
+        }
+
+        Ok(amount)
+    }
+
+    /// Get the organizer fees accrued to an organizer for a token.
+    pub fn get_organizer_fees(env: Env, organizer: Address, token: Address) -> i128 {
+        storage::get_organizer_fees(&env, &organizer, &token)
+    }
+
+    /// Set, change or clear the vault a group parks its idle funds in.
+    ///
+    /// Anything the group has parked in its current vault is recalled first.
+    /// Clearing the vault also pays out any yield held for the members.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `caller` - The group creator or a co-organizer (must authenticate)
+    /// * `group_id` - The group to configure
+    /// * `vault` - An admin-approved vault, or `None` to stop using a vault
+    /// * `yield_policy` - Who receives the yield earned in the vault
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not an organizer, or the vault is not approved
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `GroupFrozen` - If the group has been frozen by its organizers
+    /// * `TransferFailed` - If funds parked in the current vault cannot be recalled
+    pub fn set_group_vault(
+        env: Env,
+        caller: Address,
+        group_id: u64,
+        vault: Option<Address>,
+        yield_policy: crate::types::YieldPolicy,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::GroupCreation)?;
+        caller.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if !utils::is_organizer(&env, &group, &caller) {
+            return Err(AjoError::Unauthorized);
+        }
+        Self::ensure_accepting_contributions(&group)?;
+        if let Some(vault) = &vault {
+            if !storage::is_vault_approved(&env, vault) {
+                return Err(AjoError::Unauthorized);
+            }
+        }
+
+        vault::recall(&env, &group, false)?;
+        match vault.clone() {
+            Some(vault) => {
+                let position = vault::configure(&env, group_id, vault, yield_policy);
+                storage::store_group_vault(&env, group_id, &position);
+            }
+            None => {
+                vault::distribute_member_yield(&env, &group)?;
+                storage::remove_group_vault(&env, group_id);
+            }
+        }
+        events::emit_group_vault_set(&env, group_id, &vault, yield_policy);
+
+        Ok(())
+    }
+
+    /// Get a group's vault integration and parked position.
+    ///
+    /// # Returns
+    /// `None` if the group does not use a vault
+    pub fn get_group_vault(env: Env, group_id: u64) -> Option<crate::types::GroupVault> {
+        storage::get_group_vault(&env, group_id)
+    }
+
+    /// Park the contributions a group has collected so far in its vault.
+    ///
+    /// This function is permissionless so that keepers can park funds as they
+    /// arrive. Only contributions for the current cycle that are not already
+    /// parked are deposited. The funds are recalled automatically before the
+    /// group's next payout or refund.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group whose funds to park
+    ///
+    /// # Returns
+    /// The amount deposited, or `0` if the group has no vault or nothing new to park
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `GroupFrozen` - If the group has been frozen by its organizers
+    /// * `TransferFailed` - If the vault rejects the deposit
+    pub fn park_funds(env: Env, group_id: u64) -> Result<i128, AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::Payouts)?;
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Self::ensure_accepting_contributions(&group)?;
+
+        vault::park(&env, &group)
+    }
+
+    /// File an insurance claim for non-payment.
+    pub fn file_insurance_claim(
+        env: Env,
+        claimant: Address,
+        group_id: u64,
+        cycle: u32,
+        defaulter: Address,
+        amount: i128,
+    ) -> Result<u64, AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::Insurance)?;
+        claimant.require_auth();
+        crate::insurance::file_claim(&env, group_id, cycle, claimant, defaulter, amount)
+    }
+
+    /// Process (approve/reject) an insurance claim.
+    /// Only the contract admin can process claims.
+    pub fn process_insurance_claim(
+        env: Env,
+        admin: Address,
+        claim_id: u64,
+        approved: bool,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env, PauseFlag::Insurance)?;
+        admin.require_auth();
+        let contract_admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
+        if admin != contract_admin {
+            return Err(AjoError::Unauthorized);
+        }
+        crate::insurance::process_claim(&env, claim_id, approved)
+    }
+
+    /// Get insurance pool details for a specific token.
+    pub fn get_insurance_pool(
+        env: Env,
+        token_address: Address,
+    ) -> Result<crate::types::InsurancePool, AjoError> {
+        storage::get_insurance_pool(&env, &token_address).ok_or(AjoError::PoolNotFound)
+    }
+
+    /// Get insurance claim details.
+    pub fn get_insurance_claim(
+        env: Env,
+        claim_id: u64,
+    ) -> Result<crate::types::InsuranceClaim, AjoError> {
+        storage::get_insurance_claim(&env, claim_id).ok_or(AjoError::InvalidClaim)
+    }
+
+    /// Get risk score for a member.
+    pub fn get_member_risk_score(env: Env, member: Address) -> u32 {
+        crate::insurance::get_member_risk_score(&env, &member)
+    }
+
+    /// Get risk rating for a group.
+    pub fn get_group_risk_rating(env: Env, group_id: u64) -> Result<u32, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(crate::insurance::get_group_risk_rating(&env, &group))
+    }
+}
+
+impl AjoContract {
+    /// Start refunding every member of a group that is being cancelled.
+    ///
+    /// Funds parked in the group's vault are recalled first, and any vault
+    /// yield held for the members is paid out with the refunds. The first
+    /// batch of members is refunded straight away; see `refund_batch`.
+    ///
+    /// # Returns
+    /// How many members are still waiting for their refund
+    fn start_refunds(
+        env: &Env,
+        group: &Group,
+        reason: crate::types::RefundReason,
+        initiator: &Address,
+    ) -> Result<u32, AjoError> {
+        // Bring back anything parked in the group's vault
+        vault::recall(env, group, false)?;
+
+        let progress = crate::types::RefundProgress {
+            reason,
+            initiator: initiator.clone(),
+            next_member: 0,
+            yield_per_seat: vault::take_member_yield(env, group),
+            total_refunded: 0,
+        };
+        Self::refund_batch(env, group, progress, false)
+    }
+
+    /// Refund the next batch of members of a cancelled group.
+    ///
+    /// Each member receives their contribution for the current cycle across all
+    /// of their seats (if they made one) plus any credit they hold, and a refund
+    /// record is stored. At most `SETTLEMENT_BATCH_SIZE` members are refunded
+    /// per call, so that a full group fits in a transaction; the progress is
+    /// stored until the last member is refunded, which emits the event for the
+    /// refund's reason.
+    ///
+    /// # Returns
+    /// How many members are still waiting for their refund
+    fn refund_batch(
+        env: &Env,
+        group: &Group,
+        mut progress: crate::types::RefundProgress,
+        resumed: bool,
+    ) -> Result<u32, AjoError> {
+        let group_id = group.id;
+        let now = utils::get_current_timestamp(env);
+        let contract_address = env.current_contract_address();
+        let yield_per_seat = progress.yield_per_seat.unwrap_or(0);
+
+        let bitmap = storage::get_contribution_bitmap(env, group_id, group.current_cycle);
+        let members = storage::get_group_members(env, group_id);
+        let end = members
+            .len()
+            .min(progress.next_member + crate::security::limits::SETTLEMENT_BATCH_SIZE);
+        for index in progress.next_member..end {
+            let member = members.get_unchecked(index);
+            let seats = utils::seat_count(env, group, &member) as i128;
+            let mut refund_amount = storage::get_member_credit(env, group_id, &member);
+            if storage::is_bit_set(bitmap, index) {
+                refund_amount += group.contribution_amount * seats;
+            }
+            let yield_amount = yield_per_seat * seats;
+            if refund_amount + yield_amount == 0 {
+                continue;
+            }
+
+            // Transfer tokens back to member, with their share of any vault yield
+            crate::token::transfer_token(
+                env,
+                &group.token_address,
+                &contract_address,
+                &member,
+                refund_amount + yield_amount,
+            )?;
+            if refund_amount == 0 {
+                continue;
+            }
+            storage::set_member_credit(env, group_id, &member, 0);
+            progress.total_refunded += refund_amount;
+
+            // Store refund record
+            let refund_record = crate::types::RefundRecord {
+                group_id,
+                member: member.clone(),
+                amount: refund_amount,
+                timestamp: now,
+                reason: progress.reason,
+            };
+            storage::store_refund_record(env, group_id, &member, &refund_record);
+
+            // Emit refund event
+            events::emit_refund_processed(env, &refund_record);
+        }
+
+        progress.next_member = end;
+        let remaining = members.len() - end;
+        if remaining > 0 {
+            storage::store_refund_progress(env, group_id, &progress);
+            return Ok(remaining);
+        }
+
+        if resumed {
+            storage::remove_refund_progress(env, group_id);
+        }
+        if progress.yield_per_seat.is_some() {
+            events::emit_member_yield_paid(
+                env,
+                group_id,
+                yield_per_seat * group.seat_count as i128,
+            );
+        }
+        match progress.reason {
+            crate::types::RefundReason::CreatorCancellation => {}
+            crate::types::RefundReason::MemberVote => events::emit_refund_executed(
+                env,
+                group_id,
+                &progress.initiator,
+                progress.total_refunded,
+            ),
+            crate::types::RefundReason::EmergencyRefund => events::emit_emergency_refund(
+                env,
+                group_id,
+                &progress.initiator,
+                progress.total_refunded,
+            ),
+        }
+
+        Ok(0)
+    }
+
+    /// Execute the payout for a group's current cycle.
+    ///
+    /// Shared by `execute_payout` and `poke`, which check the pause switch
+    /// first. See `execute_payout` for the process and errors.
+    ///
+    /// # Returns
+    /// The platform fee taken from the payout
+    fn payout(env: &Env, group_id: u64) -> Result<i128, AjoError> {
+        // Get group (single fetch)
+        let mut group = storage::get_group(env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Self::ensure_payout_due(env, &group)?;
+
+        // Cache frequently accessed values
+        let group_id_cached = group.id;
+        let current_cycle = group.current_cycle;
+        let seat_count = group.seat_count;
+        let current_time = utils::get_current_timestamp(env);
+
+        // Get payout recipient
+        let payout_recipient = storage::get_seat_holder(env, group_id_cached, group.payout_index)
+            .ok_or(AjoError::NoMembers)?;
+
+        // Calculate payout amounts: base payout + collected penalties for this cycle
+        let base_payout = group.contribution_amount * (seat_count as i128);
+        let penalty_bonus = storage::get_cycle_penalty_pool(env, group_id_cached, current_cycle);
+        let payout_amount = base_payout + penalty_bonus;
+
+        // Get contract address for token transfer
+        let contract_address = env.current_contract_address();
+
+        // Bring back anything parked in the group's vault
+        let vault_yield = vault::recall(env, &group, true)?;
+
+        // Verify contract has sufficient balance
+        crate::token::check_contract_balance(
+            env,
+            &group.token_address,
+            &contract_address,
+            payout_amount + vault_yield,
+        )?;
+
+        // Take fees and transfer the rest, plus any vault yield, from contract to recipient
+        let (platform_fee, organizer_fee) = fees::calculate_payout_fees(env, &group, payout_amount);
+        let net_payout = payout_amount - platform_fee - organizer_fee + vault_yield;
+        crate::token::transfer_token(
+            env,
+            &group.token_address,
+            &contract_address,
+            &payout_recipient,
+            net_payout,
+        )?;
+        fees::accrue_fees(env, &group, platform_fee, organizer_fee);
+
+        // Mark payout as received and keep its details
+        storage::mark_payout_received(env, group_id_cached, &payout_recipient);
+        let payout_record = crate::types::PayoutRecord {
+            group_id: group_id_cached,
+            cycle: current_cycle,
+            member: payout_recipient.clone(),
+            base_amount: base_payout,
+            penalty_bonus,
+            vault_yield,
+            amount: net_payout,
+            platform_fee,
+            organizer_fee,
+            timestamp: current_time,
+        };
+        storage::store_payout_record(env, &payout_record);
+
+        // Emit payout event with penalty information
+        if penalty_bonus > 0 {
+            events::emit_penalty_distributed(
+                env,
+                group_id,
+                &payout_recipient,
+                group.current_cycle,
+                base_payout,
+                penalty_bonus,
+            );
+        }
+
+        events::emit_payout_executed(env, &payout_record);
+
+        // Advance payout index
+        group.payout_index += 1;
+
+        // Check if every seat has received payout
+        if group.payout_index >= seat_count {
+            // All seats have received payout - mark complete
+            group.is_complete = true;
+            group.state = crate::types::GroupState::Complete;
+            vault::distribute_member_yield(env, &group)?;
+            events::emit_group_completed(env, group_id_cached);
+        } else {
+            // Advance to next cycle on the schedule, not from the payout time
+            group.current_cycle += 1;
+            group.cycle_start_time = utils::next_cycle_start(&group, current_time);
+            events::emit_cycle_advanced(
+                env,
+                group_id_cached,
+                group.current_cycle,
+                group.cycle_start_time,
+                utils::cycle_end_time(&group),
+            );
+        }
+
+        // Update storage (single write)
+        storage::store_group(env, group_id, &group);
+
+        // Roll prepaid credit into the newly opened cycle
+        if !group.is_complete {
+            let prepaid = storage::get_prepaid_members(env, group_id);
+            Self::roll_over_credits(env, &group, prepaid)?;
+        }
+
+        Ok(platform_fee)
+    }
+
+    /// Roll prepaid credit into a group's current cycle.
+    ///
+    /// Takes up to `ROLLOVER_BATCH_SIZE` members from the front of `queue` and
+    /// pays the cycle from the credit of each one whose credit covers it and who
+    /// has not paid yet. The rest of the queue is stored for `poke`; it is
+    /// dropped once the cycle's grace period is over.
+    ///
+    /// # Returns
+    /// How many members' contributions were paid from credit
+    fn roll_over_credits(
+        env: &Env,
+        group: &Group,
+        mut queue: Vec<Address>,
+    ) -> Result<u32, AjoError> {
+        let now = utils::get_current_timestamp(env);
+        if now > utils::get_grace_period_end(group) {
+            queue = Vec::new(env);
+        }
+
+        let mut applied = 0;
+        let batch = queue
+            .len()
+            .min(crate::security::limits::ROLLOVER_BATCH_SIZE);
+        for _ in 0..batch {
+            let member = queue.pop_front_unchecked();
+            if storage::has_contributed(env, group.id, group.current_cycle, &member) {
+                continue;
+            }
+            if storage::get_member_credit(env, group.id, &member)
+                >= utils::amount_due(env, group, &member, now)
+            {
+                Self::collect_contribution(env, group, &member, false)?;
+                applied += 1;
+            }
+        }
+        storage::set_credit_rollover(env, group.id, &queue);
+
+        Ok(applied)
+    }
+
+    /// Pull the current cycle's contribution from the next batch of opted-in
+    /// members who still owe it.
+    ///
+    /// Shared by `collect_due` and `poke`; see `collect_due` for how failures
+    /// are reported and how batches follow one another.
+    fn collect_auto_debits(
+        env: &Env,
+        group: &Group,
+    ) -> Result<Vec<crate::types::CollectionResult>, AjoError> {
+        let mut results = Vec::new(env);
+        let contributed = storage::get_contribution_bitmap(env, group.id, group.current_cycle);
+        let due = storage::get_auto_debits(env, group.id) & !contributed;
+        if due == 0 {
+            return Ok(results);
+        }
+
+        let members = storage::get_group_members(env, group.id);
+        let cursor = storage::get_collection_cursor(env, group.id);
+        let mut index = cursor;
+        while index < members.len()
+            && results.len() < crate::security::limits::COLLECTION_BATCH_SIZE
+        {
+            if !storage::is_bit_set(due, index) {
+                index += 1;
+                continue;
+            }
+
+            let member = members.get_unchecked(index);
+            let outcome = match Self::collect_contribution(env, group, &member, true) {
+                Ok(amount) => crate::types::CollectionOutcome::Collected(amount),
+                // A token that moved the wrong amount cannot be skipped per member,
+                // since its transfer has already happened
+                Err(AjoError::TransferFailed) => return Err(AjoError::TransferFailed),
+                Err(error) => {
+                    events::emit_auto_debit_failed(
+                        env,
+                        group.id,
+                        &member,
+                        group.current_cycle,
+                        error as u32,
+                    );
+                    crate::types::CollectionOutcome::Failed(error as u32)
+                }
+            };
+            results.push_back(crate::types::CollectionResult { member, outcome });
+            index += 1;
+        }
+
+        // Start again from the first member once no one after this batch owes
+        let owed_after = index < members.len() && due >> index != 0;
+        let next = if owed_after { index } else { 0 };
+        if next != cursor {
+            storage::set_collection_cursor(env, group.id, next);
+        }
+
+        Ok(results)
+    }
+
+    /// Mark the members who have not paid the current cycle as defaulters once
+    /// its grace period is over.
+    ///
+    /// Marks each cycle at most once and emits a `defaults` event listing them.
+    ///
+    /// # Returns
+    /// How many members were marked
+    fn mark_defaults(env: &Env, group: &Group) -> u32 {
+        let now = utils::get_current_timestamp(env);
+        if now <= utils::get_grace_period_end(group)
+            || storage::get_cycle_defaults(env, group.id, group.current_cycle) != 0
+        {
+            return 0;
+        }
+
+        let bitmap = storage::get_contribution_bitmap(env, group.id, group.current_cycle);
+        let mut defaults = 0u128;
+        let mut defaulters = Vec::new(env);
+        for (index, member) in storage::get_group_members(env, group.id).iter().enumerate() {
+            if !storage::is_bit_set(bitmap, index as u32) {
+                defaults |= 1u128 << index;
+                defaulters.push_back(member);
+            }
+        }
+        if defaults == 0 {
+            return 0;
+        }
+
+        storage::store_cycle_defaults(env, group.id, group.current_cycle, defaults);
+        let marked = defaulters.len();
+        events::emit_defaults_marked(env, group.id, group.current_cycle, defaulters);
+        marked
+    }
+
+    /// Carry out whatever transitions are due for a group.
+    ///
+    /// Shared by `poke` and `poke_many`; see `poke` for the steps.
+    fn poke_group(
+        env: &Env,
+        keeper: &Address,
+        group_id: u64,
+    ) -> Result<crate::types::PokeResult, AjoError> {
+        use crate::types::PokeAction;
+
+        let group = storage::get_group(env, group_id).ok_or(AjoError::GroupNotFound)?;
+        let mut actions = Vec::new(env);
+        let mut keeper_reward = 0;
+        let pause_state = pausable::get_pause_state(env);
+        let is_active = group.state == crate::types::GroupState::Active && !group.is_complete;
+
+        // Work left by an earlier batch fills this call on its own
+        let rollover = storage::get_credit_rollover(env, group_id);
+        let pending = if vault::is_distributing_member_yield(env, group_id)
+            && !pause_state.is_paused(PauseFlag::Payouts)
+        {
+            let paid = vault::distribute_member_yield(env, &group)?;
+            Some(PokeAction::YieldDistributed(paid))
+        } else if let Some(progress) = storage::get_refund_progress(env, group_id)
+            .filter(|_| !pause_state.is_paused(PauseFlag::Refunds))
+        {
+            let waiting = group.member_count - progress.next_member;
+            let remaining = Self::refund_batch(env, &group, progress, true)?;
+            Some(PokeAction::RefundsProcessed(waiting - remaining))
+        } else if is_active
+            && !rollover.is_empty()
+            && !pause_state.is_paused(PauseFlag::Contributions)
+        {
+            let applied = Self::roll_over_credits(env, &group, rollover)?;
+            Some(PokeAction::CreditsApplied(applied))
+        } else {
+            None
+        };
+
+        // Otherwise only active groups with seats left to pay have anything due
+        if let Some(action) = pending {
+            actions.push_back(action);
+        }
+        if !actions.is_empty() || !is_active {
+            return Ok(crate::types::PokeResult {
+                group_id,
+                actions,
+                keeper_reward,
+            });
+        }
+
+        if !pause_state.is_paused(PauseFlag::Contributions) {
+            let results = Self::collect_auto_debits(env, &group)?;
+            if !results.is_empty() {
+                let paid = results
+                    .iter()
+                    .filter(|result| {
+                        matches!(
+                            result.outcome,
+                            crate::types::CollectionOutcome::Collected(_)
+                        )
+                    })
+                    .count() as u32;
+                actions.push_back(PokeAction::ContributionsCollected(paid));
+                if paid > 0 {
+                    return Ok(crate::types::PokeResult {
+                        group_id,
+                        actions,
+                        keeper_reward,
+                    });
+                }
+            }
+        }
+
+        let marked = Self::mark_defaults(env, &group);
+        if marked > 0 {
+            actions.push_back(PokeAction::DefaultsMarked(marked));
+        }
+
+        if !pause_state.is_paused(PauseFlag::Payouts) {
+            if Self::ensure_payout_due(env, &group).is_ok() {
+                let platform_fee = Self::payout(env, group_id)?;
+                actions.push_back(PokeAction::PayoutExecuted(group.current_cycle));
+                if group.payout_index + 1 >= group.seat_count {
+                    actions.push_back(PokeAction::Completed);
+                }
+                keeper_reward = fees::pay_keeper_reward(env, &group, keeper, platform_fee)?;
+            } else {
+                let parked = vault::park(env, &group)?;
+                if parked > 0 {
+                    actions.push_back(PokeAction::FundsParked(parked));
+                }
+            }
+        }
+
+        Ok(crate::types::PokeResult {
+            group_id,
+            actions,
+            keeper_reward,
+        })
+    }
+
+    /// Contribute a member's dues to a group's current cycle.
+    ///
+    /// Shared by `contribute` and `contribute_many`, which authenticate the member.
+    fn contribute_to(env: &Env, member: &Address, group_id: u64) -> Result<(), AjoError> {
+        // Get group (single fetch)
+        let group = storage::get_group(env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Check the group is accepting contributions
+        Self::ensure_accepting_contributions(&group)?;
+
+        // Check if member
+        if !storage::is_group_member(env, group.id, member) {
+            return Err(AjoError::NotMember);
+        }
+
+        Self::collect_contribution(env, &group, member, false)?;
+
+        Ok(())
+    }
+
+    /// Check that a group's payout is due and goes to `member`.
+    fn ensure_claimable(env: &Env, member: &Address, group_id: u64) -> Result<(), AjoError> {
+        let group = storage::get_group(env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if !storage::is_group_member(env, group.id, member) {
+            return Err(AjoError::NotMember);
+        }
+        Self::ensure_payout_due(env, &group)?;
+        if storage::get_seat_holder(env, group_id, group.payout_index).as_ref() != Some(member) {
+            return Err(AjoError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Validate and store a new group template.
+    fn store_template(
+        env: &Env,
+        owner: Option<Address>,
+        name: soroban_sdk::String,
+        params: TemplateParams,
+        rules: soroban_sdk::String,
+    ) -> Result<u64, AjoError> {
+        templates::validate(env, &name, &params, &rules)?;
+        Self::validate_token(env, &params.token_address, params.contribution_amount)?;
+
+        let template = GroupTemplate {
+            id: storage::get_next_template_id(env),
+            owner,
+            name,
+            params,
+            rules,
+        };
+        storage::store_template(env, &template);
+        events::emit_template_saved(env, &template);
+
+        Ok(template.id)
+    }
+
+    /// Check that a token is registered, working and accepts the contribution amount.
+    fn validate_token(
+        env: &Env,
+        token_address: &Address,
+        contribution_amount: i128,
+    ) -> Result<(), AjoError> {
+        let token_info = storage::get_supported_token(env, token_address)
+            .ok_or(AjoError::InvalidTokenAddress)?;
+        if contribution_amount < token_info.min_contribution
+            || contribution_amount > token_info.max_contribution
+        {
+            return Err(AjoError::ContributionOutOfRange);
+        }
+        crate::token::read_token_metadata(env, token_address)?;
+        Ok(())
+    }
+
+    /// Check that a batch call names no more than `limit` groups.
+    fn ensure_batch_size(group_ids: &Vec<u64>, limit: u32) -> Result<(), AjoError> {
+        if group_ids.len() > limit {
+            return Err(AjoError::LimitExceeded);
+        }
+        Ok(())
+    }
+
+    /// Turn one group's outcome in a batch call into its result.
+    ///
+    /// `TransferFailed` is returned as an error instead, aborting the batch,
+    /// because the transfer behind it has already happened.
+    fn batch_result(
+        group_id: u64,
+        result: Result<(), AjoError>,
+    ) -> Result<crate::types::BatchResult, AjoError> {
+        let outcome = match result {
+            Ok(()) => crate::types::BatchOutcome::Succeeded,
+            Err(AjoError::TransferFailed) => return Err(AjoError::TransferFailed),
+            Err(error) => crate::types::BatchOutcome::Failed(error as u32),
+        };
+        Ok(crate::types::BatchResult { group_id, outcome })
+    }
+
+    /// Check that a group's current cycle can be paid out.
+    ///
+    /// Runs before anything is changed, so a payout that fails here leaves no
+    /// trace.
+    fn ensure_payout_due(env: &Env, group: &Group) -> Result<(), AjoError> {
+        // Check if group is cancelled
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+
+        // Check if group is frozen by its organizers
+        if group.state == crate::types::GroupState::Frozen {
+            return Err(AjoError::GroupFrozen);
+        }
+
+        // Check if group is complete
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+
+        // Check if all members have contributed
+        if !utils::all_members_contributed(env, group) {
+            return Err(AjoError::IncompleteContributions);
+        }
+
+        // Ensure grace period has expired before executing payout
+        let current_time = utils::get_current_timestamp(env);
+        let grace_end = utils::get_grace_period_end(group);
+        if current_time < grace_end {
+            // Still within grace period - delay payout
+            return Err(AjoError::OutsideCycleWindow);
+        }
+
+        Ok(())
+    }
+
+    /// Check that a group is in a state that accepts contributions.
+    fn ensure_accepting_contributions(group: &Group) -> Result<(), AjoError> {
+        // Check if group is complete
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+
+        // Check if group is cancelled
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+
+        // Check if group is frozen by its organizers
+        if group.state == crate::types::GroupState::Frozen {
+            return Err(AjoError::GroupFrozen);
+        }
+
+        Ok(())
+    }
+
+    /// Collect a member's contribution for the group's current cycle.
+    ///
+    /// Shared by `contribute`, `contribute_amount`, `collect_due` and the credit
+    /// roll-over in `execute_payout`. The amount due covers every seat the member
+    /// holds. Applies the late penalty inside the grace
+    /// period, covers as much of the amount due as possible from the member's
+    /// credit, moves the rest of the tokens (directly, or through the member's
+    /// allowance when `via_allowance` is set), and records the contribution,
+    /// penalty statistics and insurance premium.
+    ///
+    /// # Returns
+    /// The amount transferred from the member, excluding the part covered by credit
+    fn collect_contribution(
+        env: &Env,
+        group: &Group,
+        member: &Address,
+        via_allowance: bool,
+    ) -> Result<i128, AjoError> {
+        // Cache frequently accessed values
+        let group_id_cached = group.id;
+        let current_cycle = group.current_cycle;
+        let slot =
+            storage::get_member_slot(env, group_id_cached, member).ok_or(AjoError::NotMember)?;
+        let contribution_amount = group.contribution_amount * slot.seats as i128;
+
+        // Check if already contributed
+        let bitmap = storage::get_contribution_bitmap(env, group_id_cached, current_cycle);
+        if storage::is_bit_set(bitmap, slot.index) {
+            return Err(AjoError::AlreadyContributed);
+        }
+
+        // Reject contributions after the grace period; apply a penalty inside it
+        let now = utils::get_current_timestamp(env);
+        if now > utils::get_grace_period_end(group) {
+            return Err(AjoError::GracePeriodExpired);
+        }
+        let is_late = utils::is_within_grace_period(group, now);
+        let penalty_amount = if is_late {
+            utils::calculate_penalty(contribution_amount, group.penalty_rate)
+        } else {
+            0
+        };
+        let total_amount = contribution_amount + penalty_amount;
+
+        // Cover what we can from credit paid in earlier
+        let credit = storage::get_member_credit(env, group_id_cached, member);
+        let paid_from_credit = credit.min(total_amount);
+        let transfer_amount = total_amount - paid_from_credit;
+
+        // Get contract address for token transfer
+        let contract_address = env.current_contract_address();
+
+        if transfer_amount > 0 {
+            // Check member balance before transfer
+            crate::token::check_balance(env, &group.token_address, member, transfer_amount)?;
+
+            // Transfer tokens from member to contract, pulling through the member's
+            // allowance when the contract itself is collecting
+            if via_allowance {
+                crate::token::check_allowance(
+                    env,
+                    &group.token_address,
+                    member,
+                    &contract_address,
+                    transfer_amount,
+                )?;
+                crate::token::transfer_token_from(
+                    env,
+                    &group.token_address,
+                    &contract_address,
+                    member,
+                    &contract_address,
+                    transfer_amount,
+                )?;
+            } else {
+                crate::token::transfer_token(
+                    env,
+                    &group.token_address,
+                    member,
+                    &contract_address,
+                    transfer_amount,
+                )?;
+            }
+        }
+
+        if paid_from_credit > 0 {
+            storage::set_member_credit(env, group_id_cached, member, credit - paid_from_credit);
+            if credit >= contribution_amount && credit - paid_from_credit < contribution_amount {
+                storage::remove_prepaid_member(env, group_id_cached, member);
+            }
+        }
+
+        // Record contribution
+        storage::store_contribution(env, group_id_cached, current_cycle, slot.index, true);
+        storage::add_cycle_received(env, group_id_cached, current_cycle, contribution_amount);
+        let record = crate::types::ContributionRecord {
+            group_id: group_id_cached,
+            cycle: current_cycle,
+            member: member.clone(),
+            amount: contribution_amount,
+            timestamp: now,
+            is_late,
+            penalty_amount,
+            paid_from_credit,
+        };
+        storage::store_contribution_detail(env, group_id_cached, current_cycle, member, &record);
+
+        // Update the member's penalty statistics
+        let mut penalty_record = storage::get_member_penalty(env, group_id_cached, member)
+            .unwrap_or(crate::types::MemberPenaltyRecord {
+                member: member.clone(),
+                group_id: group_id_cached,
+                late_count: 0,
+                on_time_count: 0,
+                total_penalties: 0,
+                reliability_score: 100,
+            });
+        if is_late {
+            storage::mark_late_contribution(env, group_id_cached, current_cycle, slot.index);
+            penalty_record.late_count += 1;
+            penalty_record.total_penalties += penalty_amount;
+            storage::add_to_penalty_pool(env, group_id_cached, current_cycle, penalty_amount);
+            events::emit_late_contribution(
+                env,
+                group_id_cached,
+                member,
+                current_cycle,
+                contribution_amount,
+                penalty_amount,
+            );
+        } else {
+            penalty_record.on_time_count += 1;
+        }
+        penalty_record.reliability_score = utils::calculate_reliability_score(
+            penalty_record.on_time_count,
+            penalty_record.late_count,
+        );
+        storage::store_member_penalty(env, group_id_cached, member, &penalty_record);
+
+        // Insurance logic: Deduct premium if enabled
+        let mut premium = 0;
+        if group.insurance_config.is_enabled {
+            premium = crate::insurance::calculate_premium(
+                contribution_amount,
+                group.insurance_config.rate_bps,
+            );
+            if premium > 0 {
+                crate::insurance::deposit_to_pool(env, &group.token_address, premium);
+            }
+        }
+
+        // Emit event
+        events::emit_contribution_made(env, &record, premium);
+
+        Ok(transfer_amount)
     }
 }
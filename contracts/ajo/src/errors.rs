@@ -1,6 +1,10 @@
 use soroban_sdk::contracterror;
 
 /// Error codes for the Ajo contract.
+///
+/// Contract specs allow at most 50 error variants, so new failure conditions
+/// should reuse an existing code where its meaning fits. A released code keeps
+/// its meaning: codes that are no longer used are retired, never reassigned.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -8,13 +12,16 @@ pub enum AjoError {
     /// The specified group wasn't found in storage.
     GroupNotFound = 1,
 
-    /// Can't join because the group is already at its member limit.
+    /// Can't join because the group is already at its member limit, or the group
+    /// already has the maximum number of co-organizers.
     MaxMembersExceeded = 2,
 
-    /// This account is already part of the group.
+    /// This account is already part of the group, or already holds the
+    /// organizer role being granted.
     AlreadyMember = 3,
 
-    /// Address isn't a member of the group.
+    /// Address isn't a member of the group, or doesn't hold the organizer role
+    /// being revoked.
     NotMember = 4,
 
     /// You've already made your contribution for this cycle.
@@ -23,9 +30,6 @@ pub enum AjoError {
     /// We can't move forward until everyone has contributed.
     IncompleteContributions = 6,
 
-    /// Member has already been paid out.
-    AlreadyReceivedPayout = 7,
-
     /// All cycles for this group are finished.
     GroupComplete = 8,
 
@@ -44,7 +48,8 @@ pub enum AjoError {
     /// Member doesn't have enough balance.
     InsufficientBalance = 12,
 
-    /// The token transfer didn't go through.
+    /// The token transfer didn't go through, or moved a different amount than
+    /// requested (fee-on-transfer and rebasing tokens are not supported).
     TransferFailed = 13,
 
     /// This group has no members initialized.
@@ -53,7 +58,8 @@ pub enum AjoError {
     /// Only the creator or authorized members can do this.
     Unauthorized = 15,
 
-    /// Contribution outside active cycle window
+    /// Contribution outside active cycle window, or the cycle has not been
+    /// paid out yet.
     OutsideCycleWindow = 16,
 
     /// Negative amounts aren't allowed for contributions.
@@ -62,25 +68,28 @@ pub enum AjoError {
     /// This group has been cancelled by its creator.
     GroupCancelled = 19,
 
-    /// The contract has already been initialized.
+    /// The contract has already been initialized, or the group has already
+    /// been renewed.
     AlreadyInitialized = 20,
-    
+
     /// The contract is currently paused and cannot execute this operation.
     ContractPaused = 21,
-    
+
     /// Only the admin can pause the contract.
     UnauthorizedPause = 22,
-    
+
     /// Only the admin can unpause the contract.
     UnauthorizedUnpause = 23,
 
     /// Contribution is too late - grace period has expired.
     GracePeriodExpired = 24,
 
-    /// Invalid grace period duration.
+    /// Invalid grace period duration, or an invalid grace or voting period in
+    /// the validation limits.
     InvalidGracePeriod = 25,
 
-    /// Invalid penalty rate (must be 0-100).
+    /// Invalid penalty rate, or an invalid penalty rate or refund approval
+    /// threshold in the validation limits (must be 0-100).
     InvalidPenaltyRate = 26,
 
     /// Metadata field exceeds maximum length.
@@ -136,4 +145,35 @@ pub enum AjoError {
 
     /// Insurance pool for token not found.
     PoolNotFound = 44,
+
+    /// The group is frozen by its organizers and cannot execute this operation.
+    GroupFrozen = 45,
+
+    /// The cycle schedule is malformed (weekday, day of month, time of day or
+    /// UTC offset out of range).
+    InvalidSchedule = 58,
+
+    /// The contribution amount is outside the bounds registered for the token,
+    /// or more than the member still owes for the rotation, or the bounds
+    /// themselves are invalid.
+    ContributionOutOfRange = 59,
+
+    /// A platform or organizer fee exceeds the allowed maximum.
+    InvalidFee = 60,
+
+    /// A limit set on the contract was reached: a batch call was given more
+    /// items than it may process in one transaction, or creating or joining a
+    /// group would break one of the anti-spam limits (creation rate, running
+    /// groups as creator or member, or exposure in the group token).
+    ///
+    /// The limits in force are returned by `get_rate_limits` and
+    /// `get_exposure_limit`.
+    LimitExceeded = 55,
+
+    /// The group is not in the state the operation needs: it must be frozen
+    /// to be started, and complete to be renewed.
+    InvalidGroupState = 56,
+
+    /// Group template not found.
+    TemplateNotFound = 57,
 }
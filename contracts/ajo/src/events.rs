@@ -1,75 +1,590 @@
-use soroban_sdk::{symbol_short, Address, Env};
+//! Typed, versioned contract events
+//!
+//! Every state change publishes one of the `#[contracttype]` structs below as
+//! its event data. Topics are the event name, the schema version
+//! [`EVENT_VERSION`] and, for events about one group, template or token, its
+//! ID or address:
+//!
+//! ```text
+//! ("joined", 1, group_id) => MemberJoined { .. }
+//! ("platfee", 1)          => PlatformFeeSet { .. }
+//! ```
+//!
+//! Together the events carry enough to rebuild the contract's state without
+//! reading storage. A few values follow from events already published rather
+//! than being repeated:
+//! - Member credit: set by [`CreditDeposited`], reduced by each contribution's
+//!   `paid_from_credit` and cleared by a [`RefundProcessed`].
+//! - Fee balances: accrued from each [`PayoutExecuted`] record, less
+//!   [`KeeperRewarded`] and [`FeesWithdrawn`].
+//! - Penalty records: counted from each [`ContributionMade`] record, starting
+//!   from a [`ReliabilityCarried`] record in renewed groups.
+//! - Co-organizers: the new creator of an [`OwnershipTransferred`] group is
+//!   dropped from the list.
+//!
+//! Adding fields to an event or changing its meaning bumps [`EVENT_VERSION`].
 
-/// Emit an event when a group is created
-pub fn emit_group_created(
-    env: &Env,
-    group_id: u64,
-    creator: &Address,
-    contribution_amount: i128,
-    max_members: u32,
-) {
-    let topics = (symbol_short!("created"), group_id);
-    env.events()
-        .publish(topics, (creator, contribution_amount, max_members));
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, IntoVal, Symbol, Val, Vec};
+
+use crate::types::{
+    ContractConfig, ContributionRecord, Group, GroupMetadata, GroupTemplate, InsuranceClaim,
+    MemberPenaltyRecord, PauseState, PayoutRecord, RateLimits, RefundRecord, Renewal, TokenInfo,
+    YieldPolicy,
+};
+
+/// Version of the event schema, published as the second topic of every event.
+pub const EVENT_VERSION: u32 = 1;
+
+/// The contract was initialized with its admin.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractInitialized {
+    pub admin: Address,
 }
 
-/// Emit an event when a member joins a group
-pub fn emit_member_joined(env: &Env, group_id: u64, member: &Address) {
-    let topics = (symbol_short!("joined"), group_id);
-    env.events().publish(topics, member);
+/// The admin replaced the contract's Wasm code.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractUpgraded {
+    pub admin: Address,
+    pub new_wasm_hash: BytesN<32>,
 }
 
-/// Emit an event when a member contributes
-pub fn emit_contribution_made(
+/// A pause switch changed; carries every switch after the change.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseChanged {
+    pub state: PauseState,
+}
+
+/// The admin set the platform fee.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformFeeSet {
+    pub fee_bps: u32,
+}
+
+/// The admin appointed a fee manager.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeManagerSet {
+    pub manager: Address,
+}
+
+/// The admin set the keeper's share of the platform fee.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperRewardSet {
+    pub reward_bps: u32,
+}
+
+/// Accrued platform or organizer fees were withdrawn.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeesWithdrawn {
+    pub beneficiary: Address,
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+}
+
+/// The admin registered a token or updated its limits.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenSupported {
+    pub info: TokenInfo,
+}
+
+/// The admin removed a token from the registry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenRemoved {
+    pub token: Address,
+}
+
+/// The admin approved or revoked a vault.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultApproval {
+    pub vault: Address,
+    pub approved: bool,
+}
+
+/// The admin replaced the validation limits.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigUpdated {
+    pub config: ContractConfig,
+}
+
+/// The admin set the anti-spam limits on creating and joining groups.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateLimitsSet {
+    pub limits: RateLimits,
+}
+
+/// The admin set the per-member exposure limit for a token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExposureLimitSet {
+    pub token: Address,
+    /// Largest total a member may still owe across running groups, `0` for no limit.
+    pub limit: i128,
+}
+
+/// A group was created, directly, from a template or by renewal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupCreated {
+    /// The group as first stored.
+    pub group: Group,
+}
+
+/// A member joined a group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberJoined {
+    pub group_id: u64,
+    pub member: Address,
+    pub seats: u32,
+    /// Payout position the member's seats were inserted at.
+    pub position: u32,
+}
+
+/// A member paid a cycle's contribution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributionMade {
+    pub record: ContributionRecord,
+    /// Premium added to the token's insurance pool from the contribution.
+    pub insurance_premium: i128,
+}
+
+/// A contribution was paid during the grace period, with a penalty.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LateContribution {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycle: u32,
+    pub amount: i128,
+    pub penalty: i128,
+}
+
+/// A member paid an installment or prepayment into credit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreditDeposited {
+    pub group_id: u64,
+    pub member: Address,
+    pub amount: i128,
+    /// Credit held for the member after the deposit.
+    pub credit_balance: i128,
+}
+
+/// An auto-debit pull failed for a member.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoDebitFailed {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycle: u32,
+    /// The [`AjoError`](crate::errors::AjoError) code of the failure.
+    pub error: u32,
+}
+
+/// Members were marked in default after a cycle's grace period ended unpaid.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DefaultsMarked {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub defaulters: Vec<Address>,
+}
+
+/// A member opted in to or out of auto-debit contributions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoDebitChanged {
+    pub group_id: u64,
+    pub member: Address,
+    pub enabled: bool,
+}
+
+/// A cycle's payout was made.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutExecuted {
+    pub record: PayoutRecord,
+}
+
+/// Late penalties were paid out with a cycle's payout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PenaltyDistributed {
+    pub group_id: u64,
+    pub recipient: Address,
+    pub cycle: u32,
+    pub base_amount: i128,
+    pub penalty_bonus: i128,
+}
+
+/// A group moved on to its next cycle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CycleAdvanced {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub cycle_start_time: u64,
+    pub cycle_end_time: u64,
+}
+
+/// Every seat of a group has been paid out.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupCompleted {
+    pub group_id: u64,
+}
+
+/// An organizer cancelled a group before its first payout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupCancelled {
+    pub group_id: u64,
+    pub organizer: Address,
+    pub member_count: u32,
+    pub refund_per_member: i128,
+}
+
+/// A member asked for the group to be refunded.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundRequested {
+    pub group_id: u64,
+    pub requester: Address,
+    pub voting_deadline: u64,
+}
+
+/// A member voted on a refund request.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundVoted {
+    pub group_id: u64,
+    pub voter: Address,
+    pub in_favor: bool,
+}
+
+/// An approved refund request was executed and the group cancelled.
+///
+/// Published once the last member has been refunded.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundExecuted {
+    pub group_id: u64,
+    pub executor: Address,
+    pub total_refunded: i128,
+}
+
+/// A member was refunded.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundProcessed {
+    pub record: RefundRecord,
+}
+
+/// The admin refunded a group and cancelled it.
+///
+/// Published once the last member has been refunded.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyRefund {
+    pub group_id: u64,
+    pub admin: Address,
+    pub total_refunded: i128,
+}
+
+/// A group's metadata was set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataUpdated {
+    pub group_id: u64,
+    pub metadata: GroupMetadata,
+}
+
+/// The creator appointed a co-organizer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoOrganizerAdded {
+    pub group_id: u64,
+    pub co_organizer: Address,
+}
+
+/// The creator removed a co-organizer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoOrganizerRemoved {
+    pub group_id: u64,
+    pub co_organizer: Address,
+}
+
+/// An organizer froze a group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupFrozen {
+    pub group_id: u64,
+    pub organizer: Address,
+}
+
+/// An organizer started a frozen group, restarting its current cycle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupStarted {
+    pub group_id: u64,
+    pub organizer: Address,
+    pub cycle_start_time: u64,
+}
+
+/// The creator offered group ownership to a new address.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnershipOffered {
+    pub group_id: u64,
+    pub creator: Address,
+    pub new_creator: Address,
+}
+
+/// A new creator accepted group ownership.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnershipTransferred {
+    pub group_id: u64,
+    pub previous_creator: Address,
+    pub new_creator: Address,
+}
+
+/// An organizer set or cleared a group's vault.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupVaultSet {
+    pub group_id: u64,
+    pub vault: Option<Address>,
+    pub yield_policy: YieldPolicy,
+}
+
+/// A group's idle contributions were parked in its vault.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundsParked {
+    pub group_id: u64,
+    pub vault: Address,
+    pub amount: i128,
+    pub shares: i128,
+}
+
+/// A group's parked funds were recalled from its vault.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundsRecalled {
+    pub group_id: u64,
+    pub vault: Address,
+    pub principal: i128,
+    pub yield_amount: i128,
+}
+
+/// Yield held for a group's members was paid out to them.
+///
+/// Published once the last member has been paid.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberYieldPaid {
+    pub group_id: u64,
+    pub amount: i128,
+}
+
+/// A keeper was rewarded for executing a payout through `poke`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperRewarded {
+    pub group_id: u64,
+    pub keeper: Address,
+    pub amount: i128,
+}
+
+/// A completed group was renewed into a successor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupRenewed {
+    pub group_id: u64,
+    pub successor_id: u64,
+    pub renewal: Renewal,
+}
+
+/// A member's reliability record was carried into a renewed group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReliabilityCarried {
+    pub record: MemberPenaltyRecord,
+}
+
+/// A group template was published or saved.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateSaved {
+    pub template: GroupTemplate,
+}
+
+/// A group template was removed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateRemoved {
+    pub template_id: u64,
+}
+
+/// An insurance premium was added to a token's pool.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceDeposited {
+    pub token: Address,
+    pub amount: i128,
+    /// Pool balance after the deposit.
+    pub pool_balance: i128,
+}
+
+/// An insurance claim was filed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimFiled {
+    pub claim: InsuranceClaim,
+}
+
+/// The admin approved and paid, or rejected, an insurance claim.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimProcessed {
+    pub claim: InsuranceClaim,
+}
+
+/// Publish a contract-wide event under `(name, EVENT_VERSION)`.
+fn publish<D: IntoVal<Env, Val>>(env: &Env, name: Symbol, data: D) {
+    env.events().publish((name, EVENT_VERSION), data);
+}
+
+/// Publish an event about one group, template or token under
+/// `(name, EVENT_VERSION, key)`.
+fn publish_keyed<K, D>(env: &Env, name: Symbol, key: K, data: D)
+where
+    K: IntoVal<Env, Val>,
+    D: IntoVal<Env, Val>,
+{
+    env.events().publish((name, EVENT_VERSION, key), data);
+}
+
+/// Emit an event when the contract is initialized
+pub(crate) fn emit_contract_initialized(env: &Env, admin: &Address) {
+    let data = ContractInitialized {
+        admin: admin.clone(),
+    };
+    publish(env, symbol_short!("init"), data);
+}
+
+/// Emit an event when the admin upgrades the contract's Wasm code
+pub(crate) fn emit_contract_upgraded(env: &Env, admin: &Address, new_wasm_hash: &BytesN<32>) {
+    let data = ContractUpgraded {
+        admin: admin.clone(),
+        new_wasm_hash: new_wasm_hash.clone(),
+    };
+    publish(env, symbol_short!("upgraded"), data);
+}
+
+/// Emit an event when a group is created
+pub(crate) fn emit_group_created(env: &Env, group: &Group) {
+    let data = GroupCreated {
+        group: group.clone(),
+    };
+    publish_keyed(env, symbol_short!("created"), group.id, data);
+}
+
+/// Emit an event when a member joins a group with one or more seats
+pub(crate) fn emit_member_joined(
     env: &Env,
     group_id: u64,
     member: &Address,
-    cycle: u32,
-    amount: i128,
+    seats: u32,
+    position: u32,
 ) {
-    let topics = (symbol_short!("contrib"), group_id, cycle);
-    env.events().publish(topics, (member, amount));
+    let data = MemberJoined {
+        group_id,
+        member: member.clone(),
+        seats,
+        position,
+    };
+    publish_keyed(env, symbol_short!("joined"), group_id, data);
 }
 
-/// Emit an event when a payout is executed
-pub fn emit_payout_executed(
+/// Emit an event when a member contributes
+pub(crate) fn emit_contribution_made(
     env: &Env,
-    group_id: u64,
-    recipient: &Address,
-    cycle: u32,
-    amount: i128,
+    record: &ContributionRecord,
+    insurance_premium: i128,
 ) {
-    let topics = (symbol_short!("payout"), group_id, cycle);
-    env.events().publish(topics, (recipient, amount));
+    let data = ContributionMade {
+        record: record.clone(),
+        insurance_premium,
+    };
+    publish_keyed(env, symbol_short!("contrib"), record.group_id, data);
+}
+
+/// Emit an event when a payout is executed
+pub(crate) fn emit_payout_executed(env: &Env, record: &PayoutRecord) {
+    let data = PayoutExecuted {
+        record: record.clone(),
+    };
+    publish_keyed(env, symbol_short!("payout"), record.group_id, data);
 }
 
 /// Emit an event when a group completes all cycles
-pub fn emit_group_completed(env: &Env, group_id: u64) {
-    let topics = (symbol_short!("complete"), group_id);
-    env.events().publish(topics, ());
+pub(crate) fn emit_group_completed(env: &Env, group_id: u64) {
+    let data = GroupCompleted { group_id };
+    publish_keyed(env, symbol_short!("complete"), group_id, data);
 }
 
-/// Emit an event when a cycle advances
-pub fn emit_cycle_advanced(env: &Env, group_id: u64, new_cycle: u32, cycle_start_time: u64) {
-    let topics = (symbol_short!("cycle"), group_id);
-    env.events().publish(topics, (new_cycle, cycle_start_time));
+/// Emit an event when a cycle advances, with its scheduled start and end
+pub(crate) fn emit_cycle_advanced(
+    env: &Env,
+    group_id: u64,
+    new_cycle: u32,
+    cycle_start_time: u64,
+    cycle_end_time: u64,
+) {
+    let data = CycleAdvanced {
+        group_id,
+        cycle: new_cycle,
+        cycle_start_time,
+        cycle_end_time,
+    };
+    publish_keyed(env, symbol_short!("cycle"), group_id, data);
 }
 
-/// Emit an event when a group is cancelled by its creator
-pub fn emit_group_cancelled(
+/// Emit an event when a group is cancelled by its creator or a co-organizer
+pub(crate) fn emit_group_cancelled(
     env: &Env,
     group_id: u64,
-    creator: &Address,
+    organizer: &Address,
     member_count: u32,
     refund_per_member: i128,
 ) {
-    let topics = (symbol_short!("cancel"), group_id);
-    env.events()
-        .publish(topics, (creator, member_count, refund_per_member));
+    let data = GroupCancelled {
+        group_id,
+        organizer: organizer.clone(),
+        member_count,
+        refund_per_member,
+    };
+    publish_keyed(env, symbol_short!("cancel"), group_id, data);
 }
 
 /// Emit an event when a late contribution is made with penalty
-pub fn emit_late_contribution(
+pub(crate) fn emit_late_contribution(
     env: &Env,
     group_id: u64,
     member: &Address,
@@ -77,12 +592,18 @@ pub fn emit_late_contribution(
     amount: i128,
     penalty: i128,
 ) {
-    let topics = (symbol_short!("late"), group_id, cycle);
-    env.events().publish(topics, (member, amount, penalty));
+    let data = LateContribution {
+        group_id,
+        member: member.clone(),
+        cycle,
+        amount,
+        penalty,
+    };
+    publish_keyed(env, symbol_short!("late"), group_id, data);
 }
 
 /// Emit an event when penalties are distributed with payout
-pub fn emit_penalty_distributed(
+pub(crate) fn emit_penalty_distributed(
     env: &Env,
     group_id: u64,
     recipient: &Address,
@@ -90,52 +611,428 @@ pub fn emit_penalty_distributed(
     base_amount: i128,
     penalty_bonus: i128,
 ) {
-    let topics = (symbol_short!("pendistr"), group_id, cycle);
-    env.events()
-        .publish(topics, (recipient, base_amount, penalty_bonus));
+    let data = PenaltyDistributed {
+        group_id,
+        recipient: recipient.clone(),
+        cycle,
+        base_amount,
+        penalty_bonus,
+    };
+    publish_keyed(env, symbol_short!("pendistr"), group_id, data);
 }
 
 /// Emit an event when a refund request is created
-pub fn emit_refund_requested(
+pub(crate) fn emit_refund_requested(
     env: &Env,
     group_id: u64,
     requester: &Address,
     voting_deadline: u64,
 ) {
-    let topics = (symbol_short!("refreq"), group_id);
-    env.events().publish(topics, (requester, voting_deadline));
+    let data = RefundRequested {
+        group_id,
+        requester: requester.clone(),
+        voting_deadline,
+    };
+    publish_keyed(env, symbol_short!("refreq"), group_id, data);
 }
 
 /// Emit an event when a member votes on a refund request
-pub fn emit_refund_vote(
+pub(crate) fn emit_refund_vote(env: &Env, group_id: u64, voter: &Address, in_favor: bool) {
+    let data = RefundVoted {
+        group_id,
+        voter: voter.clone(),
+        in_favor,
+    };
+    publish_keyed(env, symbol_short!("refvote"), group_id, data);
+}
+
+/// Emit an event when an approved refund request is executed
+pub(crate) fn emit_refund_executed(
     env: &Env,
     group_id: u64,
-    voter: &Address,
-    in_favor: bool,
+    executor: &Address,
+    total_refunded: i128,
 ) {
-    let topics = (symbol_short!("refvote"), group_id);
-    env.events().publish(topics, (voter, in_favor));
+    let data = RefundExecuted {
+        group_id,
+        executor: executor.clone(),
+        total_refunded,
+    };
+    publish_keyed(env, symbol_short!("refexec"), group_id, data);
 }
 
 /// Emit an event when a refund is processed
-pub fn emit_refund_processed(
+pub(crate) fn emit_refund_processed(env: &Env, record: &RefundRecord) {
+    let data = RefundProcessed {
+        record: record.clone(),
+    };
+    publish_keyed(env, symbol_short!("refund"), record.group_id, data);
+}
+
+/// Emit an event when an emergency refund is executed
+pub(crate) fn emit_emergency_refund(
+    env: &Env,
+    group_id: u64,
+    admin: &Address,
+    total_refunded: i128,
+) {
+    let data = EmergencyRefund {
+        group_id,
+        admin: admin.clone(),
+        total_refunded,
+    };
+    publish_keyed(env, symbol_short!("emrefund"), group_id, data);
+}
+
+/// Emit an event when any pause switch changes
+pub(crate) fn emit_pause_state_changed(env: &Env, state: &PauseState) {
+    let data = PauseChanged { state: *state };
+    publish(env, symbol_short!("pause"), data);
+}
+
+/// Emit an event when a group's metadata is set
+pub(crate) fn emit_metadata_updated(env: &Env, group_id: u64, metadata: &GroupMetadata) {
+    let data = MetadataUpdated {
+        group_id,
+        metadata: metadata.clone(),
+    };
+    publish_keyed(env, symbol_short!("metadata"), group_id, data);
+}
+
+/// Emit an event when the creator appoints a co-organizer
+pub(crate) fn emit_co_organizer_added(env: &Env, group_id: u64, co_organizer: &Address) {
+    let data = CoOrganizerAdded {
+        group_id,
+        co_organizer: co_organizer.clone(),
+    };
+    publish_keyed(env, symbol_short!("orgadd"), group_id, data);
+}
+
+/// Emit an event when the creator removes a co-organizer
+pub(crate) fn emit_co_organizer_removed(env: &Env, group_id: u64, co_organizer: &Address) {
+    let data = CoOrganizerRemoved {
+        group_id,
+        co_organizer: co_organizer.clone(),
+    };
+    publish_keyed(env, symbol_short!("orgrem"), group_id, data);
+}
+
+/// Emit an event when an organizer freezes a group
+pub(crate) fn emit_group_frozen(env: &Env, group_id: u64, organizer: &Address) {
+    let data = GroupFrozen {
+        group_id,
+        organizer: organizer.clone(),
+    };
+    publish_keyed(env, symbol_short!("frozen"), group_id, data);
+}
+
+/// Emit an event when an organizer starts a frozen group
+pub(crate) fn emit_group_started(
+    env: &Env,
+    group_id: u64,
+    organizer: &Address,
+    cycle_start_time: u64,
+) {
+    let data = GroupStarted {
+        group_id,
+        organizer: organizer.clone(),
+        cycle_start_time,
+    };
+    publish_keyed(env, symbol_short!("started"), group_id, data);
+}
+
+/// Emit an event when the creator offers group ownership to a new address
+pub(crate) fn emit_ownership_offered(
+    env: &Env,
+    group_id: u64,
+    creator: &Address,
+    new_creator: &Address,
+) {
+    let data = OwnershipOffered {
+        group_id,
+        creator: creator.clone(),
+        new_creator: new_creator.clone(),
+    };
+    publish_keyed(env, symbol_short!("ownoffer"), group_id, data);
+}
+
+/// Emit an event when a new creator accepts group ownership
+pub(crate) fn emit_ownership_transferred(
+    env: &Env,
+    group_id: u64,
+    previous_creator: &Address,
+    new_creator: &Address,
+) {
+    let data = OwnershipTransferred {
+        group_id,
+        previous_creator: previous_creator.clone(),
+        new_creator: new_creator.clone(),
+    };
+    publish_keyed(env, symbol_short!("owner"), group_id, data);
+}
+
+/// Emit an event when a member opts in to or out of auto-debit contributions
+pub(crate) fn emit_auto_debit_changed(env: &Env, group_id: u64, member: &Address, enabled: bool) {
+    let data = AutoDebitChanged {
+        group_id,
+        member: member.clone(),
+        enabled,
+    };
+    publish_keyed(env, symbol_short!("autodebit"), group_id, data);
+}
+
+/// Emit an event when an auto-debit pull fails for a member
+pub(crate) fn emit_auto_debit_failed(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+    cycle: u32,
+    error: u32,
+) {
+    let data = AutoDebitFailed {
+        group_id,
+        member: member.clone(),
+        cycle,
+        error,
+    };
+    publish_keyed(env, symbol_short!("debitfail"), group_id, data);
+}
+
+/// Emit an event when members are marked in default for a cycle
+pub(crate) fn emit_defaults_marked(env: &Env, group_id: u64, cycle: u32, defaulters: Vec<Address>) {
+    let data = DefaultsMarked {
+        group_id,
+        cycle,
+        defaulters,
+    };
+    publish_keyed(env, symbol_short!("defaults"), group_id, data);
+}
+
+/// Emit an event when a member pays an installment or prepayment into credit
+pub(crate) fn emit_credit_deposited(
     env: &Env,
     group_id: u64,
     member: &Address,
     amount: i128,
-    reason: u32,
+    credit_balance: i128,
 ) {
-    let topics = (symbol_short!("refund"), group_id);
-    env.events().publish(topics, (member, amount, reason));
+    let data = CreditDeposited {
+        group_id,
+        member: member.clone(),
+        amount,
+        credit_balance,
+    };
+    publish_keyed(env, symbol_short!("credit"), group_id, data);
 }
 
-/// Emit an event when an emergency refund is executed
-pub fn emit_emergency_refund(
+/// Emit an event when the admin changes the platform fee
+pub(crate) fn emit_platform_fee_set(env: &Env, fee_bps: u32) {
+    publish(env, symbol_short!("platfee"), PlatformFeeSet { fee_bps });
+}
+
+/// Emit an event when the admin appoints a fee manager
+pub(crate) fn emit_fee_manager_set(env: &Env, manager: &Address) {
+    let data = FeeManagerSet {
+        manager: manager.clone(),
+    };
+    publish(env, symbol_short!("feemgr"), data);
+}
+
+/// Emit an event when accrued fees are withdrawn
+pub(crate) fn emit_fees_withdrawn(
+    env: &Env,
+    beneficiary: &Address,
+    token: &Address,
+    to: &Address,
+    amount: i128,
+) {
+    let data = FeesWithdrawn {
+        beneficiary: beneficiary.clone(),
+        token: token.clone(),
+        to: to.clone(),
+        amount,
+    };
+    publish_keyed(env, symbol_short!("feewd"), token, data);
+}
+
+/// Emit an event when the admin registers a token or updates its limits
+pub(crate) fn emit_token_supported(env: &Env, info: &TokenInfo) {
+    let data = TokenSupported { info: info.clone() };
+    publish_keyed(env, symbol_short!("tokenadd"), info.address.clone(), data);
+}
+
+/// Emit an event when the admin removes a token from the registry
+pub(crate) fn emit_token_removed(env: &Env, token: &Address) {
+    let data = TokenRemoved {
+        token: token.clone(),
+    };
+    publish_keyed(env, symbol_short!("tokenrem"), token, data);
+}
+
+/// Emit an event when the admin replaces the validation limits
+pub(crate) fn emit_config_updated(env: &Env, config: &ContractConfig) {
+    publish(
+        env,
+        symbol_short!("config"),
+        ConfigUpdated { config: *config },
+    );
+}
+
+/// Emit an event when the admin sets the anti-spam limits
+pub(crate) fn emit_rate_limits_set(env: &Env, limits: &RateLimits) {
+    publish(
+        env,
+        symbol_short!("ratelim"),
+        RateLimitsSet { limits: *limits },
+    );
+}
+
+/// Emit an event when the admin sets a token's per-member exposure limit
+pub(crate) fn emit_exposure_limit_set(env: &Env, token: &Address, limit: i128) {
+    let data = ExposureLimitSet {
+        token: token.clone(),
+        limit,
+    };
+    publish_keyed(env, symbol_short!("explimit"), token, data);
+}
+
+/// Emit an event when the admin approves or revokes a vault
+pub(crate) fn emit_vault_approval(env: &Env, vault: &Address, approved: bool) {
+    let data = VaultApproval {
+        vault: vault.clone(),
+        approved,
+    };
+    publish_keyed(env, symbol_short!("vaultok"), vault, data);
+}
+
+/// Emit an event when an organizer sets or clears a group's vault
+pub(crate) fn emit_group_vault_set(
     env: &Env,
     group_id: u64,
-    admin: &Address,
-    total_refunded: i128,
+    vault: &Option<Address>,
+    yield_policy: YieldPolicy,
 ) {
-    let topics = (symbol_short!("emrefund"), group_id);
-    env.events().publish(topics, (admin, total_refunded));
+    let data = GroupVaultSet {
+        group_id,
+        vault: vault.clone(),
+        yield_policy,
+    };
+    publish_keyed(env, symbol_short!("vaultset"), group_id, data);
+}
+
+/// Emit an event when a group's idle contributions are parked in its vault
+pub(crate) fn emit_funds_parked(
+    env: &Env,
+    group_id: u64,
+    vault: &Address,
+    amount: i128,
+    shares: i128,
+) {
+    let data = FundsParked {
+        group_id,
+        vault: vault.clone(),
+        amount,
+        shares,
+    };
+    publish_keyed(env, symbol_short!("parked"), group_id, data);
+}
+
+/// Emit an event when a group's parked funds are recalled from its vault
+pub(crate) fn emit_funds_recalled(
+    env: &Env,
+    group_id: u64,
+    vault: &Address,
+    principal: i128,
+    yield_amount: i128,
+) {
+    let data = FundsRecalled {
+        group_id,
+        vault: vault.clone(),
+        principal,
+        yield_amount,
+    };
+    publish_keyed(env, symbol_short!("recalled"), group_id, data);
+}
+
+/// Emit an event when yield held for the members is paid out to them
+pub(crate) fn emit_member_yield_paid(env: &Env, group_id: u64, amount: i128) {
+    let data = MemberYieldPaid { group_id, amount };
+    publish_keyed(env, symbol_short!("yieldpaid"), group_id, data);
+}
+
+/// Emit an event when the admin sets the keeper reward
+pub(crate) fn emit_keeper_reward_set(env: &Env, reward_bps: u32) {
+    publish(
+        env,
+        symbol_short!("keeprwd"),
+        KeeperRewardSet { reward_bps },
+    );
+}
+
+/// Emit an event when a keeper is rewarded for executing a payout through `poke`
+pub(crate) fn emit_keeper_rewarded(env: &Env, group_id: u64, keeper: &Address, amount: i128) {
+    let data = KeeperRewarded {
+        group_id,
+        keeper: keeper.clone(),
+        amount,
+    };
+    publish_keyed(env, symbol_short!("keeper"), group_id, data);
+}
+
+/// Emit an event when a completed group is renewed into a successor
+pub(crate) fn emit_group_renewed(env: &Env, group_id: u64, successor_id: u64, renewal: &Renewal) {
+    let data = GroupRenewed {
+        group_id,
+        successor_id,
+        renewal: renewal.clone(),
+    };
+    publish_keyed(env, symbol_short!("renewed"), group_id, data);
+}
+
+/// Emit an event when a member's reliability record is carried into a renewed group
+pub(crate) fn emit_reliability_carried(env: &Env, record: &MemberPenaltyRecord) {
+    let data = ReliabilityCarried {
+        record: record.clone(),
+    };
+    publish_keyed(env, symbol_short!("carried"), record.group_id, data);
+}
+
+/// Emit an event when a group template is published or saved
+pub(crate) fn emit_template_saved(env: &Env, template: &GroupTemplate) {
+    let data = TemplateSaved {
+        template: template.clone(),
+    };
+    publish_keyed(env, symbol_short!("tplsaved"), template.id, data);
+}
+
+/// Emit an event when a group template is removed
+pub(crate) fn emit_template_removed(env: &Env, template_id: u64) {
+    let data = TemplateRemoved { template_id };
+    publish_keyed(env, symbol_short!("tplremove"), template_id, data);
+}
+
+/// Emit an event when an insurance premium is added to a token's pool
+pub(crate) fn emit_insurance_deposited(env: &Env, token: &Address, amount: i128, balance: i128) {
+    let data = InsuranceDeposited {
+        token: token.clone(),
+        amount,
+        pool_balance: balance,
+    };
+    publish_keyed(env, symbol_short!("insdep"), token, data);
+}
+
+/// Emit an event when an insurance claim is filed
+pub(crate) fn emit_claim_filed(env: &Env, claim: &InsuranceClaim) {
+    let data = ClaimFiled {
+        claim: claim.clone(),
+    };
+    publish_keyed(env, symbol_short!("claimfile"), claim.group_id, data);
+}
+
+/// Emit an event when an insurance claim is approved and paid, or rejected
+pub(crate) fn emit_claim_processed(env: &Env, claim: &InsuranceClaim) {
+    let data = ClaimProcessed {
+        claim: claim.clone(),
+    };
+    publish_keyed(env, symbol_short!("claimproc"), claim.group_id, data);
 }
@@ -0,0 +1,113 @@
+//! Platform and organizer fees
+//!
+//! Two fees, both in basis points, are taken from every payout in
+//! [`execute_payout`](crate::contract::AjoContract::execute_payout):
+//!
+//! - The **platform fee** is set by the admin and applies to every group. It accrues
+//!   in a per-token treasury that the fee manager withdraws with
+//!   [`withdraw_fees`](crate::contract::AjoContract::withdraw_fees).
+//! - The **organizer fee** is chosen per group at creation. It accrues per organizer
+//!   and token, and the organizer withdraws it with
+//!   [`withdraw_organizer_fees`](crate::contract::AjoContract::withdraw_organizer_fees).
+//!
+//! The combined rate never exceeds [`MAX_TOTAL_FEE_BPS`]. Both rates are validated
+//! against it when set, and the organizer fee is trimmed at payout time if the
+//! platform fee has since been raised.
+//!
+//! The fee manager defaults to the admin until one is appointed.
+//!
+//! The admin may also set a **keeper reward**: a share of the platform fee on
+//! each payout executed through [`poke`](crate::contract::AjoContract::poke),
+//! paid straight out of the treasury to the keeper that triggered it.
+
+use soroban_sdk::{Address, Env};
+
+use crate::errors::AjoError;
+use crate::events;
+use crate::storage;
+use crate::types::Group;
+
+/// Maximum combined platform and organizer fee, in basis points (10%).
+pub const MAX_TOTAL_FEE_BPS: u32 = 1_000;
+
+/// Calculates a fee of `fee_bps` basis points on `amount`, rounded down.
+pub fn calculate_fee(amount: i128, fee_bps: u32) -> i128 {
+    amount * (fee_bps as i128) / 10_000
+}
+
+/// Validates that a platform and organizer fee may be charged together.
+///
+/// # Errors
+/// * `InvalidFee` - If the combined rate exceeds [`MAX_TOTAL_FEE_BPS`]
+pub fn validate_fees(platform_fee_bps: u32, organizer_fee_bps: u32) -> Result<(), AjoError> {
+    if platform_fee_bps.saturating_add(organizer_fee_bps) > MAX_TOTAL_FEE_BPS {
+        return Err(AjoError::InvalidFee);
+    }
+    Ok(())
+}
+
+/// Splits the fees out of a group's payout.
+///
+/// # Returns
+/// `(platform_fee, organizer_fee)` in token units
+pub fn calculate_payout_fees(env: &Env, group: &Group, payout_amount: i128) -> (i128, i128) {
+    let platform_fee_bps = storage::get_platform_fee_bps(env);
+    let organizer_fee_bps = group
+        .organizer_fee_bps
+        .min(MAX_TOTAL_FEE_BPS.saturating_sub(platform_fee_bps));
+    (
+        calculate_fee(payout_amount, platform_fee_bps),
+        calculate_fee(payout_amount, organizer_fee_bps),
+    )
+}
+
+/// Credits the fees taken from one of a group's payouts.
+///
+/// The platform fee goes to the treasury for the group token and the organizer
+/// fee to the group creator's balance for that token.
+pub fn accrue_fees(env: &Env, group: &Group, platform_fee: i128, organizer_fee: i128) {
+    let token = &group.token_address;
+    if platform_fee > 0 {
+        let balance = storage::get_treasury_balance(env, token);
+        storage::set_treasury_balance(env, token, balance + platform_fee);
+    }
+    if organizer_fee > 0 {
+        let balance = storage::get_organizer_fees(env, &group.creator, token);
+        storage::set_organizer_fees(env, &group.creator, token, balance + organizer_fee);
+    }
+}
+
+/// Pays a keeper its share of the platform fee taken from a payout.
+///
+/// # Returns
+/// The reward paid, which is `0` when no keeper reward is set
+///
+/// # Errors
+/// * `TransferFailed` - If the reward transfer fails
+pub fn pay_keeper_reward(
+    env: &Env,
+    group: &Group,
+    keeper: &Address,
+    platform_fee: i128,
+) -> Result<i128, AjoError> {
+    let reward = calculate_fee(platform_fee, storage::get_keeper_reward_bps(env));
+    if reward <= 0 {
+        return Ok(0);
+    }
+
+    let token = &group.token_address;
+    let balance = storage::get_treasury_balance(env, token);
+    storage::set_treasury_balance(env, token, balance - reward);
+    crate::token::transfer_token(env, token, &env.current_contract_address(), keeper, reward)?;
+    events::emit_keeper_rewarded(env, group.id, keeper, reward);
+
+    Ok(reward)
+}
+
+/// Returns the address allowed to withdraw platform fees.
+///
+/// Falls back to the admin when no fee manager has been appointed, and
+/// returns `None` before the contract is initialized.
+pub fn get_fee_manager(env: &Env) -> Option<Address> {
+    storage::get_fee_manager(env).or_else(|| storage::get_admin(env))
+}
@@ -0,0 +1,105 @@
+//! Historical views of a group's cycles
+//!
+//! The contribution matrix lays out members'
+//! [`ContributionState`](crate::types::ContributionState) for a range of
+//! cycles. Each cycle keeps bitmaps over the member list of who contributed,
+//! who paid late and whose default a paid insurance claim covered, so a row
+//! costs three reads whatever the group size. Pages are bounded in both
+//! directions: at most [`MATRIX_PAGE_CYCLES`] cycles of at most
+//! [`MAX_PAGE_SIZE`] members.
+//! Cycle summaries add up what a paid-out cycle collected and name its
+//! recipient from the stored [`PayoutRecord`](crate::types::PayoutRecord).
+
+use soroban_sdk::{Env, Vec};
+
+use crate::errors::AjoError;
+use crate::security::limits::{MATRIX_PAGE_CYCLES, MAX_PAGE_SIZE};
+use crate::storage;
+use crate::types::{ContributionState, CycleContributions, CycleSummary, Group};
+use crate::utils;
+
+/// Builds the contribution matrix for cycles `from_cycle..=to_cycle` and the
+/// members at `start_member..start_member + member_limit` of the member list.
+///
+/// The cycles are clipped to those the group has reached, and to at most
+/// [`MATRIX_PAGE_CYCLES`] from `from_cycle`; the members to the member list,
+/// and to at most [`MAX_PAGE_SIZE`]. An empty range returns no rows.
+pub fn contribution_matrix(
+    env: &Env,
+    group: &Group,
+    from_cycle: u32,
+    to_cycle: u32,
+    start_member: u32,
+    member_limit: u32,
+) -> Vec<CycleContributions> {
+    let from_cycle = from_cycle.max(1);
+    let to_cycle = to_cycle
+        .min(group.current_cycle)
+        .min(from_cycle.saturating_add(MATRIX_PAGE_CYCLES - 1));
+    let end_member = start_member
+        .saturating_add(member_limit.min(MAX_PAGE_SIZE))
+        .min(group.member_count);
+
+    let now = utils::get_current_timestamp(env);
+    let current_overdue = now > utils::get_grace_period_end(group);
+
+    let mut rows = Vec::new(env);
+    for cycle in from_cycle..=to_cycle {
+        let paid = storage::get_contribution_bitmap(env, group.id, cycle);
+        let late = storage::get_late_contributions(env, group.id, cycle);
+        let covered = storage::get_insurance_covered(env, group.id, cycle);
+
+        // Earlier cycles were closed by a payout; the current one by its grace period
+        let overdue = cycle < group.current_cycle || current_overdue;
+        let mut states = Vec::new(env);
+        for index in start_member..end_member {
+            let state = if storage::is_bit_set(paid, index) {
+                if storage::is_bit_set(late, index) {
+                    ContributionState::Late
+                } else {
+                    ContributionState::OnTime
+                }
+            } else if storage::is_bit_set(covered, index) {
+                ContributionState::CoveredByInsurance
+            } else if overdue {
+                ContributionState::Missed
+            } else {
+                ContributionState::Pending
+            };
+            states.push_back(state);
+        }
+        rows.push_back(CycleContributions { cycle, states });
+    }
+    rows
+}
+
+/// Summarises a cycle that has been paid out.
+///
+/// # Errors
+/// * `OutsideCycleWindow` - If the cycle has no payout yet
+pub fn cycle_summary(env: &Env, group: &Group, cycle: u32) -> Result<CycleSummary, AjoError> {
+    let payout =
+        storage::get_payout_record(env, group.id, cycle).ok_or(AjoError::OutsideCycleWindow)?;
+
+    // Only members marked in the cycle's bitmap have a contribution record
+    let bitmap = storage::get_contribution_bitmap(env, group.id, cycle);
+    let mut collected = 0;
+    for (index, member) in storage::get_group_members(env, group.id).iter().enumerate() {
+        if !storage::is_bit_set(bitmap, index as u32) {
+            continue;
+        }
+        if let Some(record) = storage::get_contribution_detail(env, group.id, cycle, &member) {
+            collected += record.amount + record.penalty_amount;
+        }
+    }
+
+    Ok(CycleSummary {
+        group_id: group.id,
+        cycle,
+        collected,
+        penalty_pool: storage::get_cycle_penalty_pool(env, group.id, cycle),
+        recipient: payout.member,
+        payout_amount: payout.amount,
+        paid_at: payout.timestamp,
+    })
+}
@@ -1,8 +1,8 @@
-use soroban_sdk::{Address, Env, Vec};
-use crate::storage;
-use crate::types::{InsuranceConfig, InsuranceClaim, ClaimStatus, InsurancePool, Group};
 use crate::errors::AjoError;
-use crate::utils;
+use crate::events;
+use crate::storage;
+use crate::types::{ClaimStatus, Group, InsuranceClaim, InsurancePool};
+use soroban_sdk::{Address, Env};
 
 /// Calculates the insurance premium for a contribution.
 pub fn calculate_premium(amount: i128, rate_bps: u32) -> i128 {
@@ -18,6 +18,7 @@ pub fn deposit_to_pool(env: &Env, token: &Address, amount: i128) {
     });
     pool.balance += amount;
     storage::store_insurance_pool(env, token, &pool);
+    events::emit_insurance_deposited(env, token, amount, pool.balance);
 }
 
 /// Records a claim against the insurance pool.
@@ -44,14 +45,16 @@ pub fn file_claim(
     };
 
     storage::store_insurance_claim(env, claim_id, &claim);
+    events::emit_claim_filed(env, &claim);
 
     // Update pool stats
     let group = storage::get_group(env, group_id).ok_or(AjoError::GroupNotFound)?;
-    let mut pool = storage::get_insurance_pool(env, &group.token_address).unwrap_or(InsurancePool {
-        balance: 0,
-        total_payouts: 0,
-        pending_claims_count: 0,
-    });
+    let mut pool =
+        storage::get_insurance_pool(env, &group.token_address).unwrap_or(InsurancePool {
+            balance: 0,
+            total_payouts: 0,
+            pending_claims_count: 0,
+        });
     pool.pending_claims_count += 1;
     storage::store_insurance_pool(env, &group.token_address, &pool);
 
@@ -61,13 +64,14 @@ pub fn file_claim(
 /// Processes a claim and executes payout if approved.
 pub fn process_claim(env: &Env, claim_id: u64, approved: bool) -> Result<(), AjoError> {
     let mut claim = storage::get_insurance_claim(env, claim_id).ok_or(AjoError::InvalidClaim)?;
-    
+
     if claim.status != ClaimStatus::Pending {
         return Err(AjoError::ClaimAlreadyProcessed);
     }
 
     let group = storage::get_group(env, claim.group_id).ok_or(AjoError::GroupNotFound)?;
-    let mut pool = storage::get_insurance_pool(env, &group.token_address).ok_or(AjoError::PoolNotFound)?;
+    let mut pool =
+        storage::get_insurance_pool(env, &group.token_address).ok_or(AjoError::PoolNotFound)?;
 
     if approved {
         if pool.balance < claim.amount {
@@ -78,6 +82,9 @@ pub fn process_claim(env: &Env, claim_id: u64, approved: bool) -> Result<(), Ajo
         pool.balance -= claim.amount;
         pool.total_payouts += claim.amount;
         claim.status = ClaimStatus::Paid;
+        if let Some(slot) = storage::get_member_slot(env, claim.group_id, &claim.defaulter) {
+            storage::mark_insurance_covered(env, claim.group_id, claim.cycle, slot.index);
+        }
 
         // Transfer tokens from contract to claimant
         crate::token::transfer_token(
@@ -94,6 +101,7 @@ pub fn process_claim(env: &Env, claim_id: u64, approved: bool) -> Result<(), Ajo
     pool.pending_claims_count -= 1;
     storage::store_insurance_pool(env, &group.token_address, &pool);
     storage::store_insurance_claim(env, claim_id, &claim);
+    events::emit_claim_processed(env, &claim);
 
     Ok(())
 }
@@ -102,10 +110,11 @@ pub fn process_claim(env: &Env, claim_id: u64, approved: bool) -> Result<(), Ajo
 /// In a real scenario, this would check if the defaulter actually missed their contribution.
 pub fn verify_claim(env: &Env, claim_id: u64) -> Result<bool, AjoError> {
     let claim = storage::get_insurance_claim(env, claim_id).ok_or(AjoError::InvalidClaim)?;
-    
+
     // Automatic verification: Check if defaulter has contributed in that cycle
-    let has_contributed = storage::has_contributed(env, claim.group_id, claim.cycle, &claim.defaulter);
-    
+    let has_contributed =
+        storage::has_contributed(env, claim.group_id, claim.cycle, &claim.defaulter);
+
     // If they haven't contributed, the claim is valid
     Ok(!has_contributed)
 }
@@ -120,7 +129,7 @@ pub fn auto_process_claim(env: &Env, claim_id: u64) -> Result<(), AjoError> {
 }
 
 /// Calculates risk score for a member based on history.
-pub fn get_member_risk_score(env: &Env, member: &Address) -> u32 {
+pub fn get_member_risk_score(_env: &Env, _member: &Address) -> u32 {
     // This is a simplified version. In practice, it would aggregate across multiple groups.
     // We'll use the reliability_score if available in some context, or calculate one.
     // For now, let's assume a default of 100 (best) and subtract for late/missed payments.
@@ -128,11 +137,13 @@ pub fn get_member_risk_score(env: &Env, member: &Address) -> u32 {
 }
 
 /// Calculates group risk rating.
-pub fn get_group_risk_rating(env: &Env, group: &Group) -> u32 {
-    let total_members = group.members.len();
-    if total_members == 0 { return 0; }
+pub fn get_group_risk_rating(_env: &Env, group: &Group) -> u32 {
+    let total_members = group.member_count;
+    if total_members == 0 {
+        return 0;
+    }
 
-    // Simplified: Average of member risk scores? 
+    // Simplified: Average of member risk scores?
     // Or just based on historical group performance if we had that.
     75 // Default medium risk
 }
@@ -0,0 +1,96 @@
+//! What a member owes across their groups, and when they are paid
+//!
+//! A member's running groups are found through the membership list kept by
+//! [`rate_limits`](crate::rate_limits), which drops finished groups whenever
+//! the member joins another, so it does not grow with every group ever joined.
+//! For each one, an obligation gives the next contribution due, with the late penalty
+//! included while the grace period runs, its deadline and grace end, and the
+//! member's next payout position with an estimate of when it is paid.
+//!
+//! Payout estimates assume each cycle is paid out as soon as its grace period
+//! ends, so they are the earliest a payout can happen; a group frozen or paid
+//! out later pays later.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::security::limits::OBLIGATION_PAGE_SIZE;
+use crate::storage;
+use crate::types::{Group, GroupState, MemberObligation};
+use crate::utils;
+
+/// Lists a member's obligations in their running groups, in group ID order,
+/// starting at `start_id`. Cancelled and completed groups are left out.
+///
+/// At most [`OBLIGATION_PAGE_SIZE`] obligations are returned, whatever `limit`
+/// asks for.
+pub fn list(env: &Env, member: &Address, start_id: u64, limit: u32) -> Vec<MemberObligation> {
+    let limit = limit.min(OBLIGATION_PAGE_SIZE);
+    let mut obligations = Vec::new(env);
+    for group_id in storage::get_active_memberships(env, member).iter() {
+        if obligations.len() >= limit {
+            break;
+        }
+        if group_id < start_id {
+            continue;
+        }
+        if let Some(group) = storage::get_group(env, group_id) {
+            let running = matches!(group.state, GroupState::Active | GroupState::Frozen);
+            if running && !group.is_complete {
+                obligations.push_back(build(env, &group, member));
+            }
+        }
+    }
+    obligations
+}
+
+/// Builds a member's obligation for a running group.
+pub fn build(env: &Env, group: &Group, member: &Address) -> MemberObligation {
+    let now = utils::get_current_timestamp(env);
+    let credit = storage::get_member_credit(env, group.id, member);
+    let deadline = utils::cycle_end_time(group);
+
+    let mut obligation = MemberObligation {
+        group_id: group.id,
+        token_address: group.token_address.clone(),
+        cycle: group.current_cycle,
+        amount_due: 0,
+        deadline,
+        grace_end: utils::get_grace_period_end(group),
+        payout_position: None,
+        estimated_payout_at: None,
+    };
+
+    if !storage::has_contributed(env, group.id, group.current_cycle, member) {
+        let due_now = utils::amount_due(env, group, member, now);
+        obligation.amount_due = (due_now - credit).max(0);
+    } else if group.payout_index + 1 < group.seat_count {
+        // Paid up for this cycle, so the next one is due on time
+        let next_deadline = utils::next_cycle_end(&group.schedule, deadline);
+        obligation.cycle += 1;
+        obligation.deadline = next_deadline;
+        obligation.grace_end = next_deadline + group.grace_period;
+        obligation.amount_due = (utils::cycle_contribution(env, group, member) - credit).max(0);
+    }
+
+    let seats = storage::get_group_seats(env, group.id);
+    let mut position = group.payout_index;
+    while position < seats.len() {
+        if seats.get(position).as_ref() == Some(member) {
+            obligation.payout_position = Some(position);
+            obligation.estimated_payout_at = Some(estimated_payout(group, position));
+            break;
+        }
+        position += 1;
+    }
+
+    obligation
+}
+
+/// Returns the earliest time the payout for a seat position can run.
+fn estimated_payout(group: &Group, position: u32) -> u64 {
+    let mut cycle_end = utils::cycle_end_time(group);
+    for _ in group.payout_index..position {
+        cycle_end = utils::next_cycle_end(&group.schedule, cycle_end);
+    }
+    cycle_end + group.grace_period
+}
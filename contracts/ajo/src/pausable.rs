@@ -1,79 +1,122 @@
+//! Granular emergency pause switches for the Ajo contract.
+//!
+//! Each class of state-mutating operation has its own pause flag so that an
+//! incident in one area (for example, a faulty insurance payout) can be
+//! isolated without freezing every group. The admin-only [`pause`] and
+//! [`unpause`] helpers still toggle every flag at once.
+//!
+//! ## Pause matrix
+//!
+//! | Flag                          | Blocked entry points                                                                  |
+//! |-------------------------------|---------------------------------------------------------------------------------------|
+//! | [`PauseFlag::GroupCreation`]  | `create_group`, `set_group_metadata`, `add_co_organizer`, `remove_co_organizer`,      |
+//! |                               | `start_group`, `transfer_group_ownership`, `accept_group_ownership`,                  |
+//! |                               | `set_group_vault`, `renew_group`, `save_template`, `remove_template`,                 |
+//! |                               | `create_group_from_template`                                                          |
+//! | [`PauseFlag::Joins`]          | `join_group`                                                                          |
+//! | [`PauseFlag::Contributions`]  | `contribute`, `contribute_many`, `contribute_amount`, `enable_auto_debit`,            |
+//! |                               | `collect_due`                                                                         |
+//! | [`PauseFlag::Payouts`]        | `execute_payout`, `claim_many`, `withdraw_fees`, `withdraw_organizer_fees`,           |
+//! |                               | `park_funds`                                                                          |
+//! | [`PauseFlag::Refunds`]        | `cancel_group`, `request_refund`, `vote_refund`, `execute_refund`,                    |
+//! |                               | `emergency_refund`, `process_refunds`                                                 |
+//! | [`PauseFlag::Insurance`]      | `file_insurance_claim`, `process_insurance_claim`                                     |
+//!
+//! `poke` and `poke_many` are not blocked as a whole: each of their steps runs
+//! only while its own operation class (`Contributions`, `Payouts` or `Refunds`)
+//! is unpaused.
+//!
+//! Admin entry points (`initialize`, `upgrade`, `pause`, `unpause`,
+//! `pause_operation`, `unpause_operation`, `set_platform_fee`,
+//! `set_fee_manager`, `set_keeper_reward`, `add_supported_token`,
+//! `remove_supported_token`, `set_vault_approved`, `publish_template`),
+//! `freeze_group` and `disable_auto_debit` (which only ever restrict activity)
+//! and all read-only queries are never blocked.
+
 use soroban_sdk::{symbol_short, Env};
 
 use crate::errors::AjoError;
+use crate::events;
 use crate::storage;
+use crate::types::{PauseFlag, PauseState};
 
-/// Get the current pause state from instance storage.
+/// Get the current pause switches from instance storage.
 ///
-/// Returns `false` if the pause state has not been set, meaning the contract
-/// starts in an unpaused state by default. This function is used internally
-/// by the pausable module and can also be called directly to check the current
-/// pause status.
+/// Returns a state with every flag cleared if nothing has been stored yet,
+/// meaning the contract starts fully unpaused.
 ///
 /// # Arguments
 /// * `env` - The contract environment used to access instance storage
 ///
 /// # Returns
-/// `true` if the contract is paused, `false` otherwise
+/// The current [`PauseState`]
 ///
 /// # Storage Strategy
-/// The pause state is stored in instance storage under the key "PAUSED".
+/// The pause state is stored in instance storage under the key "PAUSEST".
 /// Instance storage is appropriate for contract-level configuration because:
 /// - It provides fast O(1) access
 /// - It persists across contract calls and upgrades
 /// - It matches the pattern used for admin storage
 /// - It has lower cost than persistent storage for frequently accessed data
-pub fn is_paused(env: &Env) -> bool {
-    let key = symbol_short!("PAUSED");
-    env.storage().instance().get(&key).unwrap_or(false)
+pub fn get_pause_state(env: &Env) -> PauseState {
+    let key = symbol_short!("PAUSEST");
+    env.storage().instance().get(&key).unwrap_or_default()
 }
 
-/// Set the pause state in instance storage.
+/// Store the pause switches in instance storage and emit a change event.
+///
+/// This is a private helper used by the pause/unpause functions. It should not
+/// be called directly from outside this module.
 ///
-/// This is a private helper function used by `pause()` and `unpause()` to
-/// update the pause state. It should not be called directly from outside
-/// this module.
+/// # Arguments
+/// * `env` - The contract environment used to access instance storage
+/// * `state` - The new pause state
+fn set_pause_state(env: &Env, state: &PauseState) {
+    let key = symbol_short!("PAUSEST");
+    env.storage().instance().set(&key, state);
+    events::emit_pause_state_changed(env, state);
+}
+
+/// Returns `true` if any operation class is currently paused.
 ///
 /// # Arguments
 /// * `env` - The contract environment used to access instance storage
-/// * `paused` - The new pause state (`true` to pause, `false` to unpause)
-fn set_paused(env: &Env, paused: bool) {
-    let key = symbol_short!("PAUSED");
-    env.storage().instance().set(&key, &paused);
+pub fn is_paused(env: &Env) -> bool {
+    get_pause_state(env).any_paused()
 }
 
-/// Check if the contract is paused and return an error if so.
+/// Check if an operation class is paused and return an error if so.
 ///
-/// This is the primary guard function that should be called at the beginning
-/// of all state-mutating operations. It provides a fail-fast mechanism to
-/// prevent any state changes when the contract is in a paused state.
+/// This is the guard function that every state-mutating entry point calls
+/// with the class it belongs to (see the module-level pause matrix).
 ///
 /// # Arguments
 /// * `env` - The contract environment used to check the pause state
+/// * `flag` - The operation class the caller belongs to
 ///
 /// # Returns
-/// * `Ok(())` if the contract is not paused (operation can proceed)
-/// * `Err(AjoError::ContractPaused)` if the contract is paused
+/// * `Ok(())` if the operation class is not paused (operation can proceed)
+/// * `Err(AjoError::ContractPaused)` if the operation class is paused
 ///
 /// # Usage
 /// Add this check at the start of state-mutating functions:
 /// ```ignore
-/// pausable::ensure_not_paused(&env)?;
+/// pausable::ensure_not_paused(&env, PauseFlag::Contributions)?;
 /// ```
-pub fn ensure_not_paused(env: &Env) -> Result<(), AjoError> {
-    if is_paused(env) {
+pub fn ensure_not_paused(env: &Env, flag: PauseFlag) -> Result<(), AjoError> {
+    if get_pause_state(env).is_paused(flag) {
         Err(AjoError::ContractPaused)
     } else {
         Ok(())
     }
 }
 
-/// Pause the contract to prevent state-mutating operations.
+/// Pause every operation class.
 ///
 /// This function can only be called by the contract administrator. When the
-/// contract is paused, all state-mutating operations (create_group, join_group,
-/// contribute, execute_payout) will fail with a `ContractPaused` error. Query
-/// operations and admin functions remain available during the pause.
+/// contract is paused, every entry point in the pause matrix fails with a
+/// `ContractPaused` error. Query operations and admin functions remain
+/// available during the pause.
 ///
 /// The pause mechanism is designed for emergency situations such as:
 /// - Detected security vulnerabilities
@@ -88,34 +131,21 @@ pub fn ensure_not_paused(env: &Env) -> Result<(), AjoError> {
 /// * `Ok(())` if the pause was successful
 /// * `Err(AjoError::UnauthorizedPause)` if the caller is not the admin
 ///
-/// # Authorization
-/// This function requires admin authentication via `require_auth()`. The admin
-/// address is retrieved from instance storage using `storage::get_admin()`.
-///
 /// # Idempotency
 /// Calling pause when already paused is safe and will succeed without error.
 pub fn pause(env: &Env) -> Result<(), AjoError> {
-    // Get admin and verify authorization
     let admin = storage::get_admin(env).ok_or(AjoError::UnauthorizedPause)?;
     admin.require_auth();
-    
-    // Set paused state
-    set_paused(env, true);
-    
+
+    set_pause_state(env, &PauseState::all_paused());
+
     Ok(())
 }
 
-/// Unpause the contract to restore normal operations.
+/// Unpause every operation class.
 ///
-/// This function can only be called by the contract administrator. When the
-/// contract is unpaused, all operations return to normal functionality. All
-/// stored data (groups, contributions, payouts) remains intact and accessible.
-///
-/// Unpausing should be done after:
-/// - The emergency situation has been resolved
-/// - Security vulnerabilities have been patched
-/// - Maintenance has been completed
-/// - Investigation has concluded
+/// This function can only be called by the contract administrator. All stored
+/// data (groups, contributions, payouts) remains intact and accessible.
 ///
 /// # Arguments
 /// * `env` - The contract environment used to verify admin and clear pause state
@@ -124,23 +154,53 @@ pub fn pause(env: &Env) -> Result<(), AjoError> {
 /// * `Ok(())` if the unpause was successful
 /// * `Err(AjoError::UnauthorizedUnpause)` if the caller is not the admin
 ///
-/// # Authorization
-/// This function requires admin authentication via `require_auth()`. The admin
-/// address is retrieved from instance storage using `storage::get_admin()`.
-///
 /// # Idempotency
 /// Calling unpause when already unpaused is safe and will succeed without error.
-///
-/// # Data Safety
-/// Unpausing does not modify any stored data. All groups, contributions, and
-/// payouts remain exactly as they were before the pause.
 pub fn unpause(env: &Env) -> Result<(), AjoError> {
-    // Get admin and verify authorization
     let admin = storage::get_admin(env).ok_or(AjoError::UnauthorizedUnpause)?;
     admin.require_auth();
-    
-    // Clear paused state
-    set_paused(env, false);
-    
+
+    set_pause_state(env, &PauseState::default());
+
+    Ok(())
+}
+
+/// Pause a single operation class, leaving the others untouched.
+///
+/// # Arguments
+/// * `env` - The contract environment used to verify admin and set pause state
+/// * `flag` - The operation class to pause
+///
+/// # Returns
+/// * `Ok(())` if the pause was successful
+/// * `Err(AjoError::UnauthorizedPause)` if the caller is not the admin
+pub fn pause_operation(env: &Env, flag: PauseFlag) -> Result<(), AjoError> {
+    let admin = storage::get_admin(env).ok_or(AjoError::UnauthorizedPause)?;
+    admin.require_auth();
+
+    let mut state = get_pause_state(env);
+    state.set(flag, true);
+    set_pause_state(env, &state);
+
+    Ok(())
+}
+
+/// Unpause a single operation class, leaving the others untouched.
+///
+/// # Arguments
+/// * `env` - The contract environment used to verify admin and clear pause state
+/// * `flag` - The operation class to unpause
+///
+/// # Returns
+/// * `Ok(())` if the unpause was successful
+/// * `Err(AjoError::UnauthorizedUnpause)` if the caller is not the admin
+pub fn unpause_operation(env: &Env, flag: PauseFlag) -> Result<(), AjoError> {
+    let admin = storage::get_admin(env).ok_or(AjoError::UnauthorizedUnpause)?;
+    admin.require_auth();
+
+    let mut state = get_pause_state(env);
+    state.set(flag, false);
+    set_pause_state(env, &state);
+
     Ok(())
 }
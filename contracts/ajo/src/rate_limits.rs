@@ -0,0 +1,169 @@
+//! Anti-spam limits on creating and joining groups
+//!
+//! The admin sets [`RateLimits`](crate::types::RateLimits) with
+//! [`set_rate_limits`](crate::contract::AjoContract::set_rate_limits) and a
+//! per-token exposure limit with
+//! [`set_exposure_limit`](crate::contract::AjoContract::set_exposure_limit).
+//! A limit of `0` is not enforced, and none are until the admin sets them.
+//!
+//! - **Creation rate**: the times an address created groups are kept for one
+//!   `creation_window`, so the limit applies to any window of that length
+//!   rather than to fixed periods.
+//! - **Active groups**: running groups an address is the creator of, including
+//!   groups whose ownership they accepted.
+//! - **Memberships**: running groups an address is a member of, including the
+//!   groups they created.
+//! - **Exposure**: what a member may still have to pay in across their running
+//!   groups in one token: each group's per-cycle contribution, for all of their
+//!   seats, times the cycles left if the group fills to `max_members`, less the
+//!   current cycle once they have paid it. Counting the full group means later
+//!   joiners never raise a member's commitment past what was checked.
+//!
+//! Each address keeps a list of its running groups as creator and as member.
+//! Groups that have since completed or been cancelled are dropped from the
+//! lists whenever the address creates or joins another group, so finishing a
+//! group frees its place without any bookkeeping on the completion paths. The
+//! member list is kept in group ID order and also backs
+//! [`get_member_obligations`](crate::contract::AjoContract::get_member_obligations).
+//! Group renewals count as group creation.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::errors::AjoError;
+use crate::storage;
+use crate::types::{Group, GroupState};
+use crate::utils;
+
+/// Checks the limits for creating `group` and records it against its creator.
+///
+/// The creator takes the group's first seat, so the membership and exposure
+/// limits apply as well.
+///
+/// # Errors
+/// * `LimitExceeded` - If the creator has used up the current creation window,
+///   already runs or is already in the maximum number of groups, or their seat
+///   would exceed the token's exposure limit
+pub fn admit_creator(env: &Env, group: &Group) -> Result<(), AjoError> {
+    let limits = storage::get_rate_limits(env);
+    let creator = &group.creator;
+
+    if limits.creation_window > 0 && limits.max_creations_per_window > 0 {
+        let now = utils::get_current_timestamp(env);
+        let mut created_at = Vec::new(env);
+        for time in storage::get_creation_log(env, creator).iter() {
+            if time + limits.creation_window > now {
+                created_at.push_back(time);
+            }
+        }
+        if created_at.len() >= limits.max_creations_per_window {
+            return Err(AjoError::LimitExceeded);
+        }
+        created_at.push_back(now);
+        storage::set_creation_log(env, creator, &created_at);
+    }
+
+    admit_owner(env, creator, group)?;
+    admit_member(env, creator, group, utils::seat_count(env, group, creator))
+}
+
+/// Checks the active group limit for `owner` becoming the creator of `group`,
+/// and records the group against them.
+///
+/// # Errors
+/// * `LimitExceeded` - If `owner` already runs the maximum number of groups
+pub fn admit_owner(env: &Env, owner: &Address, group: &Group) -> Result<(), AjoError> {
+    if !is_running(group) {
+        return Ok(());
+    }
+
+    let limits = storage::get_rate_limits(env);
+    let mut group_ids = Vec::new(env);
+    for group_id in storage::get_active_groups(env, owner).iter() {
+        if let Some(other) = storage::get_group(env, group_id) {
+            if is_running(&other) && other.creator == *owner {
+                group_ids.push_back(group_id);
+            }
+        }
+    }
+    if at_limit(group_ids.len(), limits.max_active_groups) {
+        return Err(AjoError::LimitExceeded);
+    }
+
+    group_ids.push_back(group.id);
+    storage::set_active_groups(env, owner, &group_ids);
+    Ok(())
+}
+
+/// Checks the membership and exposure limits for `member` taking `seats` seats
+/// in `group`, and records the membership.
+///
+/// # Errors
+/// * `LimitExceeded` - If `member` is already in the maximum number of groups, or
+///   the new seats would exceed the token's exposure limit
+pub fn admit_member(
+    env: &Env,
+    member: &Address,
+    group: &Group,
+    seats: u32,
+) -> Result<(), AjoError> {
+    let limits = storage::get_rate_limits(env);
+    let (mut group_ids, committed) = running_memberships(env, member, &group.token_address);
+    if at_limit(group_ids.len(), limits.max_memberships) {
+        return Err(AjoError::LimitExceeded);
+    }
+
+    let limit = storage::get_exposure_limit(env, &group.token_address);
+    let cycles_left = group.max_members.saturating_sub(group.payout_index);
+    if limit > 0 && committed + group.contribution_amount * (seats * cycles_left) as i128 > limit {
+        return Err(AjoError::LimitExceeded);
+    }
+
+    let position = group_ids.iter().filter(|id| *id < group.id).count() as u32;
+    group_ids.insert(position, group.id);
+    storage::set_active_memberships(env, member, &group_ids);
+    Ok(())
+}
+
+/// Returns what a member may still have to pay in across their running groups in a token.
+pub fn exposure(env: &Env, member: &Address, token: &Address) -> i128 {
+    running_memberships(env, member, token).1
+}
+
+/// Returns the member's recorded groups that are still running with them as a
+/// member, and what they may still have to pay in across those of them that use `token`.
+fn running_memberships(env: &Env, member: &Address, token: &Address) -> (Vec<u64>, i128) {
+    let mut group_ids = Vec::new(env);
+    let mut committed = 0i128;
+    for group_id in storage::get_active_memberships(env, member).iter() {
+        if let Some(group) = storage::get_group(env, group_id) {
+            if is_running(&group) && storage::is_group_member(env, group_id, member) {
+                group_ids.push_back(group_id);
+                if group.token_address == *token {
+                    committed += outstanding(env, &group, member);
+                }
+            }
+        }
+    }
+    (group_ids, committed)
+}
+
+/// Returns what `member` may have left to pay into `group`: their per-cycle
+/// contribution for each cycle not yet paid out if the group fills to
+/// `max_members`, less the current cycle if they have already paid it.
+fn outstanding(env: &Env, group: &Group, member: &Address) -> i128 {
+    let mut cycles = group.max_members.saturating_sub(group.payout_index);
+    if cycles > 0 && storage::has_contributed(env, group.id, group.current_cycle, member) {
+        cycles -= 1;
+    }
+    utils::cycle_contribution(env, group, member) * cycles as i128
+}
+
+/// Returns `true` if a group still counts toward its creator's and members' limits.
+fn is_running(group: &Group) -> bool {
+    matches!(group.state, GroupState::Active | GroupState::Frozen) && !group.is_complete
+}
+
+/// Returns `true` if `count` already meets a limit, where `0` means no limit.
+fn at_limit(count: u32, limit: u32) -> bool {
+    limit > 0 && count >= limit
+}
@@ -0,0 +1,149 @@
+//! Renewal of completed groups into a new rotation
+//!
+//! Once a group completes, its organizers can roll it into a successor with
+//! [`renew_group`](crate::contract::AjoContract::renew_group). The successor has
+//! the same parameters, metadata, co-organizers and vault, and starts with the
+//! creator as its only member. The other previous members are invited back:
+//! for [`RENEWAL_WINDOW`] seconds only they may join, and each takes their place
+//! in the predecessor's payout order rotated by one, so the first recipient of
+//! one rotation is paid last in the next. After the window the successor is
+//! open to anyone and later joiners are appended as usual.
+//!
+//! Members can optionally bring their reliability record with them. Insurance
+//! pools are held per token rather than per group, so the premiums a group paid
+//! in keep covering its successor without being moved.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::errors::AjoError;
+use crate::events;
+use crate::security::limits::RENEWAL_WINDOW;
+use crate::storage;
+use crate::types::{Group, GroupState, Renewal};
+use crate::utils;
+use crate::vault;
+
+/// Builds the successor of a completed group and seats the creator as its only
+/// member. The caller stores the group.
+pub fn successor(env: &Env, group: &Group, successor_id: u64, now: u64) -> Group {
+    let mut successor = Group {
+        id: successor_id,
+        creator: group.creator.clone(),
+        token_address: group.token_address.clone(),
+        contribution_amount: group.contribution_amount,
+        cycle_duration: group.cycle_duration,
+        max_members: group.max_members,
+        member_count: 0,
+        seat_count: 0,
+        current_cycle: 1,
+        payout_index: 0,
+        created_at: now,
+        cycle_start_time: now,
+        is_complete: false,
+        grace_period: group.grace_period,
+        penalty_rate: group.penalty_rate,
+        state: GroupState::Active,
+        insurance_config: group.insurance_config,
+        organizer_fee_bps: group.organizer_fee_bps,
+        schedule: group.schedule.clone(),
+    };
+    storage::add_group_member(env, &mut successor, &group.creator, 1, 0);
+    successor
+}
+
+/// Builds the renewal link for a successor, inviting the predecessor's members.
+///
+/// Invitees are listed once each, in the predecessor's payout order rotated by
+/// one member. The creator is included so their automatic seat is ordered too.
+pub fn invite(env: &Env, group: &Group, carry_reliability: bool, now: u64) -> Renewal {
+    let mut invitees = Vec::new(env);
+    for seat in storage::get_group_seats(env, group.id).iter() {
+        if !invitees.contains(&seat) {
+            invitees.push_back(seat);
+        }
+    }
+    if let Some(first) = invitees.pop_front() {
+        invitees.push_back(first);
+    }
+
+    Renewal {
+        predecessor: group.id,
+        invitees,
+        opt_in_deadline: now + RENEWAL_WINDOW,
+        carry_reliability,
+    }
+}
+
+/// Copies a group's metadata, co-organizers and vault to its successor.
+///
+/// The vault is only carried over while the admin still approves it, and the
+/// successor starts with nothing parked in it.
+pub fn copy_settings(env: &Env, group_id: u64, successor_id: u64) {
+    if let Some(metadata) = storage::get_group_metadata(env, group_id) {
+        storage::store_group_metadata(env, successor_id, &metadata);
+        events::emit_metadata_updated(env, successor_id, &metadata);
+    }
+
+    let co_organizers = storage::get_co_organizers(env, group_id);
+    if !co_organizers.is_empty() {
+        storage::store_co_organizers(env, successor_id, &co_organizers);
+        for co_organizer in co_organizers.iter() {
+            events::emit_co_organizer_added(env, successor_id, &co_organizer);
+        }
+    }
+
+    if let Some(position) = storage::get_group_vault(env, group_id) {
+        if storage::is_vault_approved(env, &position.vault) {
+            let position =
+                vault::configure(env, successor_id, position.vault, position.yield_policy);
+            storage::store_group_vault(env, successor_id, &position);
+            let vault = Some(position.vault);
+            events::emit_group_vault_set(env, successor_id, &vault, position.yield_policy);
+        }
+    }
+}
+
+/// Returns where a joining member's seats go in a group's payout order.
+///
+/// Outside a renewal's opt-in window seats are appended. Inside it, an invitee's
+/// seats go before those of every member ranked after them in the rotated order.
+///
+/// # Errors
+/// * `Unauthorized` - If the group is in its opt-in window and `member` was not invited
+pub fn seat_position(env: &Env, group: &Group, member: &Address) -> Result<u32, AjoError> {
+    let end = group.seat_count;
+    let renewal = match storage::get_renewal(env, group.id) {
+        Some(renewal) if utils::get_current_timestamp(env) <= renewal.opt_in_deadline => renewal,
+        _ => return Ok(end),
+    };
+    let seats = storage::get_group_seats(env, group.id);
+
+    let rank = renewal
+        .invitees
+        .first_index_of(member)
+        .ok_or(AjoError::Unauthorized)?;
+    let rank_of = |seat: Address| renewal.invitees.first_index_of(&seat).unwrap_or(u32::MAX);
+
+    // Seats that have already been paid keep their place
+    let mut position = group.payout_index;
+    while position < end && rank_of(seats.get_unchecked(position)) < rank {
+        position += 1;
+    }
+    Ok(position)
+}
+
+/// Copies a member's reliability record from the predecessor into a renewed group,
+/// if the renewal carries reliability and the member has a record to bring.
+pub fn carry_reliability(env: &Env, group_id: u64, member: &Address) {
+    let Some(renewal) = storage::get_renewal(env, group_id) else {
+        return;
+    };
+    if !renewal.carry_reliability {
+        return;
+    }
+    if let Some(mut record) = storage::get_member_penalty(env, renewal.predecessor, member) {
+        record.group_id = group_id;
+        storage::store_member_penalty(env, group_id, member, &record);
+        events::emit_reliability_carried(env, &record);
+    }
+}
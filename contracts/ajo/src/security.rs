@@ -1,116 +1,60 @@
-///! Security utilities and monitoring for the Ajo contract
-///!
-///! This module provides security-focused utilities including:
-///! - Transaction monitoring and anomaly detection
-///! - Rate limiting helpers
-///! - Security event logging
-///! - Validation helpers
+//! Security utilities and monitoring for the Ajo contract
+//!
+//! This module provides security-focused utilities including:
+//! - Transaction monitoring and anomaly detection
+//! - Security event logging
+//! - Validation helpers
+//!
+//! Anti-spam limits on creating and joining groups live in
+//! [`rate_limits`](crate::rate_limits).
 
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::{Env, Vec};
 
-use crate::errors::AjoError;
-use crate::types::Group;
+use crate::types::{AuditIssue, CycleAudit, Group, GroupAudit, GroupState};
 
 /// Security thresholds and limits
+///
+/// Limits on group parameters and metadata are admin-configurable; see the
+/// [`validation`](crate::validation) module.
 pub mod limits {
-    /// Maximum members per group (prevents DoS via large groups)
-    pub const MAX_MEMBERS: u32 = 100;
-    
-    /// Minimum members per group (ROSCA requires at least 2)
-    pub const MIN_MEMBERS: u32 = 2;
-    
-    /// Maximum contribution amount (10 million XLM in stroops)
-    pub const MAX_CONTRIBUTION: i128 = 10_000_000_000_000_000;
-    
-    /// Minimum contribution amount (0.01 XLM in stroops)
-    pub const MIN_CONTRIBUTION: i128 = 100_000;
-    
-    /// Maximum cycle duration (365 days in seconds)
-    pub const MAX_CYCLE_DURATION: u64 = 31_536_000;
-    
-    /// Minimum cycle duration (1 hour in seconds)
-    pub const MIN_CYCLE_DURATION: u64 = 3_600;
-    
-    /// Maximum metadata field lengths
-    pub const MAX_NAME_LENGTH: u32 = 100;
-    pub const MAX_DESCRIPTION_LENGTH: u32 = 500;
-    pub const MAX_RULES_LENGTH: u32 = 1000;
-}
+    /// Maximum groups a batch query or audit sweep covers in one call
+    pub const MAX_BATCH_SIZE: u32 = 10;
 
-/// Validates group creation parameters against security limits
-///
-/// This function performs comprehensive validation of all group parameters
-/// to prevent malicious or erroneous group creation.
-///
-/// # Arguments
-/// * `contribution_amount` - The fixed contribution amount per cycle
-/// * `cycle_duration` - Duration of each cycle in seconds
-/// * `max_members` - Maximum number of members allowed
-///
-/// # Returns
-/// * `Ok(())` if all parameters are valid
-/// * `Err(AjoError)` with specific error for invalid parameters
-///
-/// # Security Checks
-/// - Contribution amount must be positive and within reasonable bounds
-/// - Cycle duration must be reasonable (not too short or too long)
-/// - Member count must be within safe limits
-pub fn validate_group_params(
-    contribution_amount: i128,
-    cycle_duration: u64,
-    max_members: u32,
-) -> Result<(), AjoError> {
-    // Validate contribution amount
-    if contribution_amount == 0 {
-        return Err(AjoError::ContributionAmountZero);
-    }
-    if contribution_amount < 0 {
-        return Err(AjoError::ContributionAmountNegative);
-    }
-    if contribution_amount < limits::MIN_CONTRIBUTION {
-        return Err(AjoError::ContributionAmountZero); // Too small
-    }
-    if contribution_amount > limits::MAX_CONTRIBUTION {
-        return Err(AjoError::ContributionAmountNegative); // Too large (reuse error)
-    }
-    
-    // Validate cycle duration
-    if cycle_duration == 0 {
-        return Err(AjoError::CycleDurationZero);
-    }
-    if cycle_duration < limits::MIN_CYCLE_DURATION {
-        return Err(AjoError::CycleDurationZero); // Too short
-    }
-    if cycle_duration > limits::MAX_CYCLE_DURATION {
-        return Err(AjoError::CycleDurationZero); // Too long (reuse error)
-    }
-    
-    // Validate max members
-    if max_members < limits::MIN_MEMBERS {
-        return Err(AjoError::MaxMembersBelowMinimum);
-    }
-    if max_members > limits::MAX_MEMBERS {
-        return Err(AjoError::MaxMembersAboveLimit);
-    }
-    
-    Ok(())
-}
+    /// Maximum groups one `contribute_many` call contributes to
+    pub const CONTRIBUTE_BATCH_SIZE: u32 = 3;
 
-/// Checks if an address is a member of a group
-///
-/// # Arguments
-/// * `members` - The list of group members
-/// * `address` - The address to check
-///
-/// # Returns
-/// `true` if the address is in the members list, `false` otherwise
-pub fn is_member(members: &Vec<Address>, address: &Address) -> bool {
-    for member in members.iter() {
-        if member == *address {
-            return true;
-        }
-    }
-    false
+    /// Maximum groups one `claim_many` call claims payouts from
+    pub const CLAIM_BATCH_SIZE: u32 = 2;
+
+    /// Maximum groups one `poke_many` call checks
+    pub const POKE_BATCH_SIZE: u32 = 2;
+
+    /// Maximum items a paginated query returns in one call
+    pub const MAX_PAGE_SIZE: u32 = 50;
+
+    /// Maximum members one call refunds or pays member yield to
+    pub const SETTLEMENT_BATCH_SIZE: u32 = 5;
+
+    /// Maximum prepaid members whose credit one call rolls into a new cycle
+    pub const ROLLOVER_BATCH_SIZE: u32 = 4;
+
+    /// Maximum opted-in members one call pulls auto-debit contributions from
+    pub const COLLECTION_BATCH_SIZE: u32 = 2;
+
+    /// Maximum cycles one contribution matrix page covers
+    pub const MATRIX_PAGE_CYCLES: u32 = 10;
+
+    /// Maximum groups one member obligations query reports on
+    pub const OBLIGATION_PAGE_SIZE: u32 = 5;
+
+    /// Maximum cycles one group audit reports, counting back from the current one
+    pub const AUDIT_CYCLES: u32 = 8;
+
+    /// Estimated ledger reads one audit sweep spends on the groups it audits
+    pub const AUDIT_BATCH_READS: u32 = 28;
+
+    /// How long invited members of a renewed group have to opt in (7 days in seconds)
+    pub const RENEWAL_WINDOW: u64 = 604_800;
 }
 
 /// Validates that all members have contributed in the current cycle
@@ -122,12 +66,7 @@ pub fn is_member(members: &Vec<Address>, address: &Address) -> bool {
 /// # Returns
 /// `true` if all members have contributed, `false` otherwise
 pub fn all_members_contributed(env: &Env, group: &Group) -> bool {
-    for member in group.members.iter() {
-        if !crate::storage::has_contributed(env, group.id, group.current_cycle, &member) {
-            return false;
-        }
-    }
-    true
+    crate::utils::all_members_contributed(env, group)
 }
 
 /// Calculates the total payout amount for a cycle
@@ -136,13 +75,15 @@ pub fn all_members_contributed(env: &Env, group: &Group) -> bool {
 /// * `group` - The group to calculate payout for
 ///
 /// # Returns
-/// The total payout amount (contribution_amount × number of members)
+/// The total payout amount (contribution_amount × number of seats)
 ///
 /// # Security Note
 /// Uses checked multiplication to prevent overflow
 pub fn calculate_payout_amount(group: &Group) -> i128 {
-    let member_count = group.members.len() as i128;
-    group.contribution_amount.checked_mul(member_count)
+    let seat_count = group.seat_count as i128;
+    group
+        .contribution_amount
+        .checked_mul(seat_count)
         .expect("Payout calculation overflow")
 }
 
@@ -152,58 +93,39 @@ pub fn calculate_payout_amount(group: &Group) -> i128 {
 /// In production, this would integrate with off-chain monitoring systems.
 ///
 /// # Arguments
+/// * `env` - The contract environment
 /// * `group` - The group to monitor
 ///
 /// # Returns
 /// `true` if activity appears normal, `false` if suspicious
 ///
 /// # Checks
-/// - Rapid group creation
-/// - Unusual contribution patterns
-/// - Large withdrawal amounts
-pub fn check_transaction_pattern(group: &Group) -> bool {
-    // Check for reasonable member count
-    if group.members.len() > limits::MAX_MEMBERS as usize {
+/// - Seat count and cycle length within the current validation limits
+/// - Contribution amount within the token's registered bounds
+pub fn check_transaction_pattern(env: &Env, group: &Group) -> bool {
+    let config = crate::validation::get_config(env);
+
+    // Check for reasonable seat count
+    if group.seat_count > config.max_members {
         return false;
     }
-    
+
     // Check for reasonable contribution amount
-    if group.contribution_amount > limits::MAX_CONTRIBUTION {
-        return false;
+    if let Some(info) = crate::storage::get_supported_token(env, &group.token_address) {
+        if group.contribution_amount > info.max_contribution {
+            return false;
+        }
     }
-    
+
     // Check for reasonable cycle duration
-    if group.cycle_duration > limits::MAX_CYCLE_DURATION {
+    if group.cycle_duration > config.max_cycle_duration {
         return false;
     }
-    
+
     // All checks passed
     true
 }
 
-/// Validates metadata field lengths
-///
-/// # Arguments
-/// * `name_len` - Length of the name field
-/// * `description_len` - Length of the description field
-/// * `rules_len` - Length of the rules field
-///
-/// # Returns
-/// `Ok(())` if all lengths are within limits, `Err(AjoError::MetadataTooLong)` otherwise
-pub fn validate_metadata_lengths(
-    name_len: u32,
-    description_len: u32,
-    rules_len: u32,
-) -> Result<(), AjoError> {
-    if name_len > limits::MAX_NAME_LENGTH
-        || description_len > limits::MAX_DESCRIPTION_LENGTH
-        || rules_len > limits::MAX_RULES_LENGTH
-    {
-        return Err(AjoError::MetadataTooLong);
-    }
-    Ok(())
-}
-
 /// Security audit helper: Generates a security report for a group
 ///
 /// This function can be used by auditors or monitoring systems to get
@@ -214,153 +136,128 @@ pub fn validate_metadata_lengths(
 /// * `group` - The group to audit
 ///
 /// # Returns
-/// A tuple containing:
-/// - Total contributions expected
-/// - Total contributions received
-/// - Payouts completed
-/// - Security flags (0 = all good, >0 = issues detected)
-pub fn audit_group_security(env: &Env, group: &Group) -> (u32, u32, u32, u32) {
-    let total_expected = group.members.len() as u32 * group.current_cycle;
-    
-    let mut total_received = 0u32;
-    for cycle in 1..=group.current_cycle {
-        for member in group.members.iter() {
-            if crate::storage::has_contributed(env, group.id, cycle, &member) {
-                total_received += 1;
+/// A [`GroupAudit`] with contribution totals for the last
+/// [`AUDIT_CYCLES`](limits::AUDIT_CYCLES) cycles, payout bookkeeping, escrow
+/// against the contract's token balance, and every issue found
+///
+/// # Checks
+/// - Every reported cycle that was paid out received a contribution from
+///   every member
+/// - `payout_index` matches the payout records of the reported cycles and
+///   the seat count
+/// - The contract's token balance, plus the group's vault principal, covers
+///   what the group holds in escrow
+/// - Group parameters pass [`check_transaction_pattern`]
+/// - A running group whose grace period has ended is not waiting on members
+pub fn audit_group(env: &Env, group: &Group) -> GroupAudit {
+    let mut issues = Vec::new(env);
+    let running =
+        matches!(group.state, GroupState::Active | GroupState::Frozen) && !group.is_complete;
+    let member_count = group.member_count;
+    let expected_amount = calculate_payout_amount(group);
+
+    // Three reads per cycle: its payout record, contribution bitmap and the
+    // seat-weighted total it received. Cycles are paid out in order, so every
+    // reported cycle up to `payout_index` should have a record.
+    let first_cycle = group.current_cycle.saturating_sub(limits::AUDIT_CYCLES) + 1;
+    let payouts_expected = group.payout_index.saturating_sub(first_cycle - 1);
+    let mut cycles = Vec::new(env);
+    let mut payouts_recorded = 0u32;
+    let mut missing_contributions = false;
+    for cycle in first_cycle..=group.current_cycle {
+        let paid_out = crate::storage::get_payout_record(env, group.id, cycle).is_some();
+        let received_count =
+            crate::storage::get_contribution_bitmap(env, group.id, cycle).count_ones();
+        if paid_out {
+            payouts_recorded += 1;
+            if received_count < member_count {
+                missing_contributions = true;
             }
         }
+        cycles.push_back(CycleAudit {
+            cycle,
+            expected_count: member_count,
+            received_count,
+            expected_amount,
+            received_amount: crate::storage::get_cycle_received(env, group.id, cycle),
+        });
     }
-    
-    let payouts_completed = group.payout_index;
-    
-    // Security flags
-    let mut flags = 0u32;
-    
-    // Flag 1: Incomplete contributions
-    if total_received < total_expected && !group.is_complete {
-        flags |= 1;
+
+    // The open cycle's contributions and late penalties are held until its
+    // payout, as is every member's unapplied credit
+    let mut escrow_held = crate::storage::get_group_credit(env, group.id);
+    let mut pending_contributors = Vec::new(env);
+    if running {
+        let cycle = group.current_cycle;
+        escrow_held += crate::storage::get_cycle_received(env, group.id, cycle)
+            + crate::storage::get_cycle_penalty_pool(env, group.id, cycle);
+        let bitmap = crate::storage::get_contribution_bitmap(env, group.id, cycle);
+        for (index, member) in crate::storage::get_group_members(env, group.id)
+            .iter()
+            .enumerate()
+        {
+            if !crate::storage::is_bit_set(bitmap, index as u32) {
+                pending_contributors.push_back(member);
+            }
+        }
     }
-    
-    // Flag 2: Payout index mismatch
-    if payouts_completed > group.members.len() as u32 {
-        flags |= 2;
+
+    let vault_principal = crate::storage::get_group_vault(env, group.id)
+        .map(|position| position.principal)
+        .unwrap_or(0);
+    let token_balance =
+        crate::token::get_balance(env, &group.token_address, &env.current_contract_address());
+
+    if missing_contributions {
+        issues.push_back(AuditIssue::MissingContributions);
     }
-    
-    // Flag 3: Unusual parameters
-    if !check_transaction_pattern(group) {
-        flags |= 4;
+    if payouts_recorded != payouts_expected || group.payout_index > group.seat_count {
+        issues.push_back(AuditIssue::PayoutMismatch);
+    }
+    if token_balance + vault_principal < escrow_held {
+        issues.push_back(AuditIssue::Underfunded);
+    }
+    if !check_transaction_pattern(env, group) {
+        issues.push_back(AuditIssue::UnusualParameters);
+    }
+    let grace_expired = get_current_timestamp(env) > crate::utils::get_grace_period_end(group);
+    // A frozen group is suspended on purpose, so only an active one can be stuck
+    let overdue = group.state == GroupState::Active && running && grace_expired;
+    if overdue && !pending_contributors.is_empty() {
+        issues.push_back(AuditIssue::Stuck);
     }
-    
-    (total_expected, total_received, payouts_completed, flags)
-}
 
-/// Gets the current timestamp from the ledger
-///
-/// # Arguments
-/// * `env` - The contract environment
-///
-/// # Returns
-/// Current ledger timestamp in seconds
-pub fn get_current_timestamp(env: &Env) -> u64 {
-    env.ledger().timestamp()
+    GroupAudit {
+        group_id: group.id,
+        state: group.state,
+        cycles,
+        seat_count: group.seat_count,
+        payout_index: group.payout_index,
+        payouts_recorded,
+        escrow_held,
+        vault_principal,
+        token_balance,
+        pending_contributors,
+        issues,
+    }
 }
 
-/// Checks if a cycle is still active based on time
-///
-/// # Arguments
-/// * `env` - The contract environment
-/// * `group` - The group to check
+/// Estimated ledger reads [`audit_group`] spends on a group, beyond the
+/// group itself
 ///
-/// # Returns
-/// `true` if the current cycle is still active, `false` if expired
-pub fn is_cycle_active(env: &Env, group: &Group) -> bool {
-    let current_time = get_current_timestamp(env);
-    let cycle_end = group.cycle_start_time + group.cycle_duration;
-    current_time < cycle_end
+/// Four reads are fixed: the member list, the credit total, the vault
+/// position and the token balance. Each reported cycle adds three.
+pub fn audit_reads(group: &Group) -> u32 {
+    4 + 3 * group.current_cycle.min(limits::AUDIT_CYCLES)
 }
 
-/// Security helper: Validates that a payout is legitimate
+/// Gets the current timestamp from the ledger
 ///
 /// # Arguments
 /// * `env` - The contract environment
-/// * `group` - The group executing the payout
-/// * `recipient` - The intended recipient
 ///
 /// # Returns
-/// `Ok(())` if payout is valid, `Err(AjoError)` otherwise
-pub fn validate_payout(env: &Env, group: &Group, recipient: &Address) -> Result<(), AjoError> {
-    // Check if group is complete
-    if group.is_complete {
-        return Err(AjoError::GroupComplete);
-    }
-    
-    // Check if all members contributed
-    if !all_members_contributed(env, group) {
-        return Err(AjoError::IncompleteContributions);
-    }
-    
-    // Check if recipient is a member
-    if !is_member(&group.members, recipient) {
-        return Err(AjoError::NotMember);
-    }
-    
-    // Check if recipient already received payout
-    if crate::storage::has_received_payout(env, group.id, recipient) {
-        return Err(AjoError::AlreadyReceivedPayout);
-    }
-    
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    
-    #[test]
-    fn test_validate_group_params_valid() {
-        let result = validate_group_params(1_000_000, 604_800, 10);
-        assert!(result.is_ok());
-    }
-    
-    #[test]
-    fn test_validate_group_params_zero_contribution() {
-        let result = validate_group_params(0, 604_800, 10);
-        assert_eq!(result, Err(AjoError::ContributionAmountZero));
-    }
-    
-    #[test]
-    fn test_validate_group_params_negative_contribution() {
-        let result = validate_group_params(-100, 604_800, 10);
-        assert_eq!(result, Err(AjoError::ContributionAmountNegative));
-    }
-    
-    #[test]
-    fn test_validate_group_params_zero_duration() {
-        let result = validate_group_params(1_000_000, 0, 10);
-        assert_eq!(result, Err(AjoError::CycleDurationZero));
-    }
-    
-    #[test]
-    fn test_validate_group_params_too_few_members() {
-        let result = validate_group_params(1_000_000, 604_800, 1);
-        assert_eq!(result, Err(AjoError::MaxMembersBelowMinimum));
-    }
-    
-    #[test]
-    fn test_validate_group_params_too_many_members() {
-        let result = validate_group_params(1_000_000, 604_800, 101);
-        assert_eq!(result, Err(AjoError::MaxMembersAboveLimit));
-    }
-    
-    #[test]
-    fn test_validate_metadata_lengths_valid() {
-        let result = validate_metadata_lengths(50, 200, 500);
-        assert!(result.is_ok());
-    }
-    
-    #[test]
-    fn test_validate_metadata_lengths_too_long() {
-        let result = validate_metadata_lengths(101, 200, 500);
-        assert_eq!(result, Err(AjoError::MetadataTooLong));
-    }
+/// Current ledger timestamp in seconds
+pub fn get_current_timestamp(env: &Env) -> u64 {
+    env.ledger().timestamp()
 }
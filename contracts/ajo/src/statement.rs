@@ -0,0 +1,94 @@
+//! Member statements built from the records the contract already keeps
+//!
+//! A statement gathers what a member has paid into a group and received from
+//! it: the [`ContributionRecord`](crate::types::ContributionRecord) stored for
+//! each cycle, the insurance premium taken from each contribution, the
+//! [`PayoutRecord`](crate::types::PayoutRecord)s for their seats, any
+//! [`RefundRecord`](crate::types::RefundRecord) and the credit held for them.
+//! Every figure is read from existing storage.
+//!
+//! The contract holds no collateral for members; the closest thing is credit
+//! paid ahead of time, which is reported with the totals.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::insurance;
+use crate::storage;
+use crate::types::{Group, GroupState, MemberStatement, StatementTotals};
+use crate::utils;
+
+/// Builds a member's statement for a group.
+///
+/// The caller must have checked that `member` belongs to the group.
+pub fn build(env: &Env, group: &Group, member: &Address) -> MemberStatement {
+    let mut totals = StatementTotals {
+        group_id: group.id,
+        token_address: group.token_address.clone(),
+        contributed: 0,
+        penalties: 0,
+        insurance_premiums: 0,
+        credit: storage::get_member_credit(env, group.id, member),
+        payouts_received: 0,
+        refunded: 0,
+        amount_due: 0,
+        net_position: 0,
+    };
+
+    let mut contributions = Vec::new(env);
+    let mut unpaid_cycles = Vec::new(env);
+    for cycle in 1..=group.current_cycle {
+        match storage::get_contribution_detail(env, group.id, cycle, member) {
+            Some(record) => {
+                totals.contributed += record.amount;
+                totals.penalties += record.penalty_amount;
+                if group.insurance_config.is_enabled {
+                    totals.insurance_premiums += insurance::calculate_premium(
+                        record.amount,
+                        group.insurance_config.rate_bps,
+                    );
+                }
+                contributions.push_back(record);
+            }
+            None => unpaid_cycles.push_back(cycle),
+        }
+    }
+
+    let mut payouts = Vec::new(env);
+    for cycle in storage::get_member_payout_cycles(env, group.id, member).iter() {
+        if let Some(record) = storage::get_payout_record(env, group.id, cycle) {
+            totals.payouts_received += record.amount;
+            payouts.push_back(record);
+        }
+    }
+
+    let mut refunds = Vec::new(env);
+    if let Some(record) = storage::get_refund_record(env, group.id, member) {
+        totals.refunded += record.amount;
+        refunds.push_back(record);
+    }
+
+    // Only a running group can still ask for the current cycle's contribution
+    let running = matches!(group.state, GroupState::Active | GroupState::Frozen);
+    if running
+        && !group.is_complete
+        && !storage::has_contributed(env, group.id, group.current_cycle, member)
+    {
+        let due_now = utils::amount_due(env, group, member, utils::get_current_timestamp(env));
+        totals.amount_due = (due_now - totals.credit).max(0);
+    }
+
+    totals.net_position = totals.payouts_received + totals.refunded
+        - totals.contributed
+        - totals.penalties
+        - totals.credit;
+
+    MemberStatement {
+        member: member.clone(),
+        seats: utils::seat_count(env, group, member),
+        contributions,
+        unpaid_cycles,
+        payouts,
+        refunds,
+        totals,
+    }
+}
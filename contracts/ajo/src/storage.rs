@@ -19,16 +19,56 @@ pub enum StorageKey {
     /// Stored in persistent storage under `("GROUP", group_id)`.
     Group(u64),
 
-    /// Per-member contribution flag for a specific group and cycle.
-    /// Stored in persistent storage under `("CONTRIB", group_id, cycle, member)`.
-    /// Value is `bool` — `true` means the member has contributed.
-    Contribution(u64, u32, Address),
+    /// Ordered member addresses of a group, one entry per member in join order.
+    /// Stored in persistent storage under `("MEMBERS", group_id)`.
+    GroupMembers(u64),
+
+    /// Payout order of a group, one entry per seat.
+    /// Stored in persistent storage under `("SEATS", group_id)`.
+    GroupSeats(u64),
+
+    /// A member's index in the member list and number of seats.
+    /// Stored in persistent storage under `("MEMBER", group_id, member)`.
+    MemberSlot(u64, Address),
+
+    /// Contribution flags for every member of a group in one cycle.
+    /// Stored in persistent storage under `("CONTRIB", group_id, cycle)`.
+    /// Value is a `u128` bitmap — bit `i` is set once the member at index `i`
+    /// of the member list has contributed.
+    Contribution(u64, u32),
 
     /// Records whether a member has received their payout for a group.
     /// Stored in persistent storage under `("PAYOUT", group_id, member)`.
     /// Value is `bool` — `true` means the payout has been distributed.
     PayoutReceived(u64, Address),
 
+    /// Members marked in default for a cycle once its grace period ended unpaid.
+    /// Stored in persistent storage under `("DEFAULTS", group_id, cycle)`.
+    /// Value is a `u128` bitmap indexed like [`StorageKey::Contribution`].
+    CycleDefaults(u64, u32),
+
+    /// Members whose contribution for a cycle was paid late, in the grace period.
+    /// Stored in persistent storage under `("LATE", group_id, cycle)`.
+    /// Value is a `u128` bitmap indexed like [`StorageKey::Contribution`].
+    LateContributions(u64, u32),
+
+    /// Members whose missed contribution for a cycle a paid insurance claim covered.
+    /// Stored in persistent storage under `("COVERED", group_id, cycle)`.
+    /// Value is a `u128` bitmap indexed like [`StorageKey::Contribution`].
+    InsuranceCovered(u64, u32),
+
+    /// Contributions received for a cycle across every seat, excluding penalties.
+    /// Stored in persistent storage under `("RECEIVED", group_id, cycle)`.
+    CycleReceived(u64, u32),
+
+    /// Details of the payout made for a cycle.
+    /// Stored in persistent storage under `("PAYREC", group_id, cycle)`.
+    PayoutRecord(u64, u32),
+
+    /// Cycles in which a member received a payout, one per seat paid so far.
+    /// Stored in persistent storage under `("PAYCYC", group_id, member)`.
+    MemberPayouts(u64, Address),
+
     /// Optional metadata for a group.
     /// Stored in persistent storage under `("METADATA", group_id)`.
     GroupMetadata(u64),
@@ -56,6 +96,135 @@ pub enum StorageKey {
     /// Global insurance claim counter.
     /// Stored in instance storage under `"ICONT"`.
     ClaimCounter,
+
+    /// Per-operation-class pause switches.
+    /// Stored in instance storage under `"PAUSEST"`.
+    PauseState,
+
+    /// Co-organizers appointed by the group creator.
+    /// Stored in persistent storage under `("COORGS", group_id)`.
+    CoOrganizers(u64),
+
+    /// Proposed new creator awaiting acceptance of an ownership transfer.
+    /// Stored in persistent storage under `("PENDOWN", group_id)`.
+    PendingOwner(u64),
+
+    /// Members opted in to allowance-based auto-debit contributions.
+    /// Stored in persistent storage under `("AUTODEB", group_id)`.
+    /// Value is a `u128` bitmap indexed by the member's position in the member
+    /// list — a set bit means `collect_due` may pull from the member.
+    AutoDebits(u64),
+
+    /// Position in the member list where the next auto-debit collection starts.
+    /// Stored in persistent storage under `("COLLECT", group_id)`, and removed
+    /// once a collection reaches the last opted-in member.
+    CollectionCursor(u64),
+
+    /// Funds paid in by a member that have not yet been applied to a cycle
+    /// (installments toward the current cycle or prepaid future cycles).
+    /// Stored in persistent storage under `("CREDIT", group_id, member)`.
+    MemberCredit(u64, Address),
+
+    /// Total unapplied credit held across a group's members.
+    /// Stored in persistent storage under `("CREDITS", group_id)`.
+    GroupCredit(u64),
+
+    /// Members whose credit covers at least one whole cycle, in the order they prepaid.
+    /// Stored in persistent storage under `("PREPAID", group_id)`.
+    PrepaidMembers(u64),
+
+    /// Prepaid members whose credit has still to be rolled into the current cycle.
+    /// Stored in persistent storage under `("ROLLOVER", group_id)`.
+    CreditRollover(u64),
+
+    /// Refunds of a cancelled group still being paid out.
+    /// Stored in persistent storage under `("REFPROG", group_id)`.
+    RefundProgress(u64),
+
+    /// Platform fee in basis points taken from every payout.
+    /// Stored in instance storage under `"PLATFEE"`.
+    PlatformFee,
+
+    /// Address allowed to withdraw platform fees (defaults to the admin).
+    /// Stored in instance storage under `"FEEMGR"`.
+    FeeManager,
+
+    /// Accrued platform fees for a token.
+    /// Stored in instance storage under `("TREASURY", token_address)`.
+    Treasury(Address),
+
+    /// Accrued organizer fees for an organizer and token.
+    /// Stored in persistent storage under `("ORGFEES", organizer, token_address)`.
+    OrganizerFees(Address, Address),
+
+    /// Registry entry for a token approved for use in groups.
+    /// Stored in persistent storage under `("TOKEN", token_address)`.
+    SupportedToken(Address),
+
+    /// Addresses of every registered token, in registration order.
+    /// Stored in instance storage under `"TOKENS"`.
+    SupportedTokenList,
+
+    /// Vault contracts the admin has approved for parking group funds.
+    /// Stored in persistent storage under `("VAULTOK", vault_address)`.
+    ApprovedVault(Address),
+
+    /// A group's vault integration and parked position.
+    /// Stored in persistent storage under `("VAULT", group_id)`.
+    GroupVault(u64),
+
+    /// Total vault shares held by this contract across all groups.
+    /// Stored in persistent storage under `("VSHARES", vault_address)`.
+    VaultShares(Address),
+
+    /// Member yield still being paid out to a group's members.
+    /// Stored in persistent storage under `("YIELDPAY", group_id)`.
+    MemberYieldPayout(u64),
+
+    /// Share of the platform fee paid to keepers whose `poke` executes a payout,
+    /// in basis points.
+    /// Stored in instance storage under `"KEEPRWD"`.
+    KeeperReward,
+
+    /// Link from a renewed group to its predecessor, with the opt-in invitations.
+    /// Stored in persistent storage under `("RENEWAL", successor_id)`.
+    Renewal(u64),
+
+    /// ID of the group a completed group was renewed into.
+    /// Stored in persistent storage under `("SUCCESSR", predecessor_id)`.
+    Successor(u64),
+
+    /// Monotonically increasing counter used to assign unique template IDs.
+    /// Stored in instance storage under `"TPLCOUNT"`.
+    TemplateCounter,
+
+    /// A published or user-saved group template.
+    /// Stored in persistent storage under `("TEMPLATE", template_id)`.
+    Template(u64),
+
+    /// Anti-spam limits on group creation and membership.
+    /// Stored in instance storage under `"RATELIM"`.
+    RateLimits,
+
+    /// Largest amount a member may still owe across running groups in a token.
+    /// Stored in persistent storage under `("EXPLIMIT", token_address)`.
+    ExposureLimit(Address),
+
+    /// Times at which an address created groups within the current creation window.
+    /// Stored in persistent storage under `("CREATLOG", creator)`.
+    CreationLog(Address),
+
+    /// IDs of the running groups an address is the creator of.
+    /// Stored in persistent storage under `("ACTGRPS", creator)`.
+    ActiveGroups(Address),
+
+    /// IDs of the running groups an address is a member of, in ID order.
+    /// Stored in persistent storage under `("ACTMEMB", member)`.
+    ActiveMemberships(Address),
+
+    /// Admin-configured validation limits.
+    /// Stored in instance storage under `"CONFIG"`.
+    Config,
 }
 
 impl StorageKey {
@@ -75,8 +244,17 @@ impl StorageKey {
             StorageKey::Admin => symbol_short!("ADMIN"),
             StorageKey::GroupCounter => symbol_short!("GCOUNTER"),
             StorageKey::Group(_) => symbol_short!("GROUP"),
-            StorageKey::Contribution(_, _, _) => symbol_short!("CONTRIB"),
+            StorageKey::GroupMembers(_) => symbol_short!("MEMBERS"),
+            StorageKey::GroupSeats(_) => symbol_short!("SEATS"),
+            StorageKey::MemberSlot(_, _) => symbol_short!("MEMBER"),
+            StorageKey::Contribution(_, _) => symbol_short!("CONTRIB"),
+            StorageKey::CycleDefaults(_, _) => symbol_short!("DEFAULTS"),
+            StorageKey::LateContributions(_, _) => symbol_short!("LATE"),
+            StorageKey::InsuranceCovered(_, _) => symbol_short!("COVERED"),
+            StorageKey::CycleReceived(_, _) => symbol_short!("RECEIVED"),
             StorageKey::PayoutReceived(_, _) => symbol_short!("PAYOUT"),
+            StorageKey::PayoutRecord(_, _) => symbol_short!("PAYREC"),
+            StorageKey::MemberPayouts(_, _) => symbol_short!("PAYCYC"),
             StorageKey::GroupMetadata(_) => symbol_short!("METADATA"),
             StorageKey::ContributionDetail(_, _, _) => symbol_short!("CONTREC"),
             StorageKey::MemberPenalty(_, _) => symbol_short!("PENALTY"),
@@ -84,6 +262,37 @@ impl StorageKey {
             StorageKey::InsurancePool(_) => symbol_short!("INSPOOL"),
             StorageKey::InsuranceClaim(_) => symbol_short!("INSCLAIM"),
             StorageKey::ClaimCounter => symbol_short!("ICONT"),
+            StorageKey::PauseState => symbol_short!("PAUSEST"),
+            StorageKey::CoOrganizers(_) => symbol_short!("COORGS"),
+            StorageKey::PendingOwner(_) => symbol_short!("PENDOWN"),
+            StorageKey::AutoDebits(_) => symbol_short!("AUTODEB"),
+            StorageKey::CollectionCursor(_) => symbol_short!("COLLECT"),
+            StorageKey::MemberCredit(_, _) => symbol_short!("CREDIT"),
+            StorageKey::GroupCredit(_) => symbol_short!("CREDITS"),
+            StorageKey::PrepaidMembers(_) => symbol_short!("PREPAID"),
+            StorageKey::CreditRollover(_) => symbol_short!("ROLLOVER"),
+            StorageKey::RefundProgress(_) => symbol_short!("REFPROG"),
+            StorageKey::PlatformFee => symbol_short!("PLATFEE"),
+            StorageKey::FeeManager => symbol_short!("FEEMGR"),
+            StorageKey::Treasury(_) => symbol_short!("TREASURY"),
+            StorageKey::OrganizerFees(_, _) => symbol_short!("ORGFEES"),
+            StorageKey::SupportedToken(_) => symbol_short!("TOKEN"),
+            StorageKey::SupportedTokenList => symbol_short!("TOKENS"),
+            StorageKey::ApprovedVault(_) => symbol_short!("VAULTOK"),
+            StorageKey::GroupVault(_) => symbol_short!("VAULT"),
+            StorageKey::VaultShares(_) => symbol_short!("VSHARES"),
+            StorageKey::MemberYieldPayout(_) => symbol_short!("YIELDPAY"),
+            StorageKey::KeeperReward => symbol_short!("KEEPRWD"),
+            StorageKey::Renewal(_) => symbol_short!("RENEWAL"),
+            StorageKey::Successor(_) => symbol_short!("SUCCESSR"),
+            StorageKey::TemplateCounter => symbol_short!("TPLCOUNT"),
+            StorageKey::Template(_) => symbol_short!("TEMPLATE"),
+            StorageKey::RateLimits => symbol_short!("RATELIM"),
+            StorageKey::ExposureLimit(_) => symbol_short!("EXPLIMIT"),
+            StorageKey::CreationLog(_) => symbol_short!("CREATLOG"),
+            StorageKey::ActiveGroups(_) => symbol_short!("ACTGRPS"),
+            StorageKey::ActiveMemberships(_) => symbol_short!("ACTMEMB"),
+            StorageKey::Config => symbol_short!("CONFIG"),
         }
     }
 }
@@ -107,10 +316,16 @@ pub fn get_next_group_id(env: &Env) -> u64 {
     next
 }
 
+/// Returns the highest group ID assigned so far, or `0` if none.
+pub fn get_group_count(env: &Env) -> u64 {
+    let key = symbol_short!("GCOUNTER");
+    env.storage().instance().get(&key).unwrap_or(0)
+}
+
 /// Persists a [`Group`](crate::types::Group) to persistent ledger storage.
 ///
 /// This function writes or overwrites the group data for the given `group_id`.
-/// Call this any time the group's mutable fields (members, cycle, payout index, etc.) change.
+/// Call this any time the group's mutable fields (member counts, cycle, payout index, etc.) change.
 ///
 /// # Arguments
 /// * `env` - The contract environment used to access persistent storage
@@ -152,26 +367,179 @@ pub fn remove_group(env: &Env, group_id: u64) {
     env.storage().persistent().remove(&key);
 }
 
-/// Records whether a member has paid their contribution for a given cycle.
+/// Adds a member's seats to a group's member list and payout order.
 ///
-/// The composite key `(group_id, cycle, member)` ensures that contribution
-/// records are scoped per-group and per-cycle. A member's contribution in
-/// cycle 1 does not affect their status in cycle 2.
+/// The member takes the next index in the member list, and their seats are
+/// inserted together at `position` in the payout order. Updates the group's
+/// counts; the caller stores the group.
+///
+/// # Arguments
+/// * `env` - The contract environment used to access persistent storage
+/// * `group` - The group being joined
+/// * `member` - The joining member's address
+/// * `seats` - Number of seats the member takes
+/// * `position` - Payout position of the member's first seat
+pub fn add_group_member(
+    env: &Env,
+    group: &mut crate::types::Group,
+    member: &Address,
+    seats: u32,
+    position: u32,
+) {
+    let slot = crate::types::MemberSlot {
+        index: group.member_count,
+        seats,
+    };
+    let key = (symbol_short!("MEMBER"), group.id, member);
+    env.storage().persistent().set(&key, &slot);
+
+    let mut members = get_group_members(env, group.id);
+    members.push_back(member.clone());
+    let key = (symbol_short!("MEMBERS"), group.id);
+    env.storage().persistent().set(&key, &members);
+
+    let mut order = get_group_seats(env, group.id);
+    for _ in 0..seats {
+        order.insert(position, member.clone());
+    }
+    let key = (symbol_short!("SEATS"), group.id);
+    env.storage().persistent().set(&key, &order);
+
+    group.member_count += 1;
+    group.seat_count += seats;
+}
+
+/// Returns a group's member addresses in join order.
+pub fn get_group_members(env: &Env, group_id: u64) -> Vec<Address> {
+    let key = (symbol_short!("MEMBERS"), group_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns a group's payout order, one entry per seat.
+pub fn get_group_seats(env: &Env, group_id: u64) -> Vec<Address> {
+    let key = (symbol_short!("SEATS"), group_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns the member at a payout position, or `None` past the last seat.
+pub fn get_seat_holder(env: &Env, group_id: u64, position: u32) -> Option<Address> {
+    get_group_seats(env, group_id).get(position)
+}
+
+/// Returns a member's place in a group, or `None` if the address is not a member.
+pub fn get_member_slot(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+) -> Option<crate::types::MemberSlot> {
+    let key = (symbol_short!("MEMBER"), group_id, member);
+    env.storage().persistent().get(&key)
+}
+
+/// Returns `true` if the address is a member of the group.
+pub fn is_group_member(env: &Env, group_id: u64, member: &Address) -> bool {
+    let key = (symbol_short!("MEMBER"), group_id, member);
+    env.storage().persistent().has(&key)
+}
+
+/// Records whether the member at `index` has paid their contribution for a cycle.
+///
+/// Contributions are kept as one bitmap per group and cycle, so checking every
+/// member reads a single entry. A member's contribution in cycle 1 does not
+/// affect their status in cycle 2.
 ///
 /// # Arguments
 /// * `env` - The contract environment used to access persistent storage
 /// * `group_id` - The group the contribution belongs to
 /// * `cycle` - The cycle number being recorded
-/// * `member` - The contributing member's address
+/// * `index` - The contributing member's index in the member list
 /// * `paid` - `true` to mark as paid; `false` to reset (rarely needed)
-pub fn store_contribution(env: &Env, group_id: u64, cycle: u32, member: &Address, paid: bool) {
-    let key = (symbol_short!("CONTRIB"), group_id, cycle, member);
-    env.storage().persistent().set(&key, &paid);
+pub fn store_contribution(env: &Env, group_id: u64, cycle: u32, index: u32, paid: bool) {
+    let bit = 1u128 << index;
+    let bitmap = get_contribution_bitmap(env, group_id, cycle);
+    let bitmap = if paid { bitmap | bit } else { bitmap & !bit };
+    let key = (symbol_short!("CONTRIB"), group_id, cycle);
+    env.storage().persistent().set(&key, &bitmap);
+}
+
+/// Returns the contribution bitmap for a cycle, where bit `i` is set once the
+/// member at index `i` has contributed.
+///
+/// Defaults to `0` if no member has contributed yet.
+pub fn get_contribution_bitmap(env: &Env, group_id: u64, cycle: u32) -> u128 {
+    let key = (symbol_short!("CONTRIB"), group_id, cycle);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Records which members defaulted on a cycle, as a bitmap over the member list.
+pub fn store_cycle_defaults(env: &Env, group_id: u64, cycle: u32, bitmap: u128) {
+    let key = (symbol_short!("DEFAULTS"), group_id, cycle);
+    env.storage().persistent().set(&key, &bitmap);
+}
+
+/// Returns the bitmap of members marked in default for a cycle.
+///
+/// Defaults to `0` if no defaults have been marked.
+pub fn get_cycle_defaults(env: &Env, group_id: u64, cycle: u32) -> u128 {
+    let key = (symbol_short!("DEFAULTS"), group_id, cycle);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Adds a contribution, excluding any penalty, to what a cycle has received.
+pub fn add_cycle_received(env: &Env, group_id: u64, cycle: u32, amount: i128) {
+    let total = get_cycle_received(env, group_id, cycle) + amount;
+    let key = (symbol_short!("RECEIVED"), group_id, cycle);
+    env.storage().persistent().set(&key, &total);
+}
+
+/// Returns the contributions a cycle has received across every seat,
+/// excluding penalties.
+///
+/// Defaults to `0` if nobody has contributed.
+pub fn get_cycle_received(env: &Env, group_id: u64, cycle: u32) -> i128 {
+    let key = (symbol_short!("RECEIVED"), group_id, cycle);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Marks the member at `index` as having paid a cycle's contribution late.
+pub fn mark_late_contribution(env: &Env, group_id: u64, cycle: u32, index: u32) {
+    let bitmap = get_late_contributions(env, group_id, cycle) | (1u128 << index);
+    let key = (symbol_short!("LATE"), group_id, cycle);
+    env.storage().persistent().set(&key, &bitmap);
+}
+
+/// Returns the bitmap of members who paid a cycle's contribution late.
+///
+/// Defaults to `0` if nobody has paid late.
+pub fn get_late_contributions(env: &Env, group_id: u64, cycle: u32) -> u128 {
+    let key = (symbol_short!("LATE"), group_id, cycle);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Marks the member at `index` as covered by a paid insurance claim for a cycle.
+pub fn mark_insurance_covered(env: &Env, group_id: u64, cycle: u32, index: u32) {
+    let bitmap = get_insurance_covered(env, group_id, cycle) | (1u128 << index);
+    let key = (symbol_short!("COVERED"), group_id, cycle);
+    env.storage().persistent().set(&key, &bitmap);
+}
+
+/// Returns the bitmap of members a paid insurance claim covered for a cycle.
+///
+/// Defaults to `0` if no claim has covered the cycle.
+pub fn get_insurance_covered(env: &Env, group_id: u64, cycle: u32) -> u128 {
+    let key = (symbol_short!("COVERED"), group_id, cycle);
+    env.storage().persistent().get(&key).unwrap_or(0)
 }
 
 /// Returns `true` if the given member has contributed during the specified cycle.
 ///
-/// Defaults to `false` if no record exists, meaning the member has not yet contributed.
+/// Defaults to `false` if the address is not a member or has not yet contributed.
 ///
 /// # Arguments
 /// * `env` - The contract environment used to access persistent storage
@@ -182,8 +550,15 @@ pub fn store_contribution(env: &Env, group_id: u64, cycle: u32, member: &Address
 /// # Returns
 /// `true` if the member has contributed, `false` otherwise
 pub fn has_contributed(env: &Env, group_id: u64, cycle: u32, member: &Address) -> bool {
-    let key = (symbol_short!("CONTRIB"), group_id, cycle, member);
-    env.storage().persistent().get(&key).unwrap_or(false)
+    match get_member_slot(env, group_id, member) {
+        Some(slot) => is_bit_set(get_contribution_bitmap(env, group_id, cycle), slot.index),
+        None => false,
+    }
+}
+
+/// Returns `true` if the member at `index` is marked in a contribution bitmap.
+pub fn is_bit_set(bitmap: u128, index: u32) -> bool {
+    bitmap & (1u128 << index) != 0
 }
 
 /// Records that the given member has received their payout for a group.
@@ -200,17 +575,268 @@ pub fn mark_payout_received(env: &Env, group_id: u64, member: &Address) {
     env.storage().persistent().set(&key, &true);
 }
 
+/// Returns `true` if the given member has already received their payout for a group.
+///
+/// Defaults to `false` if no record exists.
+///
+/// # Arguments
+/// * `env` - The contract environment used to access persistent storage
+/// * `group_id` - The group to check
+/// * `member` - The member address to check
+///
+/// # Returns
+/// `true` if the payout has been distributed to the member, `false` otherwise
+pub fn has_received_payout(env: &Env, group_id: u64, member: &Address) -> bool {
+    let key = (symbol_short!("PAYOUT"), group_id, member);
+    env.storage().persistent().get(&key).unwrap_or(false)
+}
+
+/// Stores the record of a cycle's payout and indexes it under the recipient.
+pub fn store_payout_record(env: &Env, record: &crate::types::PayoutRecord) {
+    let key = (symbol_short!("PAYREC"), record.group_id, record.cycle);
+    env.storage().persistent().set(&key, record);
+
+    let mut cycles = get_member_payout_cycles(env, record.group_id, &record.member);
+    cycles.push_back(record.cycle);
+    let key = (symbol_short!("PAYCYC"), record.group_id, &record.member);
+    env.storage().persistent().set(&key, &cycles);
+}
+
+/// Retrieves the record of a cycle's payout, if it has been made.
+pub fn get_payout_record(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+) -> Option<crate::types::PayoutRecord> {
+    let key = (symbol_short!("PAYREC"), group_id, cycle);
+    env.storage().persistent().get(&key)
+}
+
+/// Returns the cycles in which a member received a payout, in order.
+pub fn get_member_payout_cycles(env: &Env, group_id: u64, member: &Address) -> Vec<u32> {
+    let key = (symbol_short!("PAYCYC"), group_id, member);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Records whether a member has opted in to auto-debit contributions for a group.
+///
+/// Does nothing for an address that is not a member.
+///
+/// # Arguments
+/// * `env` - The contract environment used to access persistent storage
+/// * `group_id` - The group the opt-in applies to
+/// * `member` - The member's address
+/// * `enabled` - `true` to opt in, `false` to opt out
+pub fn set_auto_debit(env: &Env, group_id: u64, member: &Address, enabled: bool) {
+    let Some(slot) = get_member_slot(env, group_id, member) else {
+        return;
+    };
+    let key = (symbol_short!("AUTODEB"), group_id);
+    let bitmap = get_auto_debits(env, group_id);
+    let updated = if enabled {
+        bitmap | (1u128 << slot.index)
+    } else {
+        bitmap & !(1u128 << slot.index)
+    };
+    if updated == 0 {
+        env.storage().persistent().remove(&key);
+    } else if updated != bitmap {
+        env.storage().persistent().set(&key, &updated);
+    }
+}
+
+/// Returns the bitmap of members opted in to auto-debit contributions for a group.
+///
+/// Bit `i` is set if the member at position `i` of the member list has opted in.
+pub fn get_auto_debits(env: &Env, group_id: u64) -> u128 {
+    let key = (symbol_short!("AUTODEB"), group_id);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Returns `true` if the member has opted in to auto-debit contributions for a group.
+///
+/// # Arguments
+/// * `env` - The contract environment used to access persistent storage
+/// * `group_id` - The group to check
+/// * `member` - The member address to check
+///
+/// # Returns
+/// `true` if `collect_due` may pull contributions from the member, `false` otherwise
+pub fn is_auto_debit_enabled(env: &Env, group_id: u64, member: &Address) -> bool {
+    get_member_slot(env, group_id, member)
+        .is_some_and(|slot| is_bit_set(get_auto_debits(env, group_id), slot.index))
+}
+
+/// Stores where the next auto-debit collection for a group starts.
+///
+/// A cursor of zero removes the entry.
+pub fn set_collection_cursor(env: &Env, group_id: u64, next_member: u32) {
+    let key = (symbol_short!("COLLECT"), group_id);
+    if next_member == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &next_member);
+    }
+}
+
+/// Returns the position in the member list where the next auto-debit
+/// collection for a group starts.
+pub fn get_collection_cursor(env: &Env, group_id: u64) -> u32 {
+    let key = (symbol_short!("COLLECT"), group_id);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Stores the unapplied credit a member holds in a group.
+///
+/// A zero balance removes the entry. The group's credit total is kept in step.
+///
+/// # Arguments
+/// * `env` - The contract environment used to access persistent storage
+/// * `group_id` - The group the credit belongs to
+/// * `member` - The member's address
+/// * `amount` - The new credit balance
+pub fn set_member_credit(env: &Env, group_id: u64, member: &Address, amount: i128) {
+    let total = get_group_credit(env, group_id) + amount - get_member_credit(env, group_id, member);
+    let total_key = (symbol_short!("CREDITS"), group_id);
+    env.storage().persistent().set(&total_key, &total);
+
+    let key = (symbol_short!("CREDIT"), group_id, member);
+    if amount > 0 {
+        env.storage().persistent().set(&key, &amount);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Returns the unapplied credit a member holds in a group.
+///
+/// Defaults to `0` if no record exists.
+///
+/// # Arguments
+/// * `env` - The contract environment used to access persistent storage
+/// * `group_id` - The group to check
+/// * `member` - The member address to check
+///
+/// # Returns
+/// The member's credit balance in token units
+pub fn get_member_credit(env: &Env, group_id: u64, member: &Address) -> i128 {
+    let key = (symbol_short!("CREDIT"), group_id, member);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Returns the unapplied credit held across all of a group's members.
+///
+/// Defaults to `0` if no member has held credit.
+pub fn get_group_credit(env: &Env, group_id: u64) -> i128 {
+    let key = (symbol_short!("CREDITS"), group_id);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Adds a member to the group's prepaid members, if not already listed.
+///
+/// # Arguments
+/// * `env` - The contract environment used to access persistent storage
+/// * `group_id` - The group the credit belongs to
+/// * `member` - The member whose credit covers a whole cycle
+pub fn add_prepaid_member(env: &Env, group_id: u64, member: &Address) {
+    let key = (symbol_short!("PREPAID"), group_id);
+    let mut members: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    if !members.contains(member) {
+        members.push_back(member.clone());
+        env.storage().persistent().set(&key, &members);
+    }
+}
+
+/// Removes a member from the group's prepaid members.
+///
+/// # Arguments
+/// * `env` - The contract environment used to access persistent storage
+/// * `group_id` - The group the credit belongs to
+/// * `member` - The member whose credit no longer covers a whole cycle
+pub fn remove_prepaid_member(env: &Env, group_id: u64, member: &Address) {
+    let key = (symbol_short!("PREPAID"), group_id);
+    let Some(mut members) = env.storage().persistent().get::<_, Vec<Address>>(&key) else {
+        return;
+    };
+    if let Some(index) = members.first_index_of(member) {
+        members.remove(index);
+        if members.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &members);
+        }
+    }
+}
+
+/// Returns the members whose credit covers at least one whole cycle.
+///
+/// # Arguments
+/// * `env` - The contract environment used to access persistent storage
+/// * `group_id` - The group to query
+///
+/// # Returns
+/// The prepaid members in the order they prepaid; empty if there are none
+pub fn get_prepaid_members(env: &Env, group_id: u64) -> Vec<Address> {
+    let key = (symbol_short!("PREPAID"), group_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores the prepaid members whose credit has still to be rolled into the
+/// current cycle.
+///
+/// An empty list removes the entry.
+///
+/// # Arguments
+/// * `env` - The contract environment used to access persistent storage
+/// * `group_id` - The group the credit belongs to
+/// * `members` - The members still waiting, in the order to roll them over
+pub fn set_credit_rollover(env: &Env, group_id: u64, members: &Vec<Address>) {
+    let key = (symbol_short!("ROLLOVER"), group_id);
+    if members.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, members);
+    }
+}
+
+/// Returns the prepaid members whose credit has still to be rolled into the
+/// current cycle.
+///
+/// # Arguments
+/// * `env` - The contract environment used to access persistent storage
+/// * `group_id` - The group to query
+///
+/// # Returns
+/// The members still waiting; empty if the roll-over is done
+pub fn get_credit_rollover(env: &Env, group_id: u64) -> Vec<Address> {
+    let key = (symbol_short!("ROLLOVER"), group_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
 /// Returns contribution status for every member in a cycle as an ordered vector.
 ///
-/// Iterates through `members` in order and looks up each one's contribution
-/// flag for the given cycle. The returned vector preserves member order and
-/// pairs each address with a `bool` indicating whether they have contributed.
+/// Reads the cycle's contribution bitmap once and pairs each member with its
+/// bit. The returned vector preserves member order and pairs each address with
+/// a `bool` indicating whether they have contributed.
 ///
 /// # Arguments
 /// * `env` - The contract environment used to access persistent storage
 /// * `group_id` - The group to query
 /// * `cycle` - The cycle number to query
-/// * `members` - The ordered member list from the group (use `group.members`)
+/// * `members` - The ordered member list from [`get_group_members`]
 ///
 /// # Returns
 /// A `Vec<(Address, bool)>` with members in original order, `true` = has contributed
@@ -220,12 +846,11 @@ pub fn get_cycle_contributions(
     cycle: u32,
     members: &Vec<Address>,
 ) -> Vec<(Address, bool)> {
-    let _capacity = members.len() as u32;
+    let bitmap = get_contribution_bitmap(env, group_id, cycle);
     let mut results = Vec::new(env);
 
-    for member in members.iter() {
-        let paid = has_contributed(env, group_id, cycle, &member);
-        results.push_back((member, paid));
+    for (index, member) in members.iter().enumerate() {
+        results.push_back((member, is_bit_set(bitmap, index as u32)));
     }
     results
 }
@@ -296,6 +921,69 @@ pub fn has_group_metadata(env: &Env, group_id: u64) -> bool {
     env.storage().persistent().has(&key)
 }
 
+/// Stores the co-organizer list for a group.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The unique identifier for the group
+/// * `co_organizers` - The full list of co-organizer addresses
+pub fn store_co_organizers(env: &Env, group_id: u64, co_organizers: &Vec<Address>) {
+    let key = (symbol_short!("COORGS"), group_id);
+    env.storage().persistent().set(&key, co_organizers);
+}
+
+/// Retrieves the co-organizer list for a group.
+///
+/// Returns an empty vector if the creator has not appointed anyone.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The unique identifier for the group
+///
+/// # Returns
+/// The co-organizer addresses in the order they were added
+pub fn get_co_organizers(env: &Env, group_id: u64) -> Vec<Address> {
+    let key = (symbol_short!("COORGS"), group_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores the address a group's ownership has been offered to.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The unique identifier for the group
+/// * `new_creator` - The proposed new creator, who must accept the transfer
+pub fn store_pending_owner(env: &Env, group_id: u64, new_creator: &Address) {
+    let key = (symbol_short!("PENDOWN"), group_id);
+    env.storage().persistent().set(&key, new_creator);
+}
+
+/// Retrieves the address a group's ownership has been offered to, if any.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The unique identifier for the group
+///
+/// # Returns
+/// `Some(Address)` if a transfer is pending, `None` otherwise
+pub fn get_pending_owner(env: &Env, group_id: u64) -> Option<Address> {
+    let key = (symbol_short!("PENDOWN"), group_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Clears a pending ownership transfer for a group.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The unique identifier for the group
+pub fn remove_pending_owner(env: &Env, group_id: u64) {
+    let key = (symbol_short!("PENDOWN"), group_id);
+    env.storage().persistent().remove(&key);
+}
+
 /// Stores detailed contribution record with penalty information.
 ///
 /// # Arguments
@@ -481,7 +1169,11 @@ pub fn store_refund_vote(
 ///
 /// # Returns
 /// `Some(RefundVote)` if the member has voted, `None` otherwise
-pub fn get_refund_vote(env: &Env, group_id: u64, member: &Address) -> Option<crate::types::RefundVote> {
+pub fn get_refund_vote(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+) -> Option<crate::types::RefundVote> {
     let key = (symbol_short!("REFVOTE"), group_id, member);
     env.storage().persistent().get(&key)
 }
@@ -535,6 +1227,24 @@ pub fn get_refund_record(
     env.storage().persistent().get(&key)
 }
 
+/// Stores the progress of a cancelled group's refunds.
+pub fn store_refund_progress(env: &Env, group_id: u64, progress: &crate::types::RefundProgress) {
+    let key = (symbol_short!("REFPROG"), group_id);
+    env.storage().persistent().set(&key, progress);
+}
+
+/// Retrieves the progress of a cancelled group's refunds, while any are unpaid.
+pub fn get_refund_progress(env: &Env, group_id: u64) -> Option<crate::types::RefundProgress> {
+    let key = (symbol_short!("REFPROG"), group_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Removes the progress of a cancelled group's refunds once every member is refunded.
+pub fn remove_refund_progress(env: &Env, group_id: u64) {
+    let key = (symbol_short!("REFPROG"), group_id);
+    env.storage().persistent().remove(&key);
+}
+
 /// Stores the insurance pool for a token.
 pub fn store_insurance_pool(env: &Env, token: &Address, pool: &crate::types::InsurancePool) {
     let key = (symbol_short!("INSPOOL"), token);
@@ -567,3 +1277,328 @@ pub fn get_insurance_claim(env: &Env, claim_id: u64) -> Option<crate::types::Ins
     let key = (symbol_short!("INSCLAIM"), claim_id);
     env.storage().persistent().get(&key)
 }
+
+/// Stores the platform fee taken from every payout, in basis points.
+pub fn set_platform_fee_bps(env: &Env, fee_bps: u32) {
+    let key = symbol_short!("PLATFEE");
+    env.storage().instance().set(&key, &fee_bps);
+}
+
+/// Returns the platform fee in basis points, defaulting to `0`.
+pub fn get_platform_fee_bps(env: &Env) -> u32 {
+    let key = symbol_short!("PLATFEE");
+    env.storage().instance().get(&key).unwrap_or(0)
+}
+
+/// Stores the address allowed to withdraw platform fees.
+pub fn set_fee_manager(env: &Env, manager: &Address) {
+    let key = symbol_short!("FEEMGR");
+    env.storage().instance().set(&key, manager);
+}
+
+/// Returns the appointed fee manager, if any.
+pub fn get_fee_manager(env: &Env) -> Option<Address> {
+    let key = symbol_short!("FEEMGR");
+    env.storage().instance().get(&key)
+}
+
+/// Stores the accrued platform fees for a token.
+pub fn set_treasury_balance(env: &Env, token: &Address, amount: i128) {
+    let key = (symbol_short!("TREASURY"), token);
+    env.storage().instance().set(&key, &amount);
+}
+
+/// Returns the accrued platform fees for a token, defaulting to `0`.
+pub fn get_treasury_balance(env: &Env, token: &Address) -> i128 {
+    let key = (symbol_short!("TREASURY"), token);
+    env.storage().instance().get(&key).unwrap_or(0)
+}
+
+/// Stores the accrued organizer fees for an organizer and token.
+///
+/// A zero balance removes the entry.
+pub fn set_organizer_fees(env: &Env, organizer: &Address, token: &Address, amount: i128) {
+    let key = (symbol_short!("ORGFEES"), organizer, token);
+    if amount > 0 {
+        env.storage().persistent().set(&key, &amount);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Returns the accrued organizer fees for an organizer and token, defaulting to `0`.
+pub fn get_organizer_fees(env: &Env, organizer: &Address, token: &Address) -> i128 {
+    let key = (symbol_short!("ORGFEES"), organizer, token);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Stores the registry entry for a supported token.
+///
+/// The token is appended to the supported token list the first time it is stored.
+pub fn store_supported_token(env: &Env, info: &crate::types::TokenInfo) {
+    let key = (symbol_short!("TOKEN"), &info.address);
+    if !env.storage().persistent().has(&key) {
+        let mut tokens = get_supported_token_list(env);
+        tokens.push_back(info.address.clone());
+        env.storage()
+            .instance()
+            .set(&symbol_short!("TOKENS"), &tokens);
+    }
+    env.storage().persistent().set(&key, info);
+}
+
+/// Retrieves the registry entry for a token, if it is supported.
+pub fn get_supported_token(env: &Env, token: &Address) -> Option<crate::types::TokenInfo> {
+    let key = (symbol_short!("TOKEN"), token);
+    env.storage().persistent().get(&key)
+}
+
+/// Removes a token from the registry and the supported token list.
+pub fn remove_supported_token(env: &Env, token: &Address) {
+    let key = (symbol_short!("TOKEN"), token);
+    env.storage().persistent().remove(&key);
+
+    let mut tokens = get_supported_token_list(env);
+    if let Some(index) = tokens.first_index_of(token) {
+        tokens.remove(index);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("TOKENS"), &tokens);
+    }
+}
+
+/// Returns the addresses of every supported token, in registration order.
+pub fn get_supported_token_list(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&symbol_short!("TOKENS"))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores whether the admin has approved a vault for parking group funds.
+///
+/// Revoking approval removes the entry.
+pub fn set_vault_approved(env: &Env, vault: &Address, approved: bool) {
+    let key = (symbol_short!("VAULTOK"), vault);
+    if approved {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Returns `true` if the admin has approved the vault.
+pub fn is_vault_approved(env: &Env, vault: &Address) -> bool {
+    let key = (symbol_short!("VAULTOK"), vault);
+    env.storage().persistent().has(&key)
+}
+
+/// Stores a group's vault integration and parked position.
+pub fn store_group_vault(env: &Env, group_id: u64, position: &crate::types::GroupVault) {
+    let key = (symbol_short!("VAULT"), group_id);
+    env.storage().persistent().set(&key, position);
+}
+
+/// Retrieves a group's vault integration, if one is configured.
+pub fn get_group_vault(env: &Env, group_id: u64) -> Option<crate::types::GroupVault> {
+    let key = (symbol_short!("VAULT"), group_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Removes a group's vault integration.
+pub fn remove_group_vault(env: &Env, group_id: u64) {
+    let key = (symbol_short!("VAULT"), group_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Stores the progress of paying member yield out to a group.
+pub fn store_member_yield_payout(
+    env: &Env,
+    group_id: u64,
+    payout: &crate::types::MemberYieldPayout,
+) {
+    let key = (symbol_short!("YIELDPAY"), group_id);
+    env.storage().persistent().set(&key, payout);
+}
+
+/// Retrieves the progress of paying member yield out to a group, if unfinished.
+pub fn get_member_yield_payout(
+    env: &Env,
+    group_id: u64,
+) -> Option<crate::types::MemberYieldPayout> {
+    let key = (symbol_short!("YIELDPAY"), group_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Removes the progress of paying member yield once every member is paid.
+pub fn remove_member_yield_payout(env: &Env, group_id: u64) {
+    let key = (symbol_short!("YIELDPAY"), group_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Stores the total shares this contract holds in a vault.
+pub fn set_vault_shares(env: &Env, vault: &Address, shares: i128) {
+    let key = (symbol_short!("VSHARES"), vault);
+    env.storage().persistent().set(&key, &shares);
+}
+
+/// Returns the total shares this contract holds in a vault, defaulting to `0`.
+pub fn get_vault_shares(env: &Env, vault: &Address) -> i128 {
+    let key = (symbol_short!("VSHARES"), vault);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Stores the keeper reward as a share of the platform fee, in basis points.
+pub fn set_keeper_reward_bps(env: &Env, reward_bps: u32) {
+    let key = symbol_short!("KEEPRWD");
+    env.storage().instance().set(&key, &reward_bps);
+}
+
+/// Returns the keeper reward in basis points of the platform fee, defaulting to `0`.
+pub fn get_keeper_reward_bps(env: &Env) -> u32 {
+    let key = symbol_short!("KEEPRWD");
+    env.storage().instance().get(&key).unwrap_or(0)
+}
+
+/// Stores the renewal link and invitations of a successor group.
+pub fn store_renewal(env: &Env, group_id: u64, renewal: &crate::types::Renewal) {
+    let key = (symbol_short!("RENEWAL"), group_id);
+    env.storage().persistent().set(&key, renewal);
+}
+
+/// Retrieves the renewal link of a successor group, if it was created by renewal.
+pub fn get_renewal(env: &Env, group_id: u64) -> Option<crate::types::Renewal> {
+    let key = (symbol_short!("RENEWAL"), group_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores the ID of the group a completed group was renewed into.
+pub fn set_successor(env: &Env, group_id: u64, successor_id: u64) {
+    let key = (symbol_short!("SUCCESSR"), group_id);
+    env.storage().persistent().set(&key, &successor_id);
+}
+
+/// Returns the ID of the group a completed group was renewed into, if any.
+pub fn get_successor(env: &Env, group_id: u64) -> Option<u64> {
+    let key = (symbol_short!("SUCCESSR"), group_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Returns the next template ID and advances the counter. IDs start at 1.
+pub fn get_next_template_id(env: &Env) -> u64 {
+    let key = symbol_short!("TPLCOUNT");
+    let next = get_template_count(env) + 1;
+    env.storage().instance().set(&key, &next);
+    next
+}
+
+/// Returns the highest template ID assigned so far, or `0` if none.
+pub fn get_template_count(env: &Env) -> u64 {
+    let key = symbol_short!("TPLCOUNT");
+    env.storage().instance().get(&key).unwrap_or(0)
+}
+
+/// Stores a group template.
+pub fn store_template(env: &Env, template: &crate::types::GroupTemplate) {
+    let key = (symbol_short!("TEMPLATE"), template.id);
+    env.storage().persistent().set(&key, template);
+}
+
+/// Retrieves a group template, if it exists.
+pub fn get_template(env: &Env, template_id: u64) -> Option<crate::types::GroupTemplate> {
+    let key = (symbol_short!("TEMPLATE"), template_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Removes a group template.
+pub fn remove_template(env: &Env, template_id: u64) {
+    let key = (symbol_short!("TEMPLATE"), template_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Stores the anti-spam limits on group creation and membership.
+pub fn set_rate_limits(env: &Env, limits: &crate::types::RateLimits) {
+    let key = symbol_short!("RATELIM");
+    env.storage().instance().set(&key, limits);
+}
+
+/// Returns the anti-spam limits, defaulting to none enforced.
+pub fn get_rate_limits(env: &Env) -> crate::types::RateLimits {
+    let key = symbol_short!("RATELIM");
+    env.storage().instance().get(&key).unwrap_or_default()
+}
+
+/// Stores the per-member exposure limit for a token.
+///
+/// A zero limit removes the entry.
+pub fn set_exposure_limit(env: &Env, token: &Address, limit: i128) {
+    let key = (symbol_short!("EXPLIMIT"), token);
+    if limit > 0 {
+        env.storage().persistent().set(&key, &limit);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Returns the per-member exposure limit for a token, or `0` if none is set.
+pub fn get_exposure_limit(env: &Env, token: &Address) -> i128 {
+    let key = (symbol_short!("EXPLIMIT"), token);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Stores the times at which an address created groups in the current window.
+pub fn set_creation_log(env: &Env, creator: &Address, created_at: &Vec<u64>) {
+    let key = (symbol_short!("CREATLOG"), creator);
+    env.storage().persistent().set(&key, created_at);
+}
+
+/// Returns the times at which an address created groups, oldest first.
+pub fn get_creation_log(env: &Env, creator: &Address) -> Vec<u64> {
+    let key = (symbol_short!("CREATLOG"), creator);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores the IDs of the running groups an address is the creator of.
+pub fn set_active_groups(env: &Env, creator: &Address, group_ids: &Vec<u64>) {
+    let key = (symbol_short!("ACTGRPS"), creator);
+    env.storage().persistent().set(&key, group_ids);
+}
+
+/// Returns the IDs of the groups last recorded as running under an address as creator.
+pub fn get_active_groups(env: &Env, creator: &Address) -> Vec<u64> {
+    let key = (symbol_short!("ACTGRPS"), creator);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores the IDs of the running groups an address is a member of, in ID order.
+pub fn set_active_memberships(env: &Env, member: &Address, group_ids: &Vec<u64>) {
+    let key = (symbol_short!("ACTMEMB"), member);
+    env.storage().persistent().set(&key, group_ids);
+}
+
+/// Returns the IDs of the groups last recorded as running with an address as member.
+pub fn get_active_memberships(env: &Env, member: &Address) -> Vec<u64> {
+    let key = (symbol_short!("ACTMEMB"), member);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores the admin-configured validation limits.
+pub fn set_config(env: &Env, config: &crate::types::ContractConfig) {
+    let key = symbol_short!("CONFIG");
+    env.storage().instance().set(&key, config);
+}
+
+/// Returns the admin-configured validation limits, if the admin has set any.
+pub fn get_config(env: &Env) -> Option<crate::types::ContractConfig> {
+    let key = symbol_short!("CONFIG");
+    env.storage().instance().get(&key)
+}
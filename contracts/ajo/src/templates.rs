@@ -0,0 +1,101 @@
+//! Group templates for standard configurations
+//!
+//! A template saves everything `create_group` asks for under a name, along with
+//! default rules text. The admin publishes templates for common setups with
+//! [`publish_template`](crate::contract::AjoContract::publish_template) and any
+//! user can keep their own with
+//! [`save_template`](crate::contract::AjoContract::save_template).
+//!
+//! [`create_group_from_template`](crate::contract::AjoContract::create_group_from_template)
+//! fills in the template's parameters, replacing any given in
+//! [`TemplateOverrides`], and creates the group exactly as `create_group` would,
+//! with the same validation. The template's name and rules become the new
+//! group's metadata.
+
+use soroban_sdk::{Env, String, Vec};
+
+use crate::errors::AjoError;
+use crate::fees;
+use crate::security::limits::MAX_PAGE_SIZE;
+use crate::storage;
+use crate::types::{GroupTemplate, ScheduleOverride, TemplateOverrides, TemplateParams};
+use crate::utils;
+use crate::validation;
+
+/// Validates a template before it is saved.
+///
+/// Uses the same checks as `create_group`, except for the token registry, which
+/// the contract checks separately.
+///
+/// # Errors
+/// * `MetadataTooLong` - If the name or rules exceed the metadata limits
+/// * Any error `create_group` returns for invalid parameters
+pub fn validate(
+    env: &Env,
+    name: &String,
+    params: &TemplateParams,
+    rules: &String,
+) -> Result<(), AjoError> {
+    let config = validation::get_config(env);
+    validation::validate_metadata_lengths(&config, name.len(), 0, rules.len())?;
+
+    let cycle_duration = utils::validate_schedule(&params.schedule)?;
+    validation::validate_group_params(
+        &config,
+        params.contribution_amount,
+        cycle_duration,
+        params.max_members,
+    )?;
+    validation::validate_penalty_params(
+        &config,
+        utils::shortest_cycle_duration(&params.schedule),
+        params.grace_period,
+        params.penalty_rate,
+    )?;
+    fees::validate_fees(storage::get_platform_fee_bps(env), params.organizer_fee_bps)
+}
+
+/// Returns a template's parameters with the overrides applied.
+///
+/// The result is not validated here; `create_group` validates it like any
+/// other set of group parameters.
+pub fn apply(params: TemplateParams, overrides: TemplateOverrides) -> TemplateParams {
+    TemplateParams {
+        token_address: overrides.token_address.unwrap_or(params.token_address),
+        contribution_amount: overrides
+            .contribution_amount
+            .unwrap_or(params.contribution_amount),
+        schedule: match overrides.schedule {
+            ScheduleOverride::Keep => params.schedule,
+            ScheduleOverride::Replace(schedule) => schedule,
+        },
+        max_members: overrides.max_members.unwrap_or(params.max_members),
+        grace_period: overrides.grace_period.unwrap_or(params.grace_period),
+        penalty_rate: overrides.penalty_rate.unwrap_or(params.penalty_rate),
+        insurance_rate_bps: overrides
+            .insurance_rate_bps
+            .unwrap_or(params.insurance_rate_bps),
+        organizer_fee_bps: overrides
+            .organizer_fee_bps
+            .unwrap_or(params.organizer_fee_bps),
+    }
+}
+
+/// Lists templates in ID order, starting at `start_id`.
+///
+/// Removed templates are skipped. At most [`MAX_PAGE_SIZE`] templates are
+/// returned, whatever `limit` asks for.
+pub fn list(env: &Env, start_id: u64, limit: u32) -> Vec<GroupTemplate> {
+    let limit = limit.min(MAX_PAGE_SIZE);
+    let mut templates = Vec::new(env);
+    let mut template_id = start_id.max(1);
+    let last_id = storage::get_template_count(env);
+
+    while template_id <= last_id && templates.len() < limit {
+        if let Some(template) = storage::get_template(env, template_id) {
+            templates.push_back(template);
+        }
+        template_id += 1;
+    }
+    templates
+}
@@ -1,9 +1,20 @@
-use soroban_sdk::{token, Address, Env};
+use soroban_sdk::{token, Address, Env, Error, String};
 
 use crate::errors::AjoError;
 
+/// Stellar Asset Contract error code for a missing or insufficient allowance.
+const SAC_ALLOWANCE_ERROR: u32 = 9;
+
+/// Stellar Asset Contract error code for an insufficient balance.
+const SAC_BALANCE_ERROR: u32 = 10;
+
 /// Transfer tokens from one address to another using the Stellar Asset Contract interface.
 ///
+/// The transfer goes through the fallible `try_transfer` path so that a failing
+/// token surfaces as an `AjoError` instead of aborting the call. The recipient's
+/// balance is compared before and after, and tokens that credit a different
+/// amount than requested (fee-on-transfer or rebasing tokens) are rejected.
+///
 /// # Arguments
 /// * `env` - The contract environment
 /// * `token_address` - Address of the token contract
@@ -15,7 +26,8 @@ use crate::errors::AjoError;
 /// `Ok(())` on successful transfer
 ///
 /// # Errors
-/// * `TransferFailed` - If the token transfer fails
+/// * `InsufficientBalance` - If the token reports that `from` cannot cover `amount`
+/// * `TransferFailed` - If the token transfer fails or moves a different amount
 pub fn transfer_token(
     env: &Env,
     token_address: &Address,
@@ -24,10 +36,180 @@ pub fn transfer_token(
     amount: i128,
 ) -> Result<(), AjoError> {
     let client = token::Client::new(env, token_address);
-    
-    // Attempt the transfer
-    client.transfer(from, to, &amount);
-    
+    let before = read_balance(&client, to)?;
+
+    match client.try_transfer(from, to, &amount) {
+        Ok(Ok(())) => {}
+        Err(Ok(error)) => return Err(map_token_error(error)),
+        _ => return Err(AjoError::TransferFailed),
+    }
+
+    verify_balance_delta(&client, from, to, amount, before)
+}
+
+/// Read a token's symbol and decimals through the SEP-41 interface.
+///
+/// Used to check that an address is a working token contract before it is
+/// registered or used for a group.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `token_address` - Address of the token contract
+///
+/// # Returns
+/// The token's `(symbol, decimals)`
+///
+/// # Errors
+/// * `InvalidTokenAddress` - If the address does not answer `symbol()` and `decimals()`
+pub fn read_token_metadata(env: &Env, token_address: &Address) -> Result<(String, u32), AjoError> {
+    let client = token::Client::new(env, token_address);
+
+    let decimals = match client.try_decimals() {
+        Ok(Ok(decimals)) => decimals,
+        _ => return Err(AjoError::InvalidTokenAddress),
+    };
+    let symbol = match client.try_symbol() {
+        Ok(Ok(symbol)) => symbol,
+        _ => return Err(AjoError::InvalidTokenAddress),
+    };
+
+    Ok((symbol, decimals))
+}
+
+/// Transfer tokens on behalf of `from` using an allowance granted to `spender`.
+///
+/// `from` must have approved `spender` through the SEP-41 `approve` function
+/// for at least `amount`. A shortfall reported by the token surfaces as
+/// `InsufficientAllowance`, but callers can check it up front with
+/// [`check_allowance`] to fail before any balances are read.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `token_address` - Address of the token contract
+/// * `spender` - Address that was granted the allowance
+/// * `from` - Address to transfer from
+/// * `to` - Address to transfer to
+/// * `amount` - Amount to transfer
+///
+/// # Returns
+/// `Ok(())` on successful transfer
+///
+/// # Errors
+/// * `InsufficientAllowance` - If the token reports a missing or insufficient allowance
+/// * `InsufficientBalance` - If the token reports that `from` cannot cover `amount`
+/// * `TransferFailed` - If the token transfer fails or moves a different amount
+pub fn transfer_token_from(
+    env: &Env,
+    token_address: &Address,
+    spender: &Address,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), AjoError> {
+    let client = token::Client::new(env, token_address);
+    let before = read_balance(&client, to)?;
+
+    match client.try_transfer_from(spender, from, to, &amount) {
+        Ok(Ok(())) => {}
+        Err(Ok(error)) => return Err(map_token_error(error)),
+        _ => return Err(AjoError::TransferFailed),
+    }
+
+    verify_balance_delta(&client, from, to, amount, before)
+}
+
+/// Map an error returned by a token contract to an `AjoError`.
+///
+/// The Stellar Asset Contract allowance and balance codes map to
+/// `InsufficientAllowance` and `InsufficientBalance`. Anything else, including
+/// host failures and codes from non-standard tokens, maps to `TransferFailed`.
+fn map_token_error(error: Error) -> AjoError {
+    if error == Error::from_contract_error(SAC_ALLOWANCE_ERROR) {
+        AjoError::InsufficientAllowance
+    } else if error == Error::from_contract_error(SAC_BALANCE_ERROR) {
+        AjoError::InsufficientBalance
+    } else {
+        AjoError::TransferFailed
+    }
+}
+
+/// Read the balance of the receiving side of a transfer.
+///
+/// # Errors
+/// * `TransferFailed` - If the token does not answer `balance()`
+fn read_balance(client: &token::Client, address: &Address) -> Result<i128, AjoError> {
+    match client.try_balance(address) {
+        Ok(Ok(balance)) => Ok(balance),
+        _ => Err(AjoError::TransferFailed),
+    }
+}
+
+/// Check that a transfer credited the recipient with exactly `amount`.
+///
+/// # Errors
+/// * `TransferFailed` - If the recipient's balance changed by a different
+///   amount, as happens with fee-on-transfer and rebasing tokens
+fn verify_balance_delta(
+    client: &token::Client,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    before: i128,
+) -> Result<(), AjoError> {
+    let expected = if from == to { 0 } else { amount };
+    if read_balance(client, to)? - before != expected {
+        return Err(AjoError::TransferFailed);
+    }
+    Ok(())
+}
+
+/// Get the allowance `from` has granted to `spender`.
+///
+/// The token contract reports expired allowances as zero, so the result
+/// already honours the expiration ledger set in `approve`.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `token_address` - Address of the token contract
+/// * `from` - Address that granted the allowance
+/// * `spender` - Address allowed to spend
+///
+/// # Returns
+/// The remaining, unexpired allowance
+pub fn get_allowance(
+    env: &Env,
+    token_address: &Address,
+    from: &Address,
+    spender: &Address,
+) -> i128 {
+    let client = token::Client::new(env, token_address);
+    client.allowance(from, spender)
+}
+
+/// Check if `from` has granted `spender` a sufficient allowance.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `token_address` - Address of the token contract
+/// * `from` - Address that granted the allowance
+/// * `spender` - Address allowed to spend
+/// * `amount` - Required amount
+///
+/// # Returns
+/// `Ok(())` if the allowance is sufficient
+///
+/// # Errors
+/// * `InsufficientAllowance` - If the allowance is missing, expired or too small
+pub fn check_allowance(
+    env: &Env,
+    token_address: &Address,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+) -> Result<(), AjoError> {
+    if get_allowance(env, token_address, from, spender) < amount {
+        return Err(AjoError::InsufficientAllowance);
+    }
     Ok(())
 }
 
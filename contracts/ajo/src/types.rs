@@ -11,6 +11,134 @@ pub enum GroupState {
     Cancelled = 1,
     /// Group has completed all cycles successfully.
     Complete = 2,
+    /// Group has been frozen by an organizer; joins, contributions and payouts
+    /// are suspended until it is started again.
+    Frozen = 3,
+}
+
+/// Classes of state-mutating operations that can be paused independently.
+///
+/// See the [`pausable`](crate::pausable) module for the full matrix of which
+/// contract entry points each class blocks.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PauseFlag {
+    /// Creating groups and managing their metadata, organizers and ownership.
+    GroupCreation = 0,
+    /// Joining existing groups.
+    Joins = 1,
+    /// Paying contributions into a group.
+    Contributions = 2,
+    /// Executing cycle payouts.
+    Payouts = 3,
+    /// Cancellations, refund requests, votes and refund execution.
+    Refunds = 4,
+    /// Filing and processing insurance claims.
+    Insurance = 5,
+}
+
+/// Current pause switches for every [`PauseFlag`] operation class.
+///
+/// A `true` field means operations of that class are currently blocked.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct PauseState {
+    pub group_creation: bool,
+    pub joins: bool,
+    pub contributions: bool,
+    pub payouts: bool,
+    pub refunds: bool,
+    pub insurance: bool,
+}
+
+impl PauseState {
+    /// Returns a state with every operation class paused.
+    pub fn all_paused() -> Self {
+        PauseState {
+            group_creation: true,
+            joins: true,
+            contributions: true,
+            payouts: true,
+            refunds: true,
+            insurance: true,
+        }
+    }
+
+    /// Returns `true` if the given operation class is paused.
+    pub fn is_paused(&self, flag: PauseFlag) -> bool {
+        match flag {
+            PauseFlag::GroupCreation => self.group_creation,
+            PauseFlag::Joins => self.joins,
+            PauseFlag::Contributions => self.contributions,
+            PauseFlag::Payouts => self.payouts,
+            PauseFlag::Refunds => self.refunds,
+            PauseFlag::Insurance => self.insurance,
+        }
+    }
+
+    /// Sets the pause switch for the given operation class.
+    pub fn set(&mut self, flag: PauseFlag, paused: bool) {
+        match flag {
+            PauseFlag::GroupCreation => self.group_creation = paused,
+            PauseFlag::Joins => self.joins = paused,
+            PauseFlag::Contributions => self.contributions = paused,
+            PauseFlag::Payouts => self.payouts = paused,
+            PauseFlag::Refunds => self.refunds = paused,
+            PauseFlag::Insurance => self.insurance = paused,
+        }
+    }
+
+    /// Returns `true` if any operation class is paused.
+    pub fn any_paused(&self) -> bool {
+        self.group_creation
+            || self.joins
+            || self.contributions
+            || self.payouts
+            || self.refunds
+            || self.insurance
+    }
+}
+
+/// When each cycle of a group ends.
+///
+/// Calendar schedules are evaluated in the group's local time, given as a fixed
+/// offset from UTC, and always end a cycle on the first matching moment after
+/// the cycle started. See [`utils::cycle_end_time`](crate::utils::cycle_end_time).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CycleSchedule {
+    /// Every cycle lasts the given number of seconds.
+    Fixed(u64),
+    /// Cycles end on a given weekday and time.
+    Weekly(WeeklySchedule),
+    /// Cycles end on a given day of the month and time.
+    Monthly(MonthlySchedule),
+}
+
+/// A cycle end on the same weekday and time every week.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WeeklySchedule {
+    /// Day of the week, from 0 (Monday) to 6 (Sunday).
+    pub weekday: u32,
+    /// Seconds after local midnight, below 86400.
+    pub time_of_day: u32,
+    /// Offset of local time from UTC in seconds, at most 14 hours either way.
+    pub utc_offset: i32,
+}
+
+/// A cycle end on the same day of the month and time every month.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MonthlySchedule {
+    /// Day of the month, from 1 to 31. Months without that day end on their
+    /// last day instead.
+    pub day: u32,
+    /// Seconds after local midnight, below 86400.
+    pub time_of_day: u32,
+    /// Offset of local time from UTC in seconds, at most 14 hours either way.
+    pub utc_offset: i32,
 }
 
 /// Represents an Ajo group configuration and state.
@@ -19,10 +147,14 @@ pub enum GroupState {
 /// where members contribute a fixed amount each cycle, and one member
 /// receives the full pool each round until everyone has been paid out.
 ///
+/// The member list and payout order are stored separately, so reading a group
+/// does not read one address per seat. See
+/// [`list_members`](crate::contract::AjoContract::list_members) and
+/// [`get_payout_schedule`](crate::contract::AjoContract::get_payout_schedule).
+///
 /// Fields are ordered by size for optimal memory alignment:
 /// - 16 bytes: i128
 /// - 32 bytes: Address
-/// - Variable: Vec<Address>
 /// - 8 bytes: u64 fields
 /// - 4 bytes: u32 fields
 /// - 1 byte: bool
@@ -41,33 +173,41 @@ pub struct Group {
     /// Supports Stellar Asset Contract (SAC) tokens including XLM, USDC, and custom tokens.
     pub token_address: Address,
 
-    /// Ordered list of member addresses.
-    /// Members receive payouts in the order they appear in this list.
-    pub members: Vec<Address>,
-
     /// Unique group identifier, auto-incremented from storage counter
     pub id: u64,
 
-    /// Duration of each cycle in seconds.
-    /// When a cycle ends, the next payout can be triggered.
+    /// Nominal duration of each cycle in seconds.
+    /// Equal to the fixed duration for [`CycleSchedule::Fixed`], one week for weekly
+    /// schedules and 31 days for monthly ones. Cycle ends come from `schedule`.
     pub cycle_duration: u64,
 
     /// Unix timestamp (seconds) when the group was created.
     pub created_at: u64,
 
     /// Unix timestamp (seconds) when the current cycle started.
-    /// Used together with `cycle_duration` to calculate when the cycle ends.
+    /// Each cycle starts at the end of the previous scheduled window. If a payout runs
+    /// so late that later windows have also ended, those windows are skipped and the
+    /// cycle starts at the end of the last one. Used together with `schedule` to
+    /// calculate when the cycle ends.
     pub cycle_start_time: u64,
 
-    /// Maximum number of members allowed in the group.
-    /// Must be between 2 and 100 (inclusive).
+    /// Maximum number of seats allowed in the group.
+    /// Must be within the configured member bounds when the group is created.
     pub max_members: u32,
 
+    /// Number of members, counting each member once however many seats they hold.
+    pub member_count: u32,
+
+    /// Number of seats across all members; one payout cycle per seat.
+    /// A member holding several seats contributes for each seat every cycle and
+    /// receives one payout per seat.
+    pub seat_count: u32,
+
     /// Current cycle number, starts at 1 and increments after each payout.
     pub current_cycle: u32,
 
-    /// Zero-based index into `members` indicating who receives the next payout.
-    /// When `payout_index == members.len()`, the group is complete.
+    /// Zero-based payout position of the seat that receives the next payout.
+    /// When `payout_index == seat_count`, the group is complete.
     pub payout_index: u32,
 
     /// Whether the group has completed all payout cycles.
@@ -89,6 +229,25 @@ pub struct Group {
 
     /// Insurance configuration for the group.
     pub insurance_config: InsuranceConfig,
+
+    /// Fee paid to the group creator from each payout, in basis points.
+    /// Combined with the platform fee it never exceeds 10%.
+    pub organizer_fee_bps: u32,
+
+    /// When each cycle ends.
+    pub schedule: CycleSchedule,
+}
+
+/// A member's place in a group.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MemberSlot {
+    /// Zero-based position in the group's member list, in join order.
+    /// Also the member's bit in each cycle's contribution bitmap.
+    pub index: u32,
+
+    /// Number of seats the member holds.
+    pub seats: u32,
 }
 
 /// Comprehensive snapshot of a group's current state.
@@ -118,6 +277,13 @@ pub struct GroupStatus {
     /// Total number of members currently in the group.
     pub total_members: u32,
 
+    /// Total number of seats held across all members; one payout cycle per seat.
+    pub total_seats: u32,
+
+    /// Zero-based payout position of the seat that receives the next payout.
+    /// Only meaningful when `has_next_recipient` is `true`.
+    pub next_payout_position: u32,
+
     /// Addresses of members who have not yet contributed in the current cycle.
     pub pending_contributors: Vec<Address>,
 
@@ -131,7 +297,7 @@ pub struct GroupStatus {
     /// Unix timestamp (seconds) when the current cycle started.
     pub cycle_start_time: u64,
 
-    /// Unix timestamp (seconds) when the current cycle ends (`cycle_start_time + cycle_duration`).
+    /// Unix timestamp (seconds) when the current cycle ends, as set by the group's schedule.
     pub cycle_end_time: u64,
 
     /// The ledger timestamp at the moment this status was queried.
@@ -159,9 +325,75 @@ pub struct GroupMetadata {
     pub rules: soroban_sdk::String,
 }
 
-pub const MAX_NAME_LENGTH: u32 = 50;
-pub const MAX_DESCRIPTION_LENGTH: u32 = 250;
-pub const MAX_RULES_LENGTH: u32 = 1000;
+/// Group parameters saved in a [`GroupTemplate`], matching those of `create_group`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateParams {
+    /// Token contract address for contributions and payouts.
+    pub token_address: Address,
+    /// Contribution amount per seat per cycle, in the token's units.
+    pub contribution_amount: i128,
+    /// When each cycle ends.
+    pub schedule: CycleSchedule,
+    /// Maximum number of seats in the group.
+    pub max_members: u32,
+    /// Grace period after each cycle ends, in seconds.
+    pub grace_period: u64,
+    /// Late contribution penalty as a percentage (0-100).
+    pub penalty_rate: u32,
+    /// Insurance premium in basis points of each contribution.
+    pub insurance_rate_bps: u32,
+    /// Organizer fee in basis points of each payout.
+    pub organizer_fee_bps: u32,
+}
+
+/// A saved group configuration that groups can be created from.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupTemplate {
+    /// Unique template identifier.
+    pub id: u64,
+    /// The user who saved the template, or `None` if the admin published it.
+    pub owner: Option<Address>,
+    /// Name of the template, also used as the name of groups created from it.
+    pub name: soroban_sdk::String,
+    /// Parameters for groups created from the template.
+    pub params: TemplateParams,
+    /// Default rules text for groups created from the template.
+    pub rules: soroban_sdk::String,
+}
+
+/// A schedule to replace when creating a group from a template.
+///
+/// Stands in for `Option<CycleSchedule>`, which a contract type cannot hold.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum ScheduleOverride {
+    /// Keep the template's schedule.
+    #[default]
+    Keep,
+    /// Use this schedule instead.
+    Replace(CycleSchedule),
+}
+
+/// Template parameters to replace when creating a group from a template.
+///
+/// Fields left as `None`, or `Keep` for the schedule, keep the template's value.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TemplateOverrides {
+    pub token_address: Option<Address>,
+    pub contribution_amount: Option<i128>,
+    pub schedule: ScheduleOverride,
+    pub max_members: Option<u32>,
+    pub grace_period: Option<u64>,
+    pub penalty_rate: Option<u32>,
+    pub insurance_rate_bps: Option<u32>,
+    pub organizer_fee_bps: Option<u32>,
+}
+
+/// Maximum number of co-organizers a group creator can appoint.
+pub const MAX_CO_ORGANIZERS: u32 = 5;
 
 /// Tracks a refund request initiated by a member.
 #[contracttype]
@@ -254,11 +486,31 @@ pub enum RefundReason {
     EmergencyRefund = 2,
 }
 
-/// Voting period duration in seconds (7 days).
-pub const VOTING_PERIOD: u64 = 604_800;
+/// Refunds of a cancelled group that are still being paid out.
+///
+/// Refunds are paid a few members at a time so that every call fits in one
+/// transaction. Returned by
+/// [`crate::contract::AjoContract::get_refund_progress`] until the last member
+/// has been refunded.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundProgress {
+    /// Why the group is being refunded.
+    pub reason: RefundReason,
+
+    /// The organizer, vote executor or admin who started the refund.
+    pub initiator: Address,
+
+    /// Position in the member list of the next member to refund.
+    pub next_member: u32,
 
-/// Minimum approval percentage required for refund (51%).
-pub const REFUND_APPROVAL_THRESHOLD: u32 = 51;
+    /// Vault yield paid with each refund per seat held; `None` if no yield was
+    /// held for the members.
+    pub yield_per_seat: Option<i128>,
+
+    /// Amount refunded so far, excluding vault yield.
+    pub total_refunded: i128,
+}
 
 /// Detailed record of a member's contribution for a specific cycle.
 #[contracttype]
@@ -271,19 +523,470 @@ pub struct ContributionRecord {
     pub timestamp: u64,
     pub is_late: bool,
     pub penalty_amount: i128,
+    /// Portion of `amount + penalty_amount` covered by credit paid in earlier
+    /// (installments or prepayments) rather than transferred for this cycle.
+    pub paid_from_credit: i128,
+}
+
+/// A member's payment progress for a group's current cycle.
+///
+/// Returned by [`crate::contract::AjoContract::get_contribution_progress`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributionProgress {
+    /// The group's current cycle.
+    pub cycle: u32,
+    /// `true` once the full amount for `cycle` has been paid.
+    pub contributed: bool,
+    /// Amount still owed for `cycle` if paid now, including any late penalty.
+    pub amount_due: i128,
+    /// Funds held for the member that have not been applied to a cycle yet.
+    pub credit: i128,
+    /// Number of future cycles the credit fully covers.
+    pub prepaid_cycles: u32,
+}
+
+/// A token the admin has approved for use in groups.
+///
+/// Returned by [`crate::contract::AjoContract::list_supported_tokens`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenInfo {
+    /// Address of the SEP-41 token contract.
+    pub address: Address,
+    /// Symbol reported by the token's `symbol()`.
+    pub symbol: soroban_sdk::String,
+    /// Decimal places reported by the token's `decimals()`.
+    pub decimals: u32,
+    /// Smallest contribution amount allowed for a group, in the token's units.
+    pub min_contribution: i128,
+    /// Largest contribution amount allowed for a group, in the token's units.
+    pub max_contribution: i128,
+}
+
+/// Validation limits the admin can tune without upgrading the contract.
+///
+/// Returned by [`crate::contract::AjoContract::get_config`]. See the
+/// [`validation`](crate::validation) module for the defaults and how the
+/// bounds are checked against each other.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ContractConfig {
+    /// Smallest `max_members` a group may be created with.
+    pub min_members: u32,
+    /// Largest `max_members` a group may be created with.
+    pub max_members: u32,
+    /// Shortest nominal cycle length, in seconds.
+    pub min_cycle_duration: u64,
+    /// Longest nominal cycle length, in seconds.
+    pub max_cycle_duration: u64,
+    /// Longest grace period after a cycle ends, in seconds.
+    pub max_grace_period: u64,
+    /// Highest late contribution penalty, as a percentage.
+    pub max_penalty_rate: u32,
+    /// Longest group or template name, in bytes.
+    pub max_name_length: u32,
+    /// Longest group description, in bytes.
+    pub max_description_length: u32,
+    /// Longest group or template rules text, in bytes.
+    pub max_rules_length: u32,
+    /// How long members may vote on a refund request, in seconds.
+    pub voting_period: u64,
+    /// Share of votes in favour needed to approve a refund, as a percentage.
+    pub refund_approval_threshold: u32,
 }
 
+/// Anti-spam limits on how many groups one address may create and join.
+///
+/// A limit of `0` is not enforced. See the [`rate_limits`](crate::rate_limits)
+/// module for how each limit is tracked.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RateLimits {
+    /// Length of the sliding group creation window, in seconds.
+    pub creation_window: u64,
+    /// Groups an address may create within any creation window.
+    pub max_creations_per_window: u32,
+    /// Running groups an address may be the creator of at once.
+    pub max_active_groups: u32,
+    /// Running groups an address may be a member of at once.
+    pub max_memberships: u32,
+}
+
+/// One seat's place in a group's payout rotation.
+///
+/// Returned by [`crate::contract::AjoContract::get_payout_schedule`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutPosition {
+    /// Zero-based position in the rotation.
+    pub position: u32,
+    /// The member holding the seat.
+    pub member: Address,
+    /// Which of the member's seats this is, starting at 1.
+    pub seat: u32,
+    /// The cycle whose payout goes to this seat.
+    pub cycle: u32,
+    /// `true` once the payout for this seat has been executed.
+    pub paid_out: bool,
+}
+
+/// Who receives the yield earned while a group's pot is parked in a vault.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum YieldPolicy {
+    /// Added to the payout of the seat being paid.
+    Recipient = 0,
+    /// Shared across every seat when the rotation ends or the group is refunded.
+    Members = 1,
+    /// Paid into the insurance pool for the group token.
+    Insurance = 2,
+}
+
+/// A group's vault integration and the funds it currently has parked.
+///
+/// Returned by [`crate::contract::AjoContract::get_group_vault`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupVault {
+    /// Address of the vault contract.
+    pub vault: Address,
+    /// How yield on the parked funds is shared.
+    pub yield_policy: YieldPolicy,
+    /// Contributions currently parked in the vault.
+    pub principal: i128,
+    /// The group's shares of everything this contract holds in the vault.
+    pub shares: i128,
+    /// Yield held for the members under [`YieldPolicy::Members`].
+    pub member_yield: i128,
+}
+
+/// Member yield that is still being paid out to a group's members.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberYieldPayout {
+    /// Yield paid per seat held.
+    pub per_seat: i128,
+    /// Position in the member list of the next member to pay.
+    pub next_member: u32,
+}
+
+/// Links a renewed group to the completed group it succeeds.
+///
+/// Stored under the successor's ID. Until `opt_in_deadline` only the invited
+/// previous members may join, each taking their place in the rotated payout order.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Renewal {
+    /// The completed group this one succeeds.
+    pub predecessor: u64,
+    /// Previous members invited back, in the successor's payout order: the
+    /// predecessor's order rotated by one so its first recipient goes last.
+    pub invitees: Vec<Address>,
+    /// Unix timestamp until which only invitees may join.
+    pub opt_in_deadline: u64,
+    /// Whether invitees bring their reliability record with them when they opt in.
+    pub carry_reliability: bool,
+}
+
+/// Outcome of an auto-debit attempt for a single member.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CollectionOutcome {
+    /// The contribution was collected; carries the amount pulled from the member,
+    /// which excludes anything already covered by their credit.
+    Collected(i128),
+    /// The contribution could not be pulled; carries the [`AjoError`] code,
+    /// e.g. `AjoError::InsufficientAllowance as u32`.
+    ///
+    /// [`AjoError`]: crate::errors::AjoError
+    Failed(u32),
+}
+
+/// Per-member result returned by [`crate::contract::AjoContract::collect_due`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollectionResult {
+    /// The opted-in member the contract tried to collect from.
+    pub member: Address,
+    /// Whether the collection succeeded, and why not if it failed.
+    pub outcome: CollectionOutcome,
+}
+
+/// Outcome for one group in a batch call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BatchOutcome {
+    /// The operation succeeded for the group.
+    Succeeded,
+    /// The group rejected the operation; carries the [`AjoError`] code,
+    /// e.g. `AjoError::AlreadyContributed as u32`.
+    ///
+    /// [`AjoError`]: crate::errors::AjoError
+    Failed(u32),
+}
+
+/// Per-group result returned by [`crate::contract::AjoContract::contribute_many`]
+/// and [`crate::contract::AjoContract::claim_many`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchResult {
+    /// The group the operation was attempted on.
+    pub group_id: u64,
+    /// Whether it succeeded, and why not if it failed.
+    pub outcome: BatchOutcome,
+}
+
+/// A transition carried out by [`crate::contract::AjoContract::poke`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PokeAction {
+    /// Auto-debit contributions were attempted; carries how many members paid.
+    ContributionsCollected(u32),
+    /// The payout for the cycle carried here was executed.
+    PayoutExecuted(u32),
+    /// The last seat was paid and the group is now complete.
+    Completed,
+    /// Collected contributions were parked in the group's vault; carries the amount.
+    FundsParked(i128),
+    /// Prepaid credit was rolled into the current cycle; carries how many members paid.
+    CreditsApplied(u32),
+    /// Refunds of a cancelled group were paid; carries how many members were settled.
+    RefundsProcessed(u32),
+    /// Member yield was paid out; carries how many members were settled.
+    YieldDistributed(u32),
+    /// The cycle's grace period ended with members unpaid; carries how many were
+    /// marked in default.
+    DefaultsMarked(u32),
+}
+
+/// What a single [`crate::contract::AjoContract::poke`] did to a group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PokeResult {
+    /// The group that was poked.
+    pub group_id: u64,
+    /// Transitions carried out, in order; empty if nothing was due.
+    pub actions: Vec<PokeAction>,
+    /// Reward paid to the keeper, in the group token.
+    pub keeper_reward: i128,
+}
 
 /// Records that a member has received their payout for a given cycle.
+///
+/// `amount` is what the member was sent: `base_amount + penalty_bonus + vault_yield`
+/// less both fees.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PayoutRecord {
     pub group_id: u64,
+    /// The cycle the payout was made for.
+    pub cycle: u32,
     pub member: Address,
+    /// The cycle's contributions, one per seat.
+    pub base_amount: i128,
+    /// Late penalties collected during the cycle.
+    pub penalty_bonus: i128,
+    /// Yield from the group's vault paid out with the final payout.
+    pub vault_yield: i128,
+    /// Amount paid to the member, after fees.
     pub amount: i128,
+    /// Platform fee taken from the payout.
+    pub platform_fee: i128,
+    /// Organizer fee taken from the payout.
+    pub organizer_fee: i128,
     pub timestamp: u64,
 }
 
+/// A member's money in and out of one group, in the group token.
+///
+/// `net_position` is what the member has received (payouts and refund) less
+/// what they have paid in (contributions, penalties and unapplied credit);
+/// negative while the group still owes them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatementTotals {
+    pub group_id: u64,
+    pub token_address: Address,
+    /// Contributions paid, excluding penalties.
+    pub contributed: i128,
+    /// Late penalties paid.
+    pub penalties: i128,
+    /// Insurance premiums taken from the contributions.
+    pub insurance_premiums: i128,
+    /// Funds held for the member that have not been applied to a cycle yet.
+    pub credit: i128,
+    /// Payouts received, after fees.
+    pub payouts_received: i128,
+    /// Refund received, if the group was cancelled or refunded.
+    pub refunded: i128,
+    /// Amount still owed for the current cycle if paid now.
+    pub amount_due: i128,
+    pub net_position: i128,
+}
+
+/// Full ledger of a member's activity in a group.
+///
+/// Returned by [`crate::contract::AjoContract::get_member_statement`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberStatement {
+    pub member: Address,
+    /// Number of seats the member holds.
+    pub seats: u32,
+    /// Contributions paid, one per cycle, with lateness and penalty details.
+    pub contributions: Vec<ContributionRecord>,
+    /// Cycles the group has reached that the member has not paid for.
+    pub unpaid_cycles: Vec<u32>,
+    /// Payouts received, one per seat paid so far.
+    pub payouts: Vec<PayoutRecord>,
+    /// The member's refund; empty unless the group was cancelled or refunded.
+    pub refunds: Vec<RefundRecord>,
+    pub totals: StatementTotals,
+}
+
+/// What a member owes one group next, and when they are paid.
+///
+/// Returned by [`crate::contract::AjoContract::get_member_obligations`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberObligation {
+    pub group_id: u64,
+    pub token_address: Address,
+    /// Cycle the next contribution is for: the current cycle until it is paid,
+    /// then the one after.
+    pub cycle: u32,
+    /// Amount to pay for `cycle` if paid now, including any late penalty and
+    /// less credit held; `0` once nothing more is owed.
+    pub amount_due: i128,
+    /// Unix timestamp when `cycle` ends; paying later incurs the penalty.
+    pub deadline: u64,
+    /// Unix timestamp when the grace period for `cycle` ends.
+    pub grace_end: u64,
+    /// Payout position of the member's next unpaid seat; `None` once every
+    /// seat has been paid out.
+    pub payout_position: Option<u32>,
+    /// Earliest time that seat's payout can run, assuming every cycle before
+    /// it is paid out as soon as its grace period ends.
+    pub estimated_payout_at: Option<u64>,
+}
+
+/// How a member's contribution for one cycle stands.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContributionState {
+    /// Not paid yet, and the cycle's grace period has not ended.
+    Pending = 0,
+    /// Paid before the cycle ended.
+    OnTime = 1,
+    /// Paid during the grace period, with a penalty.
+    Late = 2,
+    /// Not paid, and the cycle's grace period has ended.
+    Missed = 3,
+    /// Not paid, but an approved insurance claim covered the default.
+    CoveredByInsurance = 4,
+}
+
+/// One row of a group's contribution matrix.
+///
+/// Returned by [`crate::contract::AjoContract::get_contribution_matrix`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CycleContributions {
+    pub cycle: u32,
+    /// One state per member in the requested range, in the order of the
+    /// group's member list.
+    pub states: Vec<ContributionState>,
+}
+
+/// Totals for a cycle that has been paid out.
+///
+/// Returned by [`crate::contract::AjoContract::get_cycle_summary`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CycleSummary {
+    pub group_id: u64,
+    pub cycle: u32,
+    /// Contributions and penalties paid in for the cycle.
+    pub collected: i128,
+    /// Late penalties collected in the cycle, paid out as a bonus.
+    pub penalty_pool: i128,
+    /// Member who received the cycle's payout.
+    pub recipient: Address,
+    /// Amount the recipient received, after fees.
+    pub payout_amount: i128,
+    /// Unix timestamp of the payout.
+    pub paid_at: u64,
+}
+
+/// An invariant violation or warning found by a group audit.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AuditIssue {
+    /// A reported cycle that was paid out has fewer contributions than members.
+    MissingContributions = 0,
+    /// `payout_index` disagrees with the payout records of the reported cycles
+    /// or exceeds the number of seats.
+    PayoutMismatch = 1,
+    /// The contract holds less of the group token, counting the group's vault
+    /// principal, than the group alone has in escrow.
+    Underfunded = 2,
+    /// The group's parameters fall outside the monitored security limits.
+    UnusualParameters = 3,
+    /// The current cycle's grace period has ended with members still to pay,
+    /// so the payout cannot run.
+    Stuck = 4,
+}
+
+/// Expected against received contributions for one cycle of an audited group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CycleAudit {
+    pub cycle: u32,
+    /// Number of members expected to contribute.
+    pub expected_count: u32,
+    /// Number of members who contributed.
+    pub received_count: u32,
+    /// Contributions due across every seat, excluding penalties.
+    pub expected_amount: i128,
+    /// Contributions received, excluding penalties.
+    pub received_amount: i128,
+}
+
+/// Report on a group's bookkeeping and state.
+///
+/// Returned by [`crate::contract::AjoContract::audit_group`] and
+/// [`crate::contract::AjoContract::audit_all`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupAudit {
+    pub group_id: u64,
+    pub state: GroupState,
+    /// One row for each of the last
+    /// [`AUDIT_CYCLES`](crate::security::limits::AUDIT_CYCLES) cycles the
+    /// group has reached, in cycle order.
+    pub cycles: Vec<CycleAudit>,
+    pub seat_count: u32,
+    pub payout_index: u32,
+    /// Number of reported cycles with a stored payout record.
+    pub payouts_recorded: u32,
+    /// Contributions and penalties collected for the open cycle plus member
+    /// credit, all of which the contract holds for the group.
+    pub escrow_held: i128,
+    /// Principal the group has parked in its vault.
+    pub vault_principal: i128,
+    /// The contract's balance of the group token, shared by every group and
+    /// fee pool in that token.
+    pub token_balance: i128,
+    /// Members who have not contributed to the open cycle.
+    pub pending_contributors: Vec<Address>,
+    /// Everything found wrong, empty for a healthy group.
+    pub issues: Vec<AuditIssue>,
+}
+
 /// Insurance configuration for a group.
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -1,6 +1,7 @@
 use soroban_sdk::{Address, Env, Vec};
 
-use crate::types::Group;
+use crate::errors::AjoError;
+use crate::types::{CycleSchedule, Group};
 
 /// Returns `true` if `address` appears in the group's `members` list.
 ///
@@ -18,9 +19,50 @@ pub fn is_member(members: &Vec<Address>, address: &Address) -> bool {
     members.iter().any(|m| m == *address)
 }
 
+/// Returns the number of seats `address` holds in the group.
+///
+/// # Arguments
+/// * `env` - The contract environment used to read the member's slot
+/// * `group` - The group to check
+/// * `address` - The member to count seats for
+///
+/// # Returns
+/// The member's seat count, or `0` for non-members
+pub fn seat_count(env: &Env, group: &Group, address: &Address) -> u32 {
+    crate::storage::get_member_slot(env, group.id, address).map_or(0, |slot| slot.seats)
+}
+
+/// Returns the amount a member owes each cycle for all of their seats.
+///
+/// # Arguments
+/// * `env` - The contract environment used to read the member's slot
+/// * `group` - The group being paid into
+/// * `address` - The member paying
+///
+/// # Returns
+/// `contribution_amount × seat_count`, excluding any late penalty
+pub fn cycle_contribution(env: &Env, group: &Group, address: &Address) -> i128 {
+    group.contribution_amount * seat_count(env, group, address) as i128
+}
+
+/// Returns `true` if `address` is the group creator or one of its co-organizers.
+///
+/// # Arguments
+/// * `env` - The contract environment used to read the co-organizer list
+/// * `group` - The group to check
+/// * `address` - The address to look for
+///
+/// # Returns
+/// `true` if the address may perform organizer actions on the group
+pub fn is_organizer(env: &Env, group: &Group, address: &Address) -> bool {
+    group.creator == *address
+        || is_member(&crate::storage::get_co_organizers(env, group.id), address)
+}
+
 /// Returns `true` if every member of the group has contributed in the current cycle.
 ///
-/// Iterates over all members and short-circuits on the first missing contribution.
+/// Reads the cycle's contribution bitmap once and compares the number of
+/// members marked in it with the group's member count.
 /// This is called by [`execute_payout`](crate::contract::AjoContract::execute_payout)
 /// to gate payout execution — a payout cannot proceed until this returns `true`.
 ///
@@ -32,28 +74,28 @@ pub fn is_member(members: &Vec<Address>, address: &Address) -> bool {
 /// `true` if all members have contributed, `false` otherwise
 #[inline]
 pub fn all_members_contributed(env: &Env, group: &Group) -> bool {
-    let group_id = group.id;
-    let cycle = group.current_cycle;
-    
-    group.members.iter().all(|member| {
-        crate::storage::has_contributed(env, group_id, cycle, &member)
-    })
+    contributions_received(env, group, group.current_cycle) == group.member_count
+}
+
+/// Returns the number of members who have contributed in a cycle.
+#[inline]
+pub fn contributions_received(env: &Env, group: &Group, cycle: u32) -> u32 {
+    crate::storage::get_contribution_bitmap(env, group.id, cycle).count_ones()
 }
 
 /// Calculates the total payout amount for a single cycle.
 ///
-/// The payout equals each member's fixed contribution multiplied by the total
-/// number of members. This ensures the recipient receives the full pool of contributions.
+/// The payout equals the fixed per-seat contribution multiplied by the total
+/// number of seats. This ensures the recipient receives the full pool of contributions.
 ///
 /// # Arguments
 /// * `group` - The group whose payout is being calculated
 ///
 /// # Returns
-/// Total payout in stroops (`contribution_amount × member_count`)
+/// Total payout in stroops (`contribution_amount × seat_count`)
 #[inline]
 pub fn calculate_payout_amount(group: &Group) -> i128 {
-    let member_count = group.members.len() as i128;
-    group.contribution_amount * member_count
+    group.contribution_amount * group.seat_count as i128
 }
 
 /// Returns the current ledger timestamp in seconds since Unix epoch.
@@ -71,94 +113,232 @@ pub fn get_current_timestamp(env: &Env) -> u64 {
     env.ledger().timestamp()
 }
 
-/// Validates that group creation parameters meet business rules.
-///
-/// Called at the start of [`create_group`](crate::contract::AjoContract::create_group)
-/// before any state is written. All three parameters are validated together
-/// so callers receive a specific error identifying which constraint failed.
-///
-/// # Arguments
-/// * `amount` - Proposed contribution amount in stroops; must be > 0
-/// * `duration` - Proposed cycle duration in seconds; must be > 0
-/// * `max_members` - Proposed member cap; must be between 2 and 100 inclusive
+/// Seconds in a day.
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Largest UTC offset a calendar schedule may use (14 hours).
+const MAX_UTC_OFFSET: i32 = 50_400;
+
+/// Validates a cycle schedule and returns its nominal cycle length in seconds.
 ///
-/// # Returns
-/// `Ok(())` if all parameters are valid
+/// The nominal length is the fixed duration itself, one week for weekly
+/// schedules and 31 days for monthly ones; it is what the duration limits are
+/// checked against. A zero fixed duration is left to
+/// [`validate_group_params`](crate::validation::validate_group_params).
 ///
 /// # Errors
-/// * [`ContributionAmountZero`](crate::errors::AjoError::ContributionAmountZero) — if `amount == 0`
-/// * [`ContributionAmountNegative`](crate::errors::AjoError::ContributionAmountNegative) — if `amount < 0`
-/// * [`CycleDurationZero`](crate::errors::AjoError::CycleDurationZero) — if `duration == 0`
-/// * [`MaxMembersBelowMinimum`](crate::errors::AjoError::MaxMembersBelowMinimum) — if `max_members < 2`
-/// * [`MaxMembersAboveLimit`](crate::errors::AjoError::MaxMembersAboveLimit) — if `max_members > 100`
-pub fn validate_group_params(
-    amount: i128,
-    duration: u64,
-    max_members: u32,
-) -> Result<(), crate::errors::AjoError> {
-    const MAX_MEMBERS_LIMIT: u32 = 100;
-
-    // Amounts must be positive
-
-    // Amounts must be positive
-    if amount == 0 {
-        return Err(crate::errors::AjoError::ContributionAmountZero);
-    } else if amount < 0 {
-        return Err(crate::errors::AjoError::ContributionAmountNegative);
+/// * `InvalidSchedule` - If the weekday, day of month, time of day or UTC offset
+///   is out of range
+pub fn validate_schedule(schedule: &CycleSchedule) -> Result<u64, AjoError> {
+    let (time_of_day, utc_offset, nominal) = match schedule {
+        CycleSchedule::Fixed(duration) => return Ok(*duration),
+        CycleSchedule::Weekly(weekly) => {
+            if weekly.weekday > 6 {
+                return Err(AjoError::InvalidSchedule);
+            }
+            (
+                weekly.time_of_day,
+                weekly.utc_offset,
+                7 * SECONDS_PER_DAY as u64,
+            )
+        }
+        CycleSchedule::Monthly(monthly) => {
+            if monthly.day == 0 || monthly.day > 31 {
+                return Err(AjoError::InvalidSchedule);
+            }
+            (
+                monthly.time_of_day,
+                monthly.utc_offset,
+                31 * SECONDS_PER_DAY as u64,
+            )
+        }
+    };
+    if time_of_day as i64 >= SECONDS_PER_DAY || utc_offset.unsigned_abs() > MAX_UTC_OFFSET as u32 {
+        return Err(AjoError::InvalidSchedule);
     }
+    Ok(nominal)
+}
 
-    // Time stops for no one - especially not a zero duration esusu
-
-    // Time stops for no one - especially not a zero duration esusu
-    if duration == 0 {
-        return Err(crate::errors::AjoError::CycleDurationZero);
+/// Returns the length of the shortest full cycle `schedule` can produce, in seconds.
+///
+/// Equal to the nominal length except for monthly schedules, where a day clamped
+/// to the end of February gives a 28-day cycle.
+pub fn shortest_cycle_duration(schedule: &CycleSchedule) -> u64 {
+    match schedule {
+        CycleSchedule::Fixed(duration) => *duration,
+        CycleSchedule::Weekly(_) => 7 * SECONDS_PER_DAY as u64,
+        CycleSchedule::Monthly(_) => 28 * SECONDS_PER_DAY as u64,
     }
+}
+
+/// Returns the first cycle end strictly after `start` under `schedule`.
+///
+/// Weekly and monthly ends are matched in local time (`start + utc_offset`).
+/// A monthly day past the end of a short month is clamped to its last day, so
+/// a schedule on the 31st ends on the 28th or 29th in February.
+pub fn next_cycle_end(schedule: &CycleSchedule, start: u64) -> u64 {
+    let (offset, target) = match schedule {
+        CycleSchedule::Fixed(duration) => return start + duration,
+        CycleSchedule::Weekly(weekly) => {
+            let offset = weekly.utc_offset as i64;
+            let local = start as i64 + offset;
+            let day = local.div_euclid(SECONDS_PER_DAY);
+            // 1970-01-01 was a Thursday; weekdays count from Monday = 0
+            let weekday = (day + 3).rem_euclid(7);
+            let days_ahead = (weekly.weekday as i64 - weekday).rem_euclid(7);
+            let mut target = (day + days_ahead) * SECONDS_PER_DAY + weekly.time_of_day as i64;
+            if target <= local {
+                target += 7 * SECONDS_PER_DAY;
+            }
+            (offset, target)
+        }
+        CycleSchedule::Monthly(monthly) => {
+            let offset = monthly.utc_offset as i64;
+            let local = start as i64 + offset;
+            let (mut year, mut month, _) = civil_from_days(local.div_euclid(SECONDS_PER_DAY));
+            let mut target = monthly_target(year, month, monthly.day, monthly.time_of_day);
+            if target <= local {
+                if month == 12 {
+                    year += 1;
+                    month = 1;
+                } else {
+                    month += 1;
+                }
+                target = monthly_target(year, month, monthly.day, monthly.time_of_day);
+            }
+            (offset, target)
+        }
+    };
+    (target - offset) as u64
+}
 
-    // We need at least two people to rotate money
+/// Returns the unix timestamp (seconds) when the group's current cycle ends.
+pub fn cycle_end_time(group: &Group) -> u64 {
+    next_cycle_end(&group.schedule, group.cycle_start_time)
+}
 
-    // We need at least two people to rotate money
-    if max_members < 2 {
-        return Err(crate::errors::AjoError::MaxMembersBelowMinimum);
+/// Returns when the cycle after the group's current one starts, for a payout at `now`.
+///
+/// Cycles stay on the group's schedule: the next cycle starts at the current
+/// cycle's scheduled end. Any later windows that have also ended by `now` are
+/// skipped, so the result is the most recent scheduled boundary at or before
+/// `now` (and never earlier than the current cycle's end).
+pub fn next_cycle_start(group: &Group, now: u64) -> u64 {
+    let mut start = cycle_end_time(group);
+    if let CycleSchedule::Fixed(duration) = group.schedule {
+        return start + now.saturating_sub(start) / duration * duration;
     }
+    loop {
+        let end = next_cycle_end(&group.schedule, start);
+        if end > now {
+            return start;
+        }
+        start = end;
+    }
+}
 
-    // Reasonable upper limit to prevent gas issues
+/// Local timestamp of `day` (clamped to the month's length) at `time_of_day`.
+fn monthly_target(year: i64, month: u32, day: u32, time_of_day: u32) -> i64 {
+    let day = day.min(days_in_month(year, month));
+    days_from_civil(year, month, day) * SECONDS_PER_DAY + time_of_day as i64
+}
 
-    // Reasonable upper limit to prevent gas issues
-    if max_members > MAX_MEMBERS_LIMIT {
-        return Err(crate::errors::AjoError::MaxMembersAboveLimit);
+/// Number of days in the given month of the proleptic Gregorian calendar.
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
     }
+}
 
-    Ok(())
+/// Days since 1970-01-01 for a civil date (Howard Hinnant's algorithm).
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
 }
 
-/// Validates grace period and penalty rate parameters used when creating a group.
-///
-/// * `grace_period` - must be <= 7 days (604800 seconds)
-/// * `penalty_rate` - must be between 0 and 100 inclusive
-pub fn validate_penalty_params(
-    grace_period: u64,
-    penalty_rate: u32,
-) -> Result<(), crate::errors::AjoError> {
-    const MAX_GRACE: u64 = 604_800; // 7 days
-    if grace_period > MAX_GRACE {
-        return Err(crate::errors::AjoError::InvalidGracePeriod);
-    }
-    if penalty_rate > 100 {
-        return Err(crate::errors::AjoError::InvalidPenaltyRate);
-    }
-    Ok(())
+/// Civil `(year, month, day)` for a count of days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
 }
 
 /// Returns the unix timestamp (seconds) when the current cycle's grace period ends.
-/// Calculated as `cycle_start_time + cycle_duration + grace_period`.
+/// Calculated as [`cycle_end_time`] plus `grace_period`.
 pub fn get_grace_period_end(group: &crate::types::Group) -> u64 {
-    group.cycle_start_time + group.cycle_duration + group.grace_period
+    cycle_end_time(group) + group.grace_period
 }
 
 /// Returns `true` if the provided `current_time` falls after the cycle end
 /// and before or at the grace period end.
 pub fn is_within_grace_period(group: &crate::types::Group, current_time: u64) -> bool {
-    let cycle_end = group.cycle_start_time + group.cycle_duration;
+    let cycle_end = cycle_end_time(group);
     let grace_end = get_grace_period_end(group);
     current_time > cycle_end && current_time <= grace_end
 }
+
+/// Calculates the penalty owed on a late contribution.
+///
+/// # Arguments
+/// * `amount` - The base contribution amount
+/// * `penalty_rate` - Penalty percentage (0-100)
+///
+/// # Returns
+/// `amount × penalty_rate / 100`, rounded down
+pub fn calculate_penalty(amount: i128, penalty_rate: u32) -> i128 {
+    amount * (penalty_rate as i128) / 100
+}
+
+/// Returns the amount a member owes for the group's current cycle if paid at `current_time`.
+///
+/// This is the member's [`cycle_contribution`] plus the late penalty when
+/// `current_time` falls inside the grace period.
+///
+/// # Arguments
+/// * `env` - The contract environment used to read the member's slot
+/// * `group` - The group being paid into
+/// * `address` - The member paying
+/// * `current_time` - The time of payment
+///
+/// # Returns
+/// The amount due in token units
+pub fn amount_due(env: &Env, group: &Group, address: &Address, current_time: u64) -> i128 {
+    let base = cycle_contribution(env, group, address);
+    if is_within_grace_period(group, current_time) {
+        base + calculate_penalty(base, group.penalty_rate)
+    } else {
+        base
+    }
+}
+
+/// Calculates a member's reliability score as the percentage of on-time contributions.
+///
+/// Returns `100` for members with no contribution history.
+pub fn calculate_reliability_score(on_time_count: u32, late_count: u32) -> u32 {
+    let total = on_time_count + late_count;
+    if total == 0 {
+        return 100;
+    }
+    (on_time_count * 100) / total
+}
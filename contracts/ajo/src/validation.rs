@@ -0,0 +1,269 @@
+//! Validation limits and the checks that apply them
+//!
+//! Every limit on group parameters, metadata and refund voting lives in one
+//! [`ContractConfig`], stored in instance storage and read by the checks
+//! below. Until the admin changes it with
+//! [`set_config`](crate::contract::AjoContract::set_config), the contract uses
+//! [`default_config`].
+//!
+//! A new config is checked as a whole before it is stored:
+//! - Each minimum is at most its maximum, and the grace period fits in the
+//!   longest cycle.
+//! - Member counts stay within [`MIN_MEMBERS_LIMIT`] and [`MAX_MEMBERS_LIMIT`],
+//!   percentages within 100, and text lengths within [`MAX_TEXT_LENGTH_LIMIT`],
+//!   so no config can allow groups or metadata the contract cannot handle.
+//!
+//! New limits apply to groups created and metadata set afterwards. Existing
+//! groups keep their parameters, and a refund request keeps the voting
+//! deadline it was opened with.
+//!
+//! Contribution amounts are bounded per token by the token registry instead.
+
+use soroban_sdk::Env;
+
+use crate::errors::AjoError;
+use crate::storage;
+use crate::types::ContractConfig;
+
+/// Fewest members a rotation can work with.
+pub const MIN_MEMBERS_LIMIT: u32 = 2;
+
+/// Most members a group may ever have, to keep loops over members within
+/// resource limits.
+pub const MAX_MEMBERS_LIMIT: u32 = 100;
+
+// Each member has a bit in a cycle's `u128` contribution bitmap.
+const _: () = assert!(MAX_MEMBERS_LIMIT <= u128::BITS);
+
+/// Longest any metadata text field may ever be, in bytes.
+pub const MAX_TEXT_LENGTH_LIMIT: u32 = 1_000;
+
+/// Returns the limits used until the admin sets a config.
+pub fn default_config() -> ContractConfig {
+    ContractConfig {
+        min_members: MIN_MEMBERS_LIMIT,
+        max_members: MAX_MEMBERS_LIMIT,
+        min_cycle_duration: 3_600,
+        max_cycle_duration: 31_536_000,
+        max_grace_period: 604_800,
+        max_penalty_rate: 100,
+        max_name_length: 50,
+        max_description_length: 250,
+        max_rules_length: MAX_TEXT_LENGTH_LIMIT,
+        voting_period: 604_800,
+        refund_approval_threshold: 51,
+    }
+}
+
+/// Returns the current validation limits.
+pub fn get_config(env: &Env) -> ContractConfig {
+    storage::get_config(env).unwrap_or_else(default_config)
+}
+
+/// Checks that a config's bounds are consistent and within the hard limits.
+///
+/// # Errors
+/// * `MaxMembersBelowMinimum` - If min_members is below [`MIN_MEMBERS_LIMIT`] or above max_members
+/// * `MaxMembersAboveLimit` - If max_members exceeds [`MAX_MEMBERS_LIMIT`]
+/// * `CycleDurationZero` - If min_cycle_duration is zero or above max_cycle_duration
+/// * `InvalidGracePeriod` - If max_grace_period exceeds max_cycle_duration, or
+///   voting_period is zero
+/// * `InvalidPenaltyRate` - If max_penalty_rate exceeds 100, or
+///   refund_approval_threshold is not between 1 and 100
+/// * `MetadataTooLong` - If a text length limit is zero or exceeds [`MAX_TEXT_LENGTH_LIMIT`]
+pub fn check_config(config: &ContractConfig) -> Result<(), AjoError> {
+    if config.min_members < MIN_MEMBERS_LIMIT || config.min_members > config.max_members {
+        return Err(AjoError::MaxMembersBelowMinimum);
+    }
+    if config.max_members > MAX_MEMBERS_LIMIT {
+        return Err(AjoError::MaxMembersAboveLimit);
+    }
+    if config.min_cycle_duration == 0 || config.min_cycle_duration > config.max_cycle_duration {
+        return Err(AjoError::CycleDurationZero);
+    }
+    if config.max_grace_period > config.max_cycle_duration || config.voting_period == 0 {
+        return Err(AjoError::InvalidGracePeriod);
+    }
+    if config.max_penalty_rate > 100
+        || config.refund_approval_threshold == 0
+        || config.refund_approval_threshold > 100
+    {
+        return Err(AjoError::InvalidPenaltyRate);
+    }
+    for length in [
+        config.max_name_length,
+        config.max_description_length,
+        config.max_rules_length,
+    ] {
+        if length == 0 || length > MAX_TEXT_LENGTH_LIMIT {
+            return Err(AjoError::MetadataTooLong);
+        }
+    }
+    Ok(())
+}
+
+/// Validates the contribution amount, nominal cycle length and member cap of a
+/// new group.
+///
+/// # Errors
+/// * `ContributionAmountZero` - If amount == 0
+/// * `ContributionAmountNegative` - If amount < 0
+/// * `CycleDurationZero` - If duration is outside the configured cycle bounds
+/// * `MaxMembersBelowMinimum` - If max_members is below the configured minimum
+/// * `MaxMembersAboveLimit` - If max_members is above the configured maximum
+pub fn validate_group_params(
+    config: &ContractConfig,
+    amount: i128,
+    duration: u64,
+    max_members: u32,
+) -> Result<(), AjoError> {
+    if amount == 0 {
+        return Err(AjoError::ContributionAmountZero);
+    }
+    if amount < 0 {
+        return Err(AjoError::ContributionAmountNegative);
+    }
+    if duration < config.min_cycle_duration || duration > config.max_cycle_duration {
+        return Err(AjoError::CycleDurationZero);
+    }
+    if max_members < config.min_members {
+        return Err(AjoError::MaxMembersBelowMinimum);
+    }
+    if max_members > config.max_members {
+        return Err(AjoError::MaxMembersAboveLimit);
+    }
+    Ok(())
+}
+
+/// Validates the grace period and late penalty rate of a new group.
+///
+/// The grace period must be shorter than the schedule's shortest cycle: a payout
+/// cannot run until the grace period is over, so a longer one would make every
+/// cycle skip the window after it.
+///
+/// # Errors
+/// * `InvalidGracePeriod` - If grace_period exceeds the configured maximum, or
+///   is not shorter than shortest_cycle
+/// * `InvalidPenaltyRate` - If penalty_rate exceeds the configured maximum
+pub fn validate_penalty_params(
+    config: &ContractConfig,
+    shortest_cycle: u64,
+    grace_period: u64,
+    penalty_rate: u32,
+) -> Result<(), AjoError> {
+    if grace_period > config.max_grace_period || grace_period >= shortest_cycle {
+        return Err(AjoError::InvalidGracePeriod);
+    }
+    if penalty_rate > config.max_penalty_rate {
+        return Err(AjoError::InvalidPenaltyRate);
+    }
+    Ok(())
+}
+
+/// Validates the lengths of a group's or template's metadata fields, in bytes.
+///
+/// # Errors
+/// * `MetadataTooLong` - If any field exceeds its configured maximum
+pub fn validate_metadata_lengths(
+    config: &ContractConfig,
+    name_len: u32,
+    description_len: u32,
+    rules_len: u32,
+) -> Result<(), AjoError> {
+    if name_len > config.max_name_length
+        || description_len > config.max_description_length
+        || rules_len > config.max_rules_length
+    {
+        return Err(AjoError::MetadataTooLong);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config_is_consistent() {
+        assert_eq!(check_config(&default_config()), Ok(()));
+    }
+
+    #[test]
+    fn test_check_config_rejects_crossed_bounds() {
+        let mut config = default_config();
+        config.min_members = 10;
+        config.max_members = 5;
+        assert_eq!(check_config(&config), Err(AjoError::MaxMembersBelowMinimum));
+
+        let mut config = default_config();
+        config.min_cycle_duration = config.max_cycle_duration + 1;
+        assert_eq!(check_config(&config), Err(AjoError::CycleDurationZero));
+
+        let mut config = default_config();
+        config.max_grace_period = config.max_cycle_duration + 1;
+        assert_eq!(check_config(&config), Err(AjoError::InvalidGracePeriod));
+    }
+
+    #[test]
+    fn test_check_config_enforces_hard_limits() {
+        let mut config = default_config();
+        config.max_members = MAX_MEMBERS_LIMIT + 1;
+        assert_eq!(check_config(&config), Err(AjoError::MaxMembersAboveLimit));
+
+        let mut config = default_config();
+        config.refund_approval_threshold = 101;
+        assert_eq!(check_config(&config), Err(AjoError::InvalidPenaltyRate));
+
+        let mut config = default_config();
+        config.max_description_length = MAX_TEXT_LENGTH_LIMIT + 1;
+        assert_eq!(check_config(&config), Err(AjoError::MetadataTooLong));
+    }
+
+    #[test]
+    fn test_validate_group_params_valid() {
+        let result = validate_group_params(&default_config(), 1_000_000, 604_800, 10);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_validate_group_params_zero_contribution() {
+        let result = validate_group_params(&default_config(), 0, 604_800, 10);
+        assert_eq!(result, Err(AjoError::ContributionAmountZero));
+    }
+
+    #[test]
+    fn test_validate_group_params_negative_contribution() {
+        let result = validate_group_params(&default_config(), -100, 604_800, 10);
+        assert_eq!(result, Err(AjoError::ContributionAmountNegative));
+    }
+
+    #[test]
+    fn test_validate_group_params_zero_duration() {
+        let result = validate_group_params(&default_config(), 1_000_000, 0, 10);
+        assert_eq!(result, Err(AjoError::CycleDurationZero));
+    }
+
+    #[test]
+    fn test_validate_group_params_too_few_members() {
+        let result = validate_group_params(&default_config(), 1_000_000, 604_800, 1);
+        assert_eq!(result, Err(AjoError::MaxMembersBelowMinimum));
+    }
+
+    #[test]
+    fn test_validate_group_params_too_many_members() {
+        let result = validate_group_params(&default_config(), 1_000_000, 604_800, 101);
+        assert_eq!(result, Err(AjoError::MaxMembersAboveLimit));
+    }
+
+    #[test]
+    fn test_validate_metadata_lengths_valid() {
+        let result = validate_metadata_lengths(&default_config(), 50, 200, 500);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_validate_metadata_lengths_too_long() {
+        let result = validate_metadata_lengths(&default_config(), 51, 200, 500);
+        assert_eq!(result, Err(AjoError::MetadataTooLong));
+    }
+}
@@ -0,0 +1,280 @@
+//! Optional vault integration for idle group funds
+//!
+//! Contributions can sit in the contract for a whole cycle before they are paid
+//! out. A group's organizers may point it at an admin-approved vault contract
+//! implementing [`VaultInterface`] with
+//! [`set_group_vault`](crate::contract::AjoContract::set_group_vault). Anyone can
+//! then park the contributions collected so far with
+//! [`park_funds`](crate::contract::AjoContract::park_funds), and they are
+//! recalled automatically before the group's next payout or refund.
+//!
+//! Several groups may share a vault. The contract holds one position per vault
+//! and tracks each group's shares of it, so yield is attributed to the groups
+//! whose funds earned it. Shares are rounded up on deposit so that a group's
+//! slice is never worth less than its principal unless the vault loses value.
+//!
+//! Payouts only rely on the principal coming back. Yield is shared according to
+//! the group's [`YieldPolicy`]; if the vault cannot return the principal, the
+//! recall fails with `TransferFailed` and the payout or refund is not made.
+
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contractclient, symbol_short, vec, Address, Env, IntoVal,
+};
+
+use crate::errors::AjoError;
+use crate::events;
+use crate::security::limits::SETTLEMENT_BATCH_SIZE;
+use crate::storage;
+use crate::types::{Group, GroupVault, MemberYieldPayout, YieldPolicy};
+use crate::utils;
+
+/// Interface a vault contract must implement to hold group funds.
+///
+/// A vault manages a single underlying token, which must be the group token.
+#[contractclient(name = "VaultClient")]
+pub trait VaultInterface {
+    /// Pull `amount` of the underlying token from `from` and hold it for `from`.
+    fn deposit(env: Env, from: Address, amount: i128);
+
+    /// Send `amount` of the underlying token held for `to` back to `to`.
+    fn withdraw(env: Env, to: Address, amount: i128);
+
+    /// Value of everything held for `id`, including accrued yield.
+    fn balance(env: Env, id: Address) -> i128;
+}
+
+/// Returns the contributions a group has collected for its current cycle.
+pub fn idle_funds(env: &Env, group: &Group) -> i128 {
+    storage::get_cycle_received(env, group.id, group.current_cycle)
+}
+
+/// Parks a group's collected contributions that are not yet in its vault.
+///
+/// # Returns
+/// The amount deposited, or `0` if the group has no vault or nothing new to park
+///
+/// # Errors
+/// * `TransferFailed` - If the vault rejects the deposit or takes a different amount
+pub fn park(env: &Env, group: &Group) -> Result<i128, AjoError> {
+    let Some(mut position) = storage::get_group_vault(env, group.id) else {
+        return Ok(0);
+    };
+    let amount = idle_funds(env, group) - position.principal;
+    if amount <= 0 {
+        return Ok(0);
+    }
+
+    let contract = env.current_contract_address();
+    let client = VaultClient::new(env, &position.vault);
+    let total_shares = storage::get_vault_shares(env, &position.vault);
+    let vault_value = read_vault_balance(&client, &contract)?;
+    let shares = if total_shares == 0 || vault_value == 0 {
+        amount
+    } else {
+        // Round up so the new shares are worth at least the deposit
+        (amount * total_shares + vault_value - 1) / vault_value
+    };
+
+    // The vault pulls the deposit, so authorize its transfer from this contract
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: group.token_address.clone(),
+                fn_name: symbol_short!("transfer"),
+                args: (contract.clone(), position.vault.clone(), amount).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+
+    let before = crate::token::get_balance(env, &group.token_address, &contract);
+    match client.try_deposit(&contract, &amount) {
+        Ok(Ok(())) => {}
+        _ => return Err(AjoError::TransferFailed),
+    }
+    if before - crate::token::get_balance(env, &group.token_address, &contract) != amount {
+        return Err(AjoError::TransferFailed);
+    }
+
+    storage::set_vault_shares(env, &position.vault, total_shares + shares);
+    position.principal += amount;
+    position.shares += shares;
+    storage::store_group_vault(env, group.id, &position);
+    events::emit_funds_parked(env, group.id, &position.vault, amount, shares);
+
+    Ok(amount)
+}
+
+/// Recalls everything a group has parked in its vault and shares out the yield.
+///
+/// Yield under [`YieldPolicy::Insurance`] goes to the insurance pool and yield
+/// under [`YieldPolicy::Members`] is held for the members. Yield under
+/// [`YieldPolicy::Recipient`] is returned for the caller to add to the payout;
+/// when `for_payout` is `false` there is no recipient and it is held for the
+/// members instead.
+///
+/// # Returns
+/// The yield owed to the payout recipient
+///
+/// # Errors
+/// * `TransferFailed` - If the vault fails or returns less than the principal
+pub fn recall(env: &Env, group: &Group, for_payout: bool) -> Result<i128, AjoError> {
+    let Some(mut position) = storage::get_group_vault(env, group.id) else {
+        return Ok(0);
+    };
+    if position.shares == 0 {
+        return Ok(0);
+    }
+
+    let contract = env.current_contract_address();
+    let client = VaultClient::new(env, &position.vault);
+    let total_shares = storage::get_vault_shares(env, &position.vault);
+    let vault_value = read_vault_balance(&client, &contract)?;
+    let value = vault_value * position.shares / total_shares;
+
+    let before = crate::token::get_balance(env, &group.token_address, &contract);
+    match client.try_withdraw(&contract, &value) {
+        Ok(Ok(())) => {}
+        _ => return Err(AjoError::TransferFailed),
+    }
+    let received = crate::token::get_balance(env, &group.token_address, &contract) - before;
+    if received < position.principal {
+        return Err(AjoError::TransferFailed);
+    }
+
+    let principal = position.principal;
+    let yield_amount = received - principal;
+    storage::set_vault_shares(env, &position.vault, total_shares - position.shares);
+    position.principal = 0;
+    position.shares = 0;
+
+    let mut recipient_yield = 0;
+    match position.yield_policy {
+        YieldPolicy::Recipient if for_payout => recipient_yield = yield_amount,
+        YieldPolicy::Insurance => {
+            if yield_amount > 0 {
+                crate::insurance::deposit_to_pool(env, &group.token_address, yield_amount);
+            }
+        }
+        _ => position.member_yield += yield_amount,
+    }
+    storage::store_group_vault(env, group.id, &position);
+    events::emit_funds_recalled(env, group.id, &position.vault, principal, yield_amount);
+
+    Ok(recipient_yield)
+}
+
+/// Takes the yield held for a group's members so it can be paid out, one share
+/// per seat.
+///
+/// Rounding dust goes to the insurance pool for the group token, and the
+/// group's position no longer holds the yield afterwards.
+///
+/// # Returns
+/// The yield per seat, or `None` if no yield is held for the members
+pub fn take_member_yield(env: &Env, group: &Group) -> Option<i128> {
+    let mut position = storage::get_group_vault(env, group.id)?;
+    if position.member_yield <= 0 || group.seat_count == 0 {
+        return None;
+    }
+
+    let per_seat = position.member_yield / group.seat_count as i128;
+    let dust = position.member_yield - per_seat * group.seat_count as i128;
+    if dust > 0 {
+        crate::insurance::deposit_to_pool(env, &group.token_address, dust);
+    }
+    position.member_yield = 0;
+    storage::store_group_vault(env, group.id, &position);
+
+    Some(per_seat)
+}
+
+/// Pays the yield held for a group's members out to them, one share per seat.
+///
+/// Pays at most [`SETTLEMENT_BATCH_SIZE`] members per call, so that a full
+/// group fits in a transaction; `poke` pays the rest. Yield held after a
+/// payout has started waits for the next one. The first call takes the
+/// yield with [`take_member_yield`], and the last one emits the total paid.
+///
+/// # Returns
+/// How many members this call paid
+pub fn distribute_member_yield(env: &Env, group: &Group) -> Result<u32, AjoError> {
+    let stored = storage::get_member_yield_payout(env, group.id);
+    let resumed = stored.is_some();
+    let mut payout = match stored {
+        Some(payout) => payout,
+        None => match take_member_yield(env, group) {
+            Some(per_seat) => MemberYieldPayout {
+                per_seat,
+                next_member: 0,
+            },
+            None => return Ok(0),
+        },
+    };
+
+    let members = storage::get_group_members(env, group.id);
+    let start = payout.next_member;
+    let end = if payout.per_seat > 0 {
+        members.len().min(start + SETTLEMENT_BATCH_SIZE)
+    } else {
+        members.len()
+    };
+
+    if payout.per_seat > 0 {
+        let contract = env.current_contract_address();
+        for index in start..end {
+            let member = members.get_unchecked(index);
+            let amount = payout.per_seat * utils::seat_count(env, group, &member) as i128;
+            crate::token::transfer_token(env, &group.token_address, &contract, &member, amount)?;
+        }
+    }
+
+    payout.next_member = end;
+    if end < members.len() {
+        storage::store_member_yield_payout(env, group.id, &payout);
+    } else {
+        if resumed {
+            storage::remove_member_yield_payout(env, group.id);
+        }
+        events::emit_member_yield_paid(env, group.id, payout.per_seat * group.seat_count as i128);
+    }
+
+    Ok(end - start)
+}
+
+/// Returns `true` while a group's member yield is still being paid out.
+pub fn is_distributing_member_yield(env: &Env, group_id: u64) -> bool {
+    storage::get_member_yield_payout(env, group_id).is_some()
+}
+
+/// Builds a new vault configuration, keeping any yield still held for the members.
+pub fn configure(
+    env: &Env,
+    group_id: u64,
+    vault: Address,
+    yield_policy: YieldPolicy,
+) -> GroupVault {
+    let member_yield = storage::get_group_vault(env, group_id)
+        .map(|position| position.member_yield)
+        .unwrap_or(0);
+    GroupVault {
+        vault,
+        yield_policy,
+        principal: 0,
+        shares: 0,
+        member_yield,
+    }
+}
+
+/// Reads the value a vault holds for this contract.
+///
+/// # Errors
+/// * `TransferFailed` - If the vault does not answer `balance()`
+fn read_vault_balance(client: &VaultClient, contract: &Address) -> Result<i128, AjoError> {
+    match client.try_balance(contract) {
+        Ok(Ok(balance)) => Ok(balance),
+        _ => Err(AjoError::TransferFailed),
+    }
+}
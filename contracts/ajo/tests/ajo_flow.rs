@@ -1,10 +1,21 @@
 #![cfg(test)]
 
-use soroban_ajo::{AjoContract, AjoContractClient};
-use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env};
+use soroban_ajo::{AjoContract, AjoContractClient, CycleSchedule};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env,
+};
 
 /// Helper function to create a test environment and contract
-fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address, Address) {
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -16,12 +27,31 @@ fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address, Addre
     let member2 = Address::generate(&env);
     let member3 = Address::generate(&env);
 
-    (env, client, creator, member2, member3)
+    // Register the group token and fund the test addresses
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    // The contract only accepts tokens registered by the admin
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+    for addr in [&creator, &member2, &member3] {
+        fund(&env, &token, addr);
+    }
+
+    (env, client, creator, member2, member3, token)
+}
+
+/// Helper function to mint enough tokens for an address to cover any test scenario
+fn fund(env: &Env, token: &Address, address: &Address) {
+    StellarAssetClient::new(env, token).mint(address, &1_000_000_000_000_000);
 }
 
 #[test]
 fn test_create_group() {
-    let (env, client, creator, _, _) = setup_test_env();
+    let (_env, client, creator, _, _, token) = setup_test_env();
 
     let contribution = 100_000_000i128; // 10 XLM
     let cycle_duration = 604_800u64; // 1 week in seconds
@@ -29,7 +59,17 @@ fn test_create_group() {
     let grace_period = 86400u64; // 24 hours
     let penalty_rate = 5u32; // 5%
 
-    let group_id = client.create_group(&creator, &contribution, &cycle_duration, &max_members, &grace_period, &penalty_rate);
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &contribution,
+        &CycleSchedule::Fixed(cycle_duration),
+        &max_members,
+        &grace_period,
+        &penalty_rate,
+        &0u32,
+        &0u32,
+    );
 
     assert_eq!(group_id, 1);
 
@@ -40,73 +80,113 @@ fn test_create_group() {
     assert_eq!(group.contribution_amount, contribution);
     assert_eq!(group.cycle_duration, cycle_duration);
     assert_eq!(group.max_members, max_members);
-    assert_eq!(group.members.len(), 1);
+    assert_eq!(group.member_count, 1);
     assert_eq!(group.current_cycle, 1);
     assert_eq!(group.payout_index, 0);
-    assert_eq!(group.is_complete, false);
+    assert!(!group.is_complete);
     assert_eq!(group.grace_period, grace_period);
     assert_eq!(group.penalty_rate, penalty_rate);
 }
 
 #[test]
 fn test_join_group() {
-    let (env, client, creator, member2, member3) = setup_test_env();
+    let (_env, client, creator, member2, member3, token) = setup_test_env();
 
     // Create group
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &10u32, &86400u64, &5u32);
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &10u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
 
     // Member 2 joins
-    client.join_group(&member2, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
 
     // Member 3 joins
-    client.join_group(&member3, &group_id);
+    client.join_group(&member3, &group_id, &1u32);
 
     // Verify members
     let members = client.list_members(&group_id);
     assert_eq!(members.len(), 3);
 
     // Verify is_member checks
-    assert_eq!(client.is_member(&group_id, &creator), true);
-    assert_eq!(client.is_member(&group_id, &member2), true);
-    assert_eq!(client.is_member(&group_id, &member3), true);
+    assert!(client.is_member(&group_id, &creator));
+    assert!(client.is_member(&group_id, &member2));
+    assert!(client.is_member(&group_id, &member3));
 }
 
 #[test]
 #[should_panic]
 fn test_join_group_already_member() {
-    let (env, client, creator, _, _) = setup_test_env();
+    let (_env, client, creator, _, _, token) = setup_test_env();
 
     // Create group (creator is automatically a member)
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &10u32, &86400u64, &5u32);
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &10u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
 
     // Try to join again - should panic
-    client.join_group(&creator, &group_id);
+    client.join_group(&creator, &group_id, &1u32);
 }
 
 #[test]
 #[should_panic]
 fn test_join_group_full() {
-    let (env, client, creator, member2, _) = setup_test_env();
+    let (env, client, creator, member2, _, token) = setup_test_env();
 
     // Create group with max 2 members
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &2u32, &86400u64, &5u32);
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
 
     // Member 2 joins (now at max)
-    client.join_group(&member2, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
 
     // Try to add another member - should panic
     let member3 = Address::generate(&env);
-    client.join_group(&member3, &group_id);
+    client.join_group(&member3, &group_id, &1u32);
 }
 
 #[test]
 fn test_contribution_flow() {
-    let (env, client, creator, member2, member3) = setup_test_env();
+    let (_env, client, creator, member2, member3, token) = setup_test_env();
 
     // Create group with 3 members max
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    client.join_group(&member2, &group_id);
-    client.join_group(&member3, &group_id);
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&member2, &group_id, &1u32);
+    client.join_group(&member3, &group_id, &1u32);
 
     // All members contribute
     client.contribute(&creator, &group_id);
@@ -119,16 +199,26 @@ fn test_contribution_flow() {
 
     // All should have contributed
     for (_, has_paid) in status.iter() {
-        assert_eq!(has_paid, true);
+        assert!(has_paid);
     }
 }
 
 #[test]
 #[should_panic]
 fn test_double_contribution() {
-    let (env, client, creator, _, _) = setup_test_env();
-
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
+    let (_env, client, creator, _, _, token) = setup_test_env();
+
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
 
     // Contribute once
     client.contribute(&creator, &group_id);
@@ -140,11 +230,21 @@ fn test_double_contribution() {
 #[test]
 #[should_panic]
 fn test_payout_incomplete_contributions() {
-    let (env, client, creator, member2, _) = setup_test_env();
+    let (_env, client, creator, member2, _, token) = setup_test_env();
 
     // Create group with 2 members
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    client.join_group(&member2, &group_id);
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&member2, &group_id, &1u32);
 
     // Only creator contributes
     client.contribute(&creator, &group_id);
@@ -155,99 +255,129 @@ fn test_payout_incomplete_contributions() {
 
 #[test]
 fn test_payout_execution() {
-    let (env, client, creator, member2, member3) = setup_test_env();
+    let (env, client, creator, member2, member3, token) = setup_test_env();
 
     // Create group with 3 members
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    client.join_group(&member2, &group_id);
-    client.join_group(&member3, &group_id);
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&member2, &group_id, &1u32);
+    client.join_group(&member3, &group_id, &1u32);
 
     // Cycle 1: All contribute, creator receives payout
     client.contribute(&creator, &group_id);
     client.contribute(&member2, &group_id);
     client.contribute(&member3, &group_id);
-    
+
     // Advance time past grace period to allow payout
     env.ledger().with_mut(|li| {
         li.timestamp = li.timestamp + 604_800 + 86400 + 1; // Past cycle + grace period
     });
-    
+
     client.execute_payout(&group_id);
 
     // Verify state after first payout
     let group = client.get_group(&group_id);
     assert_eq!(group.current_cycle, 2);
     assert_eq!(group.payout_index, 1);
-    assert_eq!(group.is_complete, false);
+    assert!(!group.is_complete);
 }
 
 #[test]
 fn test_full_lifecycle() {
-    let (env, client, creator, member2, member3) = setup_test_env();
+    let (env, client, creator, member2, member3, token) = setup_test_env();
 
     // Create group with 3 members
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    client.join_group(&member2, &group_id);
-    client.join_group(&member3, &group_id);
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&member2, &group_id, &1u32);
+    client.join_group(&member3, &group_id, &1u32);
 
     // Verify not complete initially
-    assert_eq!(client.is_complete(&group_id), false);
+    assert!(!client.is_complete(&group_id));
 
     // Cycle 1: Creator receives payout
     client.contribute(&creator, &group_id);
     client.contribute(&member2, &group_id);
     client.contribute(&member3, &group_id);
-    
+
     // Advance time past grace period
     env.ledger().with_mut(|li| {
         li.timestamp = li.timestamp + 604_800 + 86400 + 1; // cycle + grace + 1
     });
-    
+
     client.execute_payout(&group_id);
-    assert_eq!(client.is_complete(&group_id), false);
+    assert!(!client.is_complete(&group_id));
 
     // Cycle 2: Member 2 receives payout
     client.contribute(&creator, &group_id);
     client.contribute(&member2, &group_id);
     client.contribute(&member3, &group_id);
-    
+
     // Advance time past grace period
     env.ledger().with_mut(|li| {
         li.timestamp = li.timestamp + 604_800 + 86400 + 1;
     });
-    
+
     client.execute_payout(&group_id);
-    assert_eq!(client.is_complete(&group_id), false);
+    assert!(!client.is_complete(&group_id));
 
     // Cycle 3: Member 3 receives payout (final)
     client.contribute(&creator, &group_id);
     client.contribute(&member2, &group_id);
     client.contribute(&member3, &group_id);
-    
+
     // Advance time past grace period
     env.ledger().with_mut(|li| {
         li.timestamp = li.timestamp + 604_800 + 86400 + 1;
     });
-    
+
     client.execute_payout(&group_id);
 
     // Group should now be complete
-    assert_eq!(client.is_complete(&group_id), true);
+    assert!(client.is_complete(&group_id));
 
     let group = client.get_group(&group_id);
-    assert_eq!(group.is_complete, true);
+    assert!(group.is_complete);
     assert_eq!(group.payout_index, 3);
 }
 
 #[test]
 #[should_panic]
 fn test_contribute_after_completion() {
-    let (env, client, creator, member2, member3) = setup_test_env();
+    let (_env, client, creator, member2, member3, token) = setup_test_env();
 
     // Create and complete a group
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    client.join_group(&member2, &group_id);
-    client.join_group(&member3, &group_id);
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&member2, &group_id, &1u32);
+    client.join_group(&member3, &group_id, &1u32);
 
     // Complete all cycles
     for _ in 0..3 {
@@ -264,45 +394,95 @@ fn test_contribute_after_completion() {
 #[test]
 #[should_panic]
 fn test_create_group_invalid_amount() {
-    let (env, client, creator, _, _) = setup_test_env();
+    let (_env, client, creator, _, _, token) = setup_test_env();
 
     // Try to create group with zero contribution
-    client.create_group(&creator, &0i128, &604_800u64, &10u32, &86400u64, &5u32);
+    client.create_group(
+        &creator,
+        &token,
+        &0i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &10u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
 }
 
 #[test]
 #[should_panic]
 fn test_create_group_negative_amount() {
-    let (env, client, creator, _, _) = setup_test_env();
+    let (_env, client, creator, _, _, token) = setup_test_env();
 
     // Try to create group with a negative contribution (should panic)
-    client.create_group(&creator, &(-100_000_000i128), &604_800u64, &10u32, &86400u64, &5u32);
+    client.create_group(
+        &creator,
+        &token,
+        &(-100_000_000i128),
+        &CycleSchedule::Fixed(604_800u64),
+        &10u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
 }
 
 #[test]
 #[should_panic]
 fn test_create_group_invalid_duration() {
-    let (env, client, creator, _, _) = setup_test_env();
+    let (_env, client, creator, _, _, token) = setup_test_env();
 
     // Try to create group with zero duration
-    client.create_group(&creator, &100_000_000i128, &0u64, &10u32, &86400u64, &5u32);
+    client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(0u64),
+        &10u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
 }
 
 #[test]
 #[should_panic]
 fn test_create_group_invalid_max_members() {
-    let (env, client, creator, _, _) = setup_test_env();
+    let (_env, client, creator, _, _, token) = setup_test_env();
 
     // Try to create group with only 1 member max
-    client.create_group(&creator, &100_000_000i128, &604_800u64, &1u32, &86400u64, &5u32);
+    client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &1u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
 }
 
 #[test]
 #[should_panic]
 fn test_contribute_not_member() {
-    let (env, client, creator, _, _) = setup_test_env();
-
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &10u32, &86400u64, &5u32);
+    let (env, client, creator, _, _, token) = setup_test_env();
+
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &10u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
 
     // Try to contribute as non-member
     let non_member = Address::generate(&env);
@@ -311,13 +491,33 @@ fn test_contribute_not_member() {
 
 #[test]
 fn test_multiple_groups() {
-    let (env, client, creator, member2, _) = setup_test_env();
+    let (_env, client, creator, member2, _, token) = setup_test_env();
 
     // Create first group
-    let group_id1 = client.create_group(&creator, &100_000_000i128, &604_800u64, &5u32, &86400u64, &5u32);
+    let group_id1 = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &5u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
 
     // Create second group
-    let group_id2 = client.create_group(&member2, &200_000_000i128, &1_209_600u64, &3u32, &86400u64, &5u32);
+    let group_id2 = client.create_group(
+        &member2,
+        &token,
+        &200_000_000i128,
+        &CycleSchedule::Fixed(1_209_600u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
 
     // Verify both groups exist independently
     assert_eq!(group_id1, 1);
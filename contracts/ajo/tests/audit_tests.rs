@@ -0,0 +1,183 @@
+#![cfg(test)]
+
+//! Tests for group audits and the admin audit sweep
+
+use soroban_ajo::{
+    AjoContract, AjoContractClient, AjoError, AuditIssue, CycleSchedule, GroupState,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    vec, Address, Env,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const GRACE: u64 = 86400;
+
+/// Helper function to create a contract, a registered token and three funded members
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    for addr in [&alice, &bob, &carol] {
+        StellarAssetClient::new(&env, &token).mint(addr, &1_000_000_000_000);
+    }
+
+    (env, client, token, alice, bob, carol)
+}
+
+/// Helper function to create a group of alice, bob and carol with one seat each
+fn create_group(
+    client: &AjoContractClient,
+    token: &Address,
+    alice: &Address,
+    bob: &Address,
+    carol: &Address,
+) -> u64 {
+    let group_id = client.create_group(
+        alice,
+        token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(CYCLE),
+        &3u32,
+        &GRACE,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(bob, &group_id, &1u32);
+    client.join_group(carol, &group_id, &1u32);
+    group_id
+}
+
+#[test]
+fn test_audit_healthy_group() {
+    let (env, client, token, alice, bob, carol) = setup_test_env();
+    let group_id = create_group(&client, &token, &alice, &bob, &carol);
+
+    for member in [&alice, &bob, &carol] {
+        client.contribute(member, &group_id);
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + GRACE + 1;
+    });
+    client.execute_payout(&group_id);
+
+    // Bob pays late into cycle two, which ends a cycle after the first
+    client.contribute(&alice, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE - GRACE + 3600;
+    });
+    client.contribute(&bob, &group_id);
+
+    let audit = client.audit_group(&group_id);
+    assert_eq!(audit.group_id, group_id);
+    assert_eq!(audit.state, GroupState::Active);
+    assert_eq!(audit.cycles.len(), 2);
+    let first = audit.cycles.get(0).unwrap();
+    assert_eq!(first.expected_count, 3);
+    assert_eq!(first.received_count, 3);
+    assert_eq!(first.expected_amount, CONTRIBUTION * 3);
+    assert_eq!(first.received_amount, CONTRIBUTION * 3);
+    let second = audit.cycles.get(1).unwrap();
+    assert_eq!(second.received_count, 2);
+    assert_eq!(second.received_amount, CONTRIBUTION * 2);
+
+    assert_eq!(audit.seat_count, 3);
+    assert_eq!(audit.payout_index, 1);
+    assert_eq!(audit.payouts_recorded, 1);
+    assert_eq!(audit.escrow_held, CONTRIBUTION * 2 + CONTRIBUTION * 5 / 100);
+    assert_eq!(audit.vault_principal, 0);
+    assert_eq!(audit.token_balance, audit.escrow_held);
+    assert_eq!(audit.pending_contributors, vec![&env, carol.clone()]);
+    assert!(audit.issues.is_empty());
+
+    assert_eq!(
+        client.try_audit_group(&99),
+        Err(Ok(AjoError::GroupNotFound))
+    );
+}
+
+#[test]
+fn test_audit_flags_stuck_and_underfunded_groups() {
+    let (env, client, token, alice, bob, carol) = setup_test_env();
+    let group_id = create_group(&client, &token, &alice, &bob, &carol);
+    client.contribute(&alice, &group_id);
+    client.contribute(&bob, &group_id);
+
+    // Carol never pays, so the payout can never run
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + GRACE + 1;
+    });
+    let audit = client.audit_group(&group_id);
+    assert_eq!(audit.issues, vec![&env, AuditIssue::Stuck]);
+    assert_eq!(audit.pending_contributors, vec![&env, carol.clone()]);
+
+    // A frozen group is waiting on purpose
+    client.freeze_group(&alice, &group_id);
+    assert!(client.audit_group(&group_id).issues.is_empty());
+
+    // Credit the contract does not hold leaves the group underfunded
+    env.as_contract(&client.address, || {
+        let key = (symbol_short!("CREDITS"), group_id);
+        env.storage().persistent().set(&key, &(CONTRIBUTION * 10));
+    });
+    let audit = client.audit_group(&group_id);
+    assert_eq!(audit.escrow_held, CONTRIBUTION * 12);
+    assert_eq!(audit.issues, vec![&env, AuditIssue::Underfunded]);
+}
+
+#[test]
+fn test_audit_all_pages_through_groups() {
+    let (env, client, token, alice, bob, carol) = setup_test_env();
+    let mut group_ids = std::vec::Vec::new();
+    for _ in 0..12 {
+        group_ids.push(create_group(&client, &token, &alice, &bob, &carol));
+    }
+    client.cancel_group(&alice, &group_ids[1]);
+
+    let ids = |cursor: u64, limit: u32| -> std::vec::Vec<u64> {
+        client
+            .audit_all(&cursor, &limit)
+            .iter()
+            .map(|audit| audit.group_id)
+            .collect()
+    };
+    assert_eq!(ids(0, 3), group_ids[0..3]);
+    assert_eq!(ids(group_ids[2] + 1, 3), group_ids[3..6]);
+    // Pages stop at the read budget: eight reads for each one-cycle group
+    assert_eq!(ids(1, 50), group_ids[0..3]);
+    assert_eq!(ids(group_ids[11], 10), group_ids[11..]);
+    assert!(ids(group_ids[11] + 1, 10).is_empty());
+
+    let audits = client.audit_all(&group_ids[1], &1);
+    assert_eq!(audits.get(0).unwrap().state, GroupState::Cancelled);
+    assert!(audits.get(0).unwrap().issues.is_empty());
+
+    env.set_auths(&[]);
+    assert!(client.try_audit_all(&0, &10).is_err());
+}
@@ -0,0 +1,269 @@
+#![cfg(test)]
+
+//! Tests for allowance-based auto-debit contributions
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, CollectionOutcome, CycleSchedule};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    Address, Env,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+
+/// Helper function to create a test environment with a three-member group
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    TokenClient<'static>,
+    u64,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let member3 = Address::generate(&env);
+
+    // Register the group token and fund the test addresses
+    let token_admin = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    // The contract only accepts tokens registered by the admin
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.add_supported_token(&token_id, &1i128, &i128::MAX);
+    for addr in [&creator, &member2, &member3] {
+        StellarAssetClient::new(&env, &token_id).mint(addr, &1_000_000_000_000);
+    }
+    let token = TokenClient::new(&env, &token_id);
+
+    let group_id = client.create_group(
+        &creator,
+        &token_id,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &10u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&member2, &group_id, &1u32);
+    client.join_group(&member3, &group_id, &1u32);
+
+    (env, client, token, group_id, creator, member2, member3)
+}
+
+/// Helper function to opt a member in and approve the contract for `amount`
+fn opt_in(
+    env: &Env,
+    client: &AjoContractClient,
+    token: &TokenClient,
+    group_id: u64,
+    member: &Address,
+    amount: i128,
+) {
+    client.enable_auto_debit(member, &group_id);
+    let expiration = env.ledger().sequence() + 1_000;
+    token.approve(member, &client.address, &amount, &expiration);
+}
+
+#[test]
+fn test_collect_due_pulls_from_opted_in_members() {
+    let (env, client, token, group_id, creator, member2, member3) = setup_test_env();
+
+    opt_in(&env, &client, &token, group_id, &creator, CONTRIBUTION * 10);
+    opt_in(&env, &client, &token, group_id, &member2, CONTRIBUTION * 10);
+    let before = token.balance(&creator);
+
+    let results = client.collect_due(&group_id);
+
+    assert_eq!(results.len(), 2);
+    for result in results.iter() {
+        assert_eq!(result.outcome, CollectionOutcome::Collected(CONTRIBUTION));
+    }
+    assert_eq!(before - token.balance(&creator), CONTRIBUTION);
+    assert_eq!(token.balance(&client.address), CONTRIBUTION * 2);
+    assert_eq!(token.allowance(&member2, &client.address), CONTRIBUTION * 9);
+
+    // Member 3 never opted in and still owes their contribution
+    let status = client.get_contribution_status(&group_id, &1u32);
+    assert!(status.get(0).unwrap().1);
+    assert!(status.get(1).unwrap().1);
+    assert!(!status.get(2).unwrap().1);
+    client.contribute(&member3, &group_id);
+}
+
+#[test]
+fn test_collect_due_skips_members_who_already_paid() {
+    let (env, client, token, group_id, creator, _member2, _) = setup_test_env();
+
+    opt_in(&env, &client, &token, group_id, &creator, CONTRIBUTION * 10);
+    client.contribute(&creator, &group_id);
+
+    let results = client.collect_due(&group_id);
+    assert_eq!(results.len(), 0);
+}
+
+#[test]
+fn test_collect_due_reports_insufficient_allowance_per_member() {
+    let (env, client, token, group_id, creator, member2, _) = setup_test_env();
+
+    opt_in(&env, &client, &token, group_id, &creator, CONTRIBUTION - 1);
+    opt_in(&env, &client, &token, group_id, &member2, CONTRIBUTION);
+
+    let results = client.collect_due(&group_id);
+
+    assert_eq!(results.len(), 2);
+    let first = results.get(0).unwrap();
+    assert_eq!(first.member, creator);
+    assert_eq!(
+        first.outcome,
+        CollectionOutcome::Failed(AjoError::InsufficientAllowance as u32)
+    );
+    let second = results.get(1).unwrap();
+    assert_eq!(second.member, member2);
+    assert_eq!(second.outcome, CollectionOutcome::Collected(CONTRIBUTION));
+}
+
+#[test]
+fn test_collect_due_continues_in_batches() {
+    let (env, client, token, _, creator, member2, member3) = setup_test_env();
+    let member4 = Address::generate(&env);
+    let member5 = Address::generate(&env);
+    for addr in [&member4, &member5] {
+        StellarAssetClient::new(&env, &token.address).mint(addr, &1_000_000_000_000);
+    }
+    let group_id = client.create_group(
+        &creator,
+        &token.address,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(604_800u64),
+        &5u32,
+        &86400u64,
+        &10u32,
+        &0u32,
+        &0u32,
+    );
+    for member in [&member2, &member3, &member4, &member5] {
+        client.join_group(member, &group_id, &1u32);
+    }
+
+    opt_in(&env, &client, &token, group_id, &creator, CONTRIBUTION - 1);
+    for member in [&member2, &member3, &member4, &member5] {
+        opt_in(&env, &client, &token, group_id, member, CONTRIBUTION);
+    }
+
+    // Two members per call, continuing from where the last call stopped
+    let batches: std::vec::Vec<std::vec::Vec<Address>> = (0..3)
+        .map(|_| {
+            client
+                .collect_due(&group_id)
+                .iter()
+                .map(|result| result.member)
+                .collect()
+        })
+        .collect();
+    assert_eq!(
+        batches,
+        [
+            vec![creator.clone(), member2],
+            vec![member3, member4],
+            vec![member5],
+        ]
+    );
+
+    // Past the last member the next call starts again and retries the failure
+    let expiration = env.ledger().sequence() + 1_000;
+    token.approve(&creator, &client.address, &CONTRIBUTION, &expiration);
+    let results = client.collect_due(&group_id);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results.get(0).unwrap().member, creator);
+    assert_eq!(
+        results.get(0).unwrap().outcome,
+        CollectionOutcome::Collected(CONTRIBUTION)
+    );
+    assert_eq!(client.get_group_status(&group_id).contributions_received, 5);
+}
+
+#[test]
+fn test_collect_due_honours_allowance_expiration() {
+    let (env, client, token, group_id, creator, _, _) = setup_test_env();
+
+    client.enable_auto_debit(&creator, &group_id);
+    let expiration = env.ledger().sequence() + 10;
+    token.approve(&creator, &client.address, &(CONTRIBUTION * 10), &expiration);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number += 20;
+    });
+
+    let results = client.collect_due(&group_id);
+    assert_eq!(
+        results.get(0).unwrap().outcome,
+        CollectionOutcome::Failed(AjoError::InsufficientAllowance as u32)
+    );
+}
+
+#[test]
+fn test_collect_due_applies_late_penalty() {
+    let (env, client, token, group_id, creator, _, _) = setup_test_env();
+
+    opt_in(&env, &client, &token, group_id, &creator, CONTRIBUTION * 10);
+
+    // Move into the grace period
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 1;
+    });
+
+    let results = client.collect_due(&group_id);
+    let penalty = CONTRIBUTION / 10;
+    assert_eq!(
+        results.get(0).unwrap().outcome,
+        CollectionOutcome::Collected(CONTRIBUTION + penalty)
+    );
+    assert_eq!(client.get_cycle_penalty_pool(&group_id, &1u32), penalty);
+}
+
+#[test]
+fn test_disable_auto_debit_stops_collection() {
+    let (env, client, token, group_id, creator, _, _) = setup_test_env();
+
+    opt_in(&env, &client, &token, group_id, &creator, CONTRIBUTION * 10);
+    assert!(client.is_auto_debit_enabled(&group_id, &creator));
+
+    client.disable_auto_debit(&creator, &group_id);
+    assert!(!client.is_auto_debit_enabled(&group_id, &creator));
+
+    let results = client.collect_due(&group_id);
+    assert_eq!(results.len(), 0);
+}
+
+#[test]
+fn test_enable_auto_debit_requires_membership() {
+    let (env, client, _token, group_id, _, _, _) = setup_test_env();
+
+    let outsider = Address::generate(&env);
+    let result = client.try_enable_auto_debit(&outsider, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::NotMember)));
+}
+
+#[test]
+fn test_collect_due_on_frozen_group() {
+    let (env, client, token, group_id, creator, _, _) = setup_test_env();
+
+    opt_in(&env, &client, &token, group_id, &creator, CONTRIBUTION * 10);
+    client.freeze_group(&creator, &group_id);
+
+    let result = client.try_collect_due(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::GroupFrozen)));
+}
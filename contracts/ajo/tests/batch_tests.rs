@@ -0,0 +1,207 @@
+#![cfg(test)]
+
+//! Tests for batch contributions, claims and status queries across groups
+
+use soroban_ajo::{
+    AjoContract, AjoContractClient, AjoError, BatchOutcome, BatchResult, CycleSchedule,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    vec, Address, Env, Vec,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+
+/// Helper function to create a contract, a registered token and two funded members
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    TokenClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token_admin = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_supported_token(&token_id, &1i128, &i128::MAX);
+    let token = TokenClient::new(&env, &token_id);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    for addr in [&alice, &bob] {
+        StellarAssetClient::new(&env, &token_id).mint(addr, &1_000_000_000_000);
+    }
+
+    (env, client, token, alice, bob)
+}
+
+/// Helper function to create a two-member group; `first` receives the first payout
+fn create_group(
+    client: &AjoContractClient,
+    token: &TokenClient,
+    first: &Address,
+    second: &Address,
+) -> u64 {
+    let group_id = client.create_group(
+        first,
+        &token.address,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(second, &group_id, &1u32);
+    group_id
+}
+
+/// Helper function to move past the current cycle's grace period
+fn advance_past_grace(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 86400 + 1;
+    });
+}
+
+fn result(group_id: u64, outcome: BatchOutcome) -> BatchResult {
+    BatchResult { group_id, outcome }
+}
+
+#[test]
+fn test_contribute_many_reports_each_group() {
+    let (env, client, token, alice, bob) = setup_test_env();
+    let first = create_group(&client, &token, &alice, &bob);
+    let second = create_group(&client, &token, &bob, &alice);
+    client.contribute(&alice, &second);
+    let balance_before = token.balance(&alice);
+
+    let results = client.contribute_many(&alice, &vec![&env, first, second, 99]);
+    assert_eq!(
+        results,
+        vec![
+            &env,
+            result(first, BatchOutcome::Succeeded),
+            result(
+                second,
+                BatchOutcome::Failed(AjoError::AlreadyContributed as u32)
+            ),
+            result(99, BatchOutcome::Failed(AjoError::GroupNotFound as u32)),
+        ]
+    );
+
+    // One authorization covers the whole batch
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, alice);
+
+    assert_eq!(token.balance(&alice), balance_before - CONTRIBUTION);
+    assert!(client.get_contribution_progress(&first, &alice).contributed);
+}
+
+#[test]
+fn test_claim_many_pays_due_seats_only() {
+    let (env, client, token, alice, bob) = setup_test_env();
+    let due = create_group(&client, &token, &alice, &bob);
+    let also_due = create_group(&client, &token, &alice, &bob);
+    let not_her_turn = create_group(&client, &token, &bob, &alice);
+    let unpaid = create_group(&client, &token, &alice, &bob);
+    for group_id in [due, also_due, not_her_turn] {
+        client.contribute(&alice, &group_id);
+        client.contribute(&bob, &group_id);
+    }
+    client.contribute(&alice, &unpaid);
+    advance_past_grace(&env);
+    let balance_before = token.balance(&alice);
+
+    let results = client.claim_many(&alice, &vec![&env, due, not_her_turn]);
+    assert_eq!(
+        results,
+        vec![
+            &env,
+            result(due, BatchOutcome::Succeeded),
+            result(
+                not_her_turn,
+                BatchOutcome::Failed(AjoError::Unauthorized as u32)
+            ),
+        ]
+    );
+    let results = client.claim_many(&alice, &vec![&env, also_due, unpaid]);
+    assert_eq!(
+        results,
+        vec![
+            &env,
+            result(also_due, BatchOutcome::Succeeded),
+            result(
+                unpaid,
+                BatchOutcome::Failed(AjoError::IncompleteContributions as u32)
+            ),
+        ]
+    );
+
+    assert_eq!(token.balance(&alice), balance_before + CONTRIBUTION * 4);
+    assert_eq!(client.get_group(&due).payout_index, 1);
+    assert_eq!(client.get_group(&not_her_turn).payout_index, 0);
+
+    // Claiming again finds nothing left for her this cycle
+    let results = client.claim_many(&alice, &vec![&env, due]);
+    assert_eq!(
+        results.get(0).unwrap().outcome,
+        BatchOutcome::Failed(AjoError::IncompleteContributions as u32)
+    );
+}
+
+#[test]
+fn test_get_group_statuses_matches_single_queries() {
+    let (env, client, token, alice, bob) = setup_test_env();
+    let first = create_group(&client, &token, &alice, &bob);
+    let second = create_group(&client, &token, &bob, &alice);
+    client.contribute(&alice, &second);
+
+    let statuses = client.get_group_statuses(&vec![&env, second, first]);
+    assert_eq!(statuses.len(), 2);
+    assert_eq!(statuses.get(0).unwrap(), client.get_group_status(&second));
+    assert_eq!(statuses.get(1).unwrap(), client.get_group_status(&first));
+
+    let result = client.try_get_group_statuses(&vec![&env, first, 99]);
+    assert_eq!(result, Err(Ok(AjoError::GroupNotFound)));
+}
+
+#[test]
+fn test_batches_are_bounded() {
+    let (env, client, token, alice, bob) = setup_test_env();
+    let group_id = create_group(&client, &token, &alice, &bob);
+
+    // Each batch call has its own limit, set by what one group costs it
+    let repeat = |count: u32| {
+        let mut group_ids = Vec::new(&env);
+        for _ in 0..count {
+            group_ids.push_back(group_id);
+        }
+        group_ids
+    };
+    let result = client.try_contribute_many(&alice, &repeat(4));
+    assert_eq!(result, Err(Ok(AjoError::LimitExceeded)));
+    assert_eq!(client.contribute_many(&alice, &repeat(3)).len(), 3);
+    let result = client.try_claim_many(&alice, &repeat(3));
+    assert_eq!(result, Err(Ok(AjoError::LimitExceeded)));
+    assert_eq!(client.claim_many(&alice, &repeat(2)).len(), 2);
+
+    let mut too_many = repeat(11);
+    let result = client.try_get_group_statuses(&too_many);
+    assert_eq!(result, Err(Ok(AjoError::LimitExceeded)));
+
+    too_many.pop_back();
+    assert_eq!(client.get_group_statuses(&too_many).len(), 10);
+}
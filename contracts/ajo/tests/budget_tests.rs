@@ -0,0 +1,829 @@
+#![cfg(test)]
+
+//! Resource budget tests for full-size groups
+//!
+//! Each measured call gets a fresh default budget and an empty ledger
+//! footprint, as it would on-chain. Payouts and refunds are measured at every
+//! group size up to the largest the contract allows, and must stay within the
+//! network's per-transaction limits: CPU instructions, memory, and ledger
+//! entries read and written. Queries, which are simulated rather than
+//! submitted, are held to CPU thresholds.
+//!
+//! Contributions are kept as one bitmap per cycle, so the calls that check
+//! every member read one entry per cycle instead of one per member per cycle.
+//! The contribution matrix also reads the cycle's late and insurance-covered
+//! bitmaps, and is paged by cycles and by members.
+//! Refunds, rolling prepaid credit into a new cycle, paying out member yield
+//! and collecting auto-debits touch a few entries per member, so they are done
+//! a batch of members at a time and continued by `process_refunds`,
+//! `collect_due` or `poke`.
+//! A member's obligations are read a page of groups at a time, so a member of
+//! many groups can still list them.
+//! Calls that act on several groups take only as many as fit in one
+//! transaction at their measured cost per group, and `poke_many` stops after
+//! the first group it carried out a transition for.
+//!
+//! The CPU thresholds at the largest group size are set low enough that going
+//! back to one entry per member would fail them:
+//! - `get_group_status`: 1M instructions, reading one bitmap for the cycle
+//! - `contribute`: 4M, so the last member's contribution costs no more than
+//!   the first's
+//! - `execute_payout`: 8M, checking every contribution through the bitmap
+//! - `audit_group`: 10M over six cycles, reading the member list once and one
+//!   bitmap per cycle
+//!
+//! Each cycle keeps a running total of what it received, which an audit and
+//! parking funds in a vault read instead of every member's seats. An audit
+//! reports only the most recent cycles and also reads a running total of the
+//! credit members hold, so it reads a fixed number of entries per group.
+//! `audit_all` stops once its estimated reads reach a budget and is continued
+//! from the next group ID.
+
+use soroban_ajo::{
+    AjoContract, AjoContractClient, BatchOutcome, CycleSchedule, PokeAction, VaultInterface,
+    YieldPolicy,
+};
+use soroban_env_host::storage::AccessType;
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    vec, Address, Env,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const GRACE: u64 = 86_400;
+const MINTED: i128 = 1_000_000_000_000_000;
+
+/// Largest group the contract allows
+const MAX_MEMBERS: u32 = 100;
+
+/// Group sizes the payout and refund entry points are measured at
+const GROUP_SIZES: [u32; 4] = [2, 10, 50, MAX_MEMBERS];
+
+/// Per-transaction network limits
+const TX_CPU_INSTRUCTIONS: u64 = 100_000_000;
+const TX_MEMORY_BYTES: u64 = 40 * 1024 * 1024;
+const TX_READ_ENTRIES: u32 = 40;
+const TX_WRITE_ENTRIES: u32 = 25;
+
+#[contracttype]
+enum DataKey {
+    Token,
+    Holding(Address),
+}
+
+/// A vault that holds deposits as-is and credits yield when told to
+#[contract]
+pub struct MockVault;
+
+#[contractimpl]
+impl MockVault {
+    pub fn init(env: Env, token: Address) {
+        env.storage().instance().set(&DataKey::Token, &token);
+    }
+
+    /// Credit `amount` of yield to `id` (the tokens must be minted to the vault)
+    pub fn accrue(env: Env, id: Address, amount: i128) {
+        let holding = Self::balance(env.clone(), id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Holding(id), &(holding + amount));
+    }
+}
+
+#[contractimpl]
+impl VaultInterface for MockVault {
+    fn deposit(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        let token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        TokenClient::new(&env, &token).transfer(&from, &env.current_contract_address(), &amount);
+        Self::accrue(env, from, amount);
+    }
+
+    fn withdraw(env: Env, to: Address, amount: i128) {
+        to.require_auth();
+        let holding = Self::balance(env.clone(), to.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Holding(to.clone()), &(holding - amount));
+        let token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        TokenClient::new(&env, &token).transfer(&env.current_contract_address(), &to, &amount);
+    }
+
+    fn balance(env: Env, id: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Holding(id))
+            .unwrap_or(0)
+    }
+}
+
+/// Resources used by one measured call
+#[derive(Debug)]
+struct Usage {
+    cpu: u64,
+    memory: u64,
+    /// Ledger entries in the footprint, read-only and read-write
+    entries_read: u32,
+    /// Ledger entries in the footprint that were written
+    entries_written: u32,
+}
+
+impl Usage {
+    /// Fails the test if the call would not fit in a transaction
+    fn assert_fits(&self, call: &str, size: u32) {
+        assert!(
+            self.cpu <= TX_CPU_INSTRUCTIONS
+                && self.memory <= TX_MEMORY_BYTES
+                && self.entries_read <= TX_READ_ENTRIES
+                && self.entries_written <= TX_WRITE_ENTRIES,
+            "{call} with {size} members exceeds the transaction limits: {self:?}"
+        );
+    }
+}
+
+/// A group filled with single-seat members, and the accounts around it
+struct FullGroup {
+    env: Env,
+    client: AjoContractClient<'static>,
+    group_id: u64,
+    admin: Address,
+    token: Address,
+    members: std::vec::Vec<Address>,
+}
+
+/// Helper function to create a group filled with `size` single-seat members
+fn setup_full_group(size: u32) -> FullGroup {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // Setup makes many calls; only the measured calls use the default budget
+    env.budget().reset_unlimited();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+
+    let members: std::vec::Vec<Address> = (0..size).map(|_| Address::generate(&env)).collect();
+    for member in &members {
+        StellarAssetClient::new(&env, &token).mint(member, &MINTED);
+    }
+
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(CYCLE),
+        &size,
+        &GRACE,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    for member in &members[1..] {
+        client.join_group(member, &group_id, &1u32);
+    }
+
+    FullGroup {
+        env,
+        client,
+        group_id,
+        admin,
+        token,
+        members,
+    }
+}
+
+/// Helper function to create `count` groups of the same `size` members,
+/// each paying out to the first member first
+fn setup_full_groups(size: u32, count: u32) -> (FullGroup, soroban_sdk::Vec<u64>) {
+    let group = setup_full_group(size);
+    let mut group_ids = vec![&group.env, group.group_id];
+    for _ in 1..count {
+        let group_id = group.client.create_group(
+            &group.members[0],
+            &group.token,
+            &CONTRIBUTION,
+            &CycleSchedule::Fixed(CYCLE),
+            &size,
+            &GRACE,
+            &5u32,
+            &0u32,
+            &0u32,
+        );
+        for member in &group.members[1..] {
+            group.client.join_group(member, &group_id, &1u32);
+        }
+        group_ids.push_back(group_id);
+    }
+    (group, group_ids)
+}
+
+/// Helper function to have every member contribute to the current cycle
+fn contribute_all(client: &AjoContractClient, group_id: u64, members: &[Address]) {
+    for member in members {
+        client.contribute(member, &group_id);
+    }
+}
+
+/// Helper function to move past the current cycle's grace period
+fn advance_past_grace(env: &Env) {
+    env.ledger()
+        .with_mut(|li| li.timestamp += CYCLE + GRACE + 1);
+}
+
+/// Helper function to run `f` on a fresh default budget and empty footprint
+/// and return what it used
+fn measure(env: &Env, f: impl FnOnce()) -> Usage {
+    env.host()
+        .with_mut_storage(|storage| {
+            storage.footprint = Default::default();
+            Ok(())
+        })
+        .unwrap();
+    env.budget().reset_default();
+    f();
+    let cpu = env.budget().cpu_instruction_cost();
+    let memory = env.budget().memory_bytes_cost();
+    env.budget().reset_unlimited();
+
+    let budget = env.host().budget_cloned();
+    let (entries_read, entries_written) = env
+        .host()
+        .with_mut_storage(|storage| {
+            let mut read = 0;
+            let mut written = 0;
+            for (_, access) in storage.footprint.0.iter(&budget)? {
+                read += 1;
+                if *access == AccessType::ReadWrite {
+                    written += 1;
+                }
+            }
+            Ok((read, written))
+        })
+        .unwrap();
+
+    Usage {
+        cpu,
+        memory,
+        entries_read,
+        entries_written,
+    }
+}
+
+/// Helper function to finish a group's refunds with `process_refunds`,
+/// checking that every call fits in a transaction
+fn finish_refunds(group: &FullGroup, call: &str, size: u32) {
+    let mut calls = 0;
+    while group.client.get_refund_progress(&group.group_id).is_some() {
+        let usage = measure(&group.env, || {
+            group.client.process_refunds(&group.group_id);
+        });
+        usage.assert_fits(&format!("process_refunds after {call}"), size);
+        calls += 1;
+    }
+    assert!(
+        calls <= size,
+        "{call} took {calls} extra calls for {size} members"
+    );
+}
+
+/// Helper function to opt every member in to auto-debit with an allowance for
+/// one contribution
+fn enable_auto_debits(group: &FullGroup) {
+    let token_client = TokenClient::new(&group.env, &group.token);
+    let expiration = group.env.ledger().sequence() + 1_000;
+    for member in &group.members {
+        group.client.enable_auto_debit(member, &group.group_id);
+        token_client.approve(member, &group.client.address, &CONTRIBUTION, &expiration);
+    }
+}
+
+/// Helper function to check that every member got back what they paid in
+fn assert_all_refunded(group: &FullGroup) {
+    let token_client = TokenClient::new(&group.env, &group.token);
+    for member in &group.members {
+        assert_eq!(token_client.balance(member), MINTED);
+    }
+}
+
+#[test]
+fn test_group_status_budget_at_max_size() {
+    let group = setup_full_group(MAX_MEMBERS);
+    contribute_all(&group.client, group.group_id, &group.members[..50]);
+
+    let usage = measure(&group.env, || {
+        let status = group.client.get_group_status(&group.group_id);
+        assert_eq!(status.contributions_received, 50);
+        assert_eq!(status.pending_contributors.len(), 50);
+    });
+    assert!(usage.cpu < 1_000_000, "get_group_status used {usage:?}");
+}
+
+#[test]
+fn test_contribute_and_payout_budget_at_max_size() {
+    let group = setup_full_group(MAX_MEMBERS);
+    let (last, others) = group.members.split_last().unwrap();
+    contribute_all(&group.client, group.group_id, others);
+
+    // The last contribution costs the same as the first
+    let usage = measure(&group.env, || {
+        group.client.contribute(last, &group.group_id)
+    });
+    assert!(usage.cpu < 4_000_000, "contribute used {usage:?}");
+
+    advance_past_grace(&group.env);
+    let usage = measure(&group.env, || group.client.execute_payout(&group.group_id));
+    assert!(usage.cpu < 8_000_000, "execute_payout used {usage:?}");
+    assert_eq!(group.client.get_group(&group.group_id).current_cycle, 2);
+}
+
+#[test]
+fn test_audit_budget_grows_per_cycle_not_per_member() {
+    let group = setup_full_group(MAX_MEMBERS);
+    for _ in 0..5 {
+        contribute_all(&group.client, group.group_id, &group.members);
+        advance_past_grace(&group.env);
+        group.client.execute_payout(&group.group_id);
+    }
+
+    let usage = measure(&group.env, || {
+        let audit = group.client.audit_group(&group.group_id);
+        assert_eq!(audit.cycles.len(), 6);
+        assert!(audit.issues.is_empty());
+    });
+    assert!(usage.cpu < 10_000_000, "audit_group used {usage:?}");
+}
+
+#[test]
+fn test_execute_payout_fits_at_every_size() {
+    for size in GROUP_SIZES {
+        let group = setup_full_group(size);
+        contribute_all(&group.client, group.group_id, &group.members);
+        advance_past_grace(&group.env);
+
+        let usage = measure(&group.env, || group.client.execute_payout(&group.group_id));
+        usage.assert_fits("execute_payout", size);
+        assert_eq!(group.client.get_group(&group.group_id).current_cycle, 2);
+    }
+}
+
+#[test]
+fn test_credit_roll_over_fits_at_every_size() {
+    for size in GROUP_SIZES {
+        let group = setup_full_group(size);
+
+        // Every member pays this cycle and prepays the next
+        for member in &group.members {
+            group
+                .client
+                .contribute_amount(member, &group.group_id, &(CONTRIBUTION * 2));
+        }
+        advance_past_grace(&group.env);
+
+        let usage = measure(&group.env, || group.client.execute_payout(&group.group_id));
+        usage.assert_fits("execute_payout with prepaid members", size);
+
+        // Pokes roll over the rest of the credit, a batch at a time
+        let mut pokes = 0;
+        while group
+            .client
+            .get_group_status(&group.group_id)
+            .contributions_received
+            < size
+        {
+            let mut actions = vec![&group.env];
+            let usage = measure(&group.env, || {
+                actions = group.client.poke(&group.admin, &group.group_id).actions;
+            });
+            usage.assert_fits("poke rolling over credit", size);
+            assert!(matches!(
+                actions.first(),
+                Some(PokeAction::CreditsApplied(_))
+            ));
+            pokes += 1;
+        }
+        assert!(pokes < size);
+        assert_eq!(group.client.get_group(&group.group_id).current_cycle, 2);
+        for member in &group.members {
+            assert_eq!(group.client.get_member_credit(&group.group_id, member), 0);
+        }
+    }
+}
+
+#[test]
+fn test_collect_due_fits_at_every_size() {
+    for size in GROUP_SIZES {
+        let group = setup_full_group(size);
+        enable_auto_debits(&group);
+
+        let mut calls = 0;
+        let mut collected = 0;
+        while collected < size {
+            let mut results = vec![&group.env];
+            let usage = measure(&group.env, || {
+                results = group.client.collect_due(&group.group_id);
+            });
+            usage.assert_fits("collect_due", size);
+            assert!(!results.is_empty());
+            collected += results.len();
+            calls += 1;
+        }
+        assert_eq!(calls, size.div_ceil(2));
+        assert_eq!(
+            group
+                .client
+                .get_group_status(&group.group_id)
+                .contributions_received,
+            size
+        );
+        assert!(group.client.collect_due(&group.group_id).is_empty());
+    }
+}
+
+#[test]
+fn test_poke_collects_auto_debits_at_every_size() {
+    for size in GROUP_SIZES {
+        let group = setup_full_group(size);
+        enable_auto_debits(&group);
+
+        // Pokes collect a batch at a time, then pay out after the grace period
+        let mut pokes = 0;
+        loop {
+            let mut actions = vec![&group.env];
+            let usage = measure(&group.env, || {
+                actions = group.client.poke(&group.admin, &group.group_id).actions;
+            });
+            usage.assert_fits("poke collecting auto-debits", size);
+            if actions.is_empty() {
+                break;
+            }
+            assert!(matches!(
+                actions.first(),
+                Some(PokeAction::ContributionsCollected(_))
+            ));
+            pokes += 1;
+        }
+        assert_eq!(pokes, size.div_ceil(2));
+
+        advance_past_grace(&group.env);
+        let mut actions = vec![&group.env];
+        let usage = measure(&group.env, || {
+            actions = group.client.poke(&group.admin, &group.group_id).actions;
+        });
+        usage.assert_fits("poke paying out", size);
+        assert_eq!(actions.first(), Some(PokeAction::PayoutExecuted(1)));
+    }
+}
+
+#[test]
+fn test_contribute_and_claim_many_fit_at_every_size() {
+    for size in GROUP_SIZES {
+        let (group, group_ids) = setup_full_groups(size, 3);
+
+        // Late, inside the grace period, so each contribution also pays a penalty
+        group
+            .env
+            .ledger()
+            .with_mut(|li| li.timestamp += CYCLE + 3_600);
+        let mut results = vec![&group.env];
+        let usage = measure(&group.env, || {
+            results = group.client.contribute_many(&group.members[0], &group_ids);
+        });
+        usage.assert_fits("contribute_many", size);
+        for result in results.iter() {
+            assert_eq!(result.outcome, BatchOutcome::Succeeded);
+        }
+
+        for group_id in group_ids.iter() {
+            contribute_all(&group.client, group_id, &group.members[1..]);
+        }
+        advance_past_grace(&group.env);
+        let claimed = group_ids.slice(0..2);
+        let usage = measure(&group.env, || {
+            results = group.client.claim_many(&group.members[0], &claimed);
+        });
+        usage.assert_fits("claim_many", size);
+        for result in results.iter() {
+            assert_eq!(result.outcome, BatchOutcome::Succeeded);
+        }
+    }
+}
+
+#[test]
+fn test_poke_many_fits_at_every_size() {
+    for size in GROUP_SIZES {
+        let (group, group_ids) = setup_full_groups(size, 2);
+        let (idle, busy) = (group_ids.get_unchecked(0), group_ids.get_unchecked(1));
+        let token_client = TokenClient::new(&group.env, &group.token);
+        let expiration = group.env.ledger().sequence() + 1_000;
+        for member in &group.members {
+            group.client.enable_auto_debit(member, &busy);
+            token_client.approve(member, &group.client.address, &CONTRIBUTION, &expiration);
+        }
+
+        // A group with nothing due, then a batch of auto-debit collections
+        let mut results = vec![&group.env];
+        let usage = measure(&group.env, || {
+            results = group.client.poke_many(&group.admin, &group_ids);
+        });
+        usage.assert_fits("poke_many collecting auto-debits", size);
+        assert_eq!(results.len(), 2);
+        assert!(results.get_unchecked(0).actions.is_empty());
+        while group.client.get_group_status(&busy).contributions_received < size {
+            group.client.poke(&group.admin, &busy);
+        }
+
+        // Once the idle group's defaults are marked, a payout follows it
+        advance_past_grace(&group.env);
+        group.client.poke(&group.admin, &idle);
+        let usage = measure(&group.env, || {
+            results = group.client.poke_many(&group.admin, &group_ids);
+        });
+        usage.assert_fits("poke_many paying out", size);
+        assert_eq!(
+            results.get_unchecked(1).actions.first(),
+            Some(PokeAction::PayoutExecuted(1))
+        );
+    }
+}
+
+#[test]
+fn test_vault_fits_at_every_size() {
+    for size in GROUP_SIZES {
+        let group = setup_full_group(size);
+        // The vault pulls deposits from the contract in a nested call
+        group.env.mock_all_auths_allowing_non_root_auth();
+        let vault = MockVaultClient::new(&group.env, &group.env.register_contract(None, MockVault));
+        vault.init(&group.token);
+        group.client.set_vault_approved(&vault.address, &true);
+        group.client.set_group_vault(
+            &group.members[0],
+            &group.group_id,
+            &Some(vault.address.clone()),
+            &YieldPolicy::Members,
+        );
+        contribute_all(&group.client, group.group_id, &group.members);
+
+        let mut actions = vec![&group.env];
+        let usage = measure(&group.env, || {
+            actions = group.client.poke(&group.admin, &group.group_id).actions;
+        });
+        usage.assert_fits("poke parking funds", size);
+        let pot = CONTRIBUTION * size as i128;
+        assert_eq!(actions, vec![&group.env, PokeAction::FundsParked(pot)]);
+
+        let earned = 1_000_000 * size as i128;
+        StellarAssetClient::new(&group.env, &group.token).mint(&vault.address, &earned);
+        vault.accrue(&group.client.address, &earned);
+        advance_past_grace(&group.env);
+        let usage = measure(&group.env, || group.client.execute_payout(&group.group_id));
+        usage.assert_fits("execute_payout recalling from a vault", size);
+
+        // Dropping the vault shares the yield among the members a batch at a time
+        let usage = measure(&group.env, || {
+            group.client.set_group_vault(
+                &group.members[0],
+                &group.group_id,
+                &None,
+                &YieldPolicy::Members,
+            );
+        });
+        usage.assert_fits("set_group_vault paying member yield", size);
+        let mut pokes = 0;
+        loop {
+            let usage = measure(&group.env, || {
+                actions = group.client.poke(&group.admin, &group.group_id).actions;
+            });
+            usage.assert_fits("poke paying member yield", size);
+            if !matches!(actions.first(), Some(PokeAction::YieldDistributed(_))) {
+                break;
+            }
+            pokes += 1;
+        }
+        assert_eq!(pokes, size.saturating_sub(5).div_ceil(5));
+    }
+}
+
+#[test]
+fn test_cancel_group_fits_at_every_size() {
+    for size in GROUP_SIZES {
+        let group = setup_full_group(size);
+        contribute_all(&group.client, group.group_id, &group.members);
+
+        let usage = measure(&group.env, || {
+            group
+                .client
+                .cancel_group(&group.members[0], &group.group_id);
+        });
+        usage.assert_fits("cancel_group", size);
+        finish_refunds(&group, "cancel_group", size);
+        assert_all_refunded(&group);
+    }
+}
+
+#[test]
+fn test_execute_refund_fits_at_every_size() {
+    for size in GROUP_SIZES {
+        let group = setup_full_group(size);
+        contribute_all(
+            &group.client,
+            group.group_id,
+            &group.members[..size as usize / 2],
+        );
+
+        // The cycle stalls, and the members vote to wind the group up
+        advance_past_grace(&group.env);
+        group
+            .client
+            .request_refund(&group.members[0], &group.group_id);
+        group
+            .client
+            .vote_refund(&group.members[0], &group.group_id, &true);
+        group
+            .env
+            .ledger()
+            .with_mut(|li| li.timestamp += 30 * 86_400);
+
+        let usage = measure(&group.env, || {
+            group
+                .client
+                .execute_refund(&group.members[0], &group.group_id);
+        });
+        usage.assert_fits("execute_refund", size);
+        finish_refunds(&group, "execute_refund", size);
+        assert_all_refunded(&group);
+    }
+}
+
+#[test]
+fn test_emergency_refund_fits_at_every_size() {
+    for size in GROUP_SIZES {
+        let group = setup_full_group(size);
+        contribute_all(&group.client, group.group_id, &group.members);
+
+        let usage = measure(&group.env, || {
+            group.client.emergency_refund(&group.admin, &group.group_id);
+        });
+        usage.assert_fits("emergency_refund", size);
+        finish_refunds(&group, "emergency_refund", size);
+        assert_all_refunded(&group);
+    }
+}
+
+#[test]
+fn test_poke_continues_refunds() {
+    let group = setup_full_group(12);
+    contribute_all(&group.client, group.group_id, &group.members);
+    group.client.emergency_refund(&group.admin, &group.group_id);
+
+    let progress = group.client.get_refund_progress(&group.group_id).unwrap();
+    assert_eq!(progress.next_member, 5);
+    assert_eq!(progress.total_refunded, CONTRIBUTION * 5);
+
+    let result = group.client.poke(&group.admin, &group.group_id);
+    assert_eq!(
+        result.actions,
+        vec![&group.env, PokeAction::RefundsProcessed(5)]
+    );
+    assert_eq!(group.client.process_refunds(&group.group_id), 0);
+    assert!(group.client.get_refund_progress(&group.group_id).is_none());
+    assert_all_refunded(&group);
+
+    // Nothing is left to refund
+    assert!(group
+        .client
+        .poke(&group.admin, &group.group_id)
+        .actions
+        .is_empty());
+    assert!(group.client.try_process_refunds(&group.group_id).is_err());
+}
+
+#[test]
+fn test_member_obligations_page_fits() {
+    let group = setup_full_group(2);
+    let member = &group.members[1];
+
+    // The member joins eleven more groups, each with a different creator
+    for _ in 0..11 {
+        let creator = Address::generate(&group.env);
+        StellarAssetClient::new(&group.env, &group.token).mint(&creator, &MINTED);
+        let group_id = group.client.create_group(
+            &creator,
+            &group.token,
+            &CONTRIBUTION,
+            &CycleSchedule::Fixed(CYCLE),
+            &2u32,
+            &GRACE,
+            &5u32,
+            &0u32,
+            &0u32,
+        );
+        group.client.join_group(member, &group_id, &1u32);
+    }
+
+    let mut start_id = 0;
+    let mut listed = 0;
+    loop {
+        let mut page = vec![&group.env];
+        let usage = measure(&group.env, || {
+            page = group
+                .client
+                .get_member_obligations(member, &start_id, &u32::MAX);
+        });
+        usage.assert_fits("get_member_obligations for a member of 12 groups", 2);
+        match page.last() {
+            Some(last) => start_id = last.group_id + 1,
+            None => break,
+        }
+        listed += page.len();
+    }
+    assert_eq!(listed, 12);
+}
+
+#[test]
+fn test_contribution_matrix_fits_at_every_size() {
+    for size in GROUP_SIZES {
+        let group = setup_full_group(size);
+        let cycles = size.min(6);
+        for _ in 1..cycles {
+            contribute_all(&group.client, group.group_id, &group.members);
+            advance_past_grace(&group.env);
+            group.client.execute_payout(&group.group_id);
+        }
+
+        // Every page of members covers every cycle reached so far
+        let mut start_member = 0;
+        while start_member < size {
+            let usage = measure(&group.env, || {
+                let matrix = group.client.get_contribution_matrix(
+                    &group.group_id,
+                    &1,
+                    &cycles,
+                    &start_member,
+                    &u32::MAX,
+                );
+                assert_eq!(matrix.len(), cycles);
+            });
+            usage.assert_fits("get_contribution_matrix", size);
+            start_member += 50;
+        }
+    }
+}
+
+#[test]
+fn test_audit_all_fits_at_every_size() {
+    for size in GROUP_SIZES {
+        let group = setup_full_group(size);
+        // Past the audit window wherever the rotation is long enough
+        for _ in 1..size.min(10) {
+            contribute_all(&group.client, group.group_id, &group.members);
+            advance_past_grace(&group.env);
+            group.client.execute_payout(&group.group_id);
+        }
+        contribute_all(&group.client, group.group_id, &group.members[1..]);
+
+        // Smaller running groups after it share the sweep's budget
+        for _ in 0..6 {
+            let group_id = group.client.create_group(
+                &group.members[0],
+                &group.token,
+                &CONTRIBUTION,
+                &CycleSchedule::Fixed(CYCLE),
+                &2u32,
+                &GRACE,
+                &5u32,
+                &0u32,
+                &0u32,
+            );
+            group.client.join_group(&group.members[1], &group_id, &1u32);
+        }
+
+        let mut cursor = 1;
+        let mut audited = 0;
+        while cursor <= group.group_id + 6 {
+            let mut audits = None;
+            let usage = measure(&group.env, || {
+                audits = Some(group.client.audit_all(&cursor, &u32::MAX));
+            });
+            usage.assert_fits("audit_all", size);
+
+            let audits = audits.unwrap();
+            assert!(!audits.is_empty());
+            for audit in audits.iter() {
+                assert!(audit.issues.is_empty(), "{:?}", audit.issues);
+            }
+            audited += audits.len();
+            cursor = audits.last().unwrap().group_id + 1;
+        }
+        assert_eq!(audited, 7);
+    }
+}
@@ -1,10 +1,35 @@
 #![cfg(test)]
 
-use soroban_ajo::{AjoContract, AjoContractClient, AjoError};
-use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env};
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, CycleSchedule};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env,
+};
 
 /// Helper function to create a test environment and contract
-fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address, Address) {
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let (env, client, _admin, creator, member2, member3, token) = setup_test_env_with_admin();
+    (env, client, creator, member2, member3, token)
+}
+
+/// Helper function to create a test environment and also return the contract admin
+fn setup_test_env_with_admin() -> (
+    Env,
+    AjoContractClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -16,19 +41,48 @@ fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address, Addre
     let member2 = Address::generate(&env);
     let member3 = Address::generate(&env);
 
-    (env, client, creator, member2, member3)
+    // Register the group token and fund the test addresses
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    // The contract only accepts tokens registered by the admin
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+    for addr in [&creator, &member2, &member3] {
+        fund(&env, &token, addr);
+    }
+
+    (env, client, admin, creator, member2, member3, token)
+}
+
+/// Helper function to mint enough tokens for an address to cover any test scenario
+fn fund(env: &Env, token: &Address, address: &Address) {
+    StellarAssetClient::new(env, token).mint(address, &1_000_000_000_000_000);
 }
 
 #[test]
 fn test_cancel_group_before_payout() {
-    let (_env, client, creator, member2, member3) = setup_test_env();
+    let (_env, client, creator, member2, member3, token) = setup_test_env();
 
     // Create group
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Members join
-    client.join_group(&member2, &group_id);
-    client.join_group(&member3, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
+    client.join_group(&member3, &group_id, &1u32);
 
     // Members contribute
     client.contribute(&creator, &group_id);
@@ -44,7 +98,10 @@ fn test_cancel_group_before_payout() {
     // Verify refund records exist
     let refund1 = client.get_refund_record(&group_id, &creator);
     assert_eq!(refund1.amount, 100_000_000i128);
-    assert_eq!(refund1.reason, soroban_ajo::RefundReason::CreatorCancellation);
+    assert_eq!(
+        refund1.reason,
+        soroban_ajo::RefundReason::CreatorCancellation
+    );
 
     let refund2 = client.get_refund_record(&group_id, &member2);
     assert_eq!(refund2.amount, 100_000_000i128);
@@ -52,14 +109,24 @@ fn test_cancel_group_before_payout() {
 
 #[test]
 fn test_cannot_cancel_after_payout() {
-    let (env, client, creator, member2, member3) = setup_test_env();
+    let (env, client, creator, member2, member3, token) = setup_test_env();
 
     // Create group
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Members join
-    client.join_group(&member2, &group_id);
-    client.join_group(&member3, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
+    client.join_group(&member3, &group_id, &1u32);
 
     // All contribute
     client.contribute(&creator, &group_id);
@@ -79,13 +146,23 @@ fn test_cannot_cancel_after_payout() {
 
 #[test]
 fn test_only_creator_can_cancel() {
-    let (_env, client, creator, member2, member3) = setup_test_env();
+    let (_env, client, creator, member2, _member3, token) = setup_test_env();
 
     // Create group
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Member joins
-    client.join_group(&member2, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
 
     // Non-creator tries to cancel - should fail
     let result = client.try_cancel_group(&member2, &group_id);
@@ -94,14 +171,24 @@ fn test_only_creator_can_cancel() {
 
 #[test]
 fn test_request_refund_after_cycle_expires() {
-    let (env, client, creator, member2, member3) = setup_test_env();
+    let (env, client, creator, member2, member3, token) = setup_test_env();
 
     // Create group
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Members join
-    client.join_group(&member2, &group_id);
-    client.join_group(&member3, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
+    client.join_group(&member3, &group_id, &1u32);
 
     // Only some members contribute
     client.contribute(&creator, &group_id);
@@ -124,13 +211,23 @@ fn test_request_refund_after_cycle_expires() {
 
 #[test]
 fn test_cannot_request_refund_before_cycle_expires() {
-    let (_env, client, creator, member2, _) = setup_test_env();
+    let (_env, client, creator, member2, _, token) = setup_test_env();
 
     // Create group
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Member joins
-    client.join_group(&member2, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
 
     // Try to request refund before cycle expires - should fail
     let result = client.try_request_refund(&creator, &group_id);
@@ -139,14 +236,24 @@ fn test_cannot_request_refund_before_cycle_expires() {
 
 #[test]
 fn test_voting_on_refund_request() {
-    let (env, client, creator, member2, member3) = setup_test_env();
+    let (env, client, creator, member2, member3, token) = setup_test_env();
 
     // Create group
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Members join
-    client.join_group(&member2, &group_id);
-    client.join_group(&member3, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
+    client.join_group(&member3, &group_id, &1u32);
 
     // Contribute
     client.contribute(&creator, &group_id);
@@ -172,13 +279,23 @@ fn test_voting_on_refund_request() {
 
 #[test]
 fn test_cannot_vote_twice() {
-    let (env, client, creator, member2, _) = setup_test_env();
+    let (env, client, creator, member2, _, token) = setup_test_env();
 
     // Create group
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Member joins
-    client.join_group(&member2, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
 
     // Advance time past grace period
     env.ledger().with_mut(|li| {
@@ -198,14 +315,24 @@ fn test_cannot_vote_twice() {
 
 #[test]
 fn test_execute_approved_refund() {
-    let (env, client, creator, member2, member3) = setup_test_env();
+    let (env, client, creator, member2, member3, token) = setup_test_env();
 
     // Create group
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Members join
-    client.join_group(&member2, &group_id);
-    client.join_group(&member3, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
+    client.join_group(&member3, &group_id, &1u32);
 
     // All contribute
     client.contribute(&creator, &group_id);
@@ -244,14 +371,24 @@ fn test_execute_approved_refund() {
 
 #[test]
 fn test_execute_rejected_refund() {
-    let (env, client, creator, member2, member3) = setup_test_env();
+    let (env, client, creator, member2, member3, token) = setup_test_env();
 
     // Create group
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Members join
-    client.join_group(&member2, &group_id);
-    client.join_group(&member3, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
+    client.join_group(&member3, &group_id, &1u32);
 
     // Advance time past grace period
     env.ledger().with_mut(|li| {
@@ -278,13 +415,23 @@ fn test_execute_rejected_refund() {
 
 #[test]
 fn test_cannot_execute_before_voting_ends() {
-    let (env, client, creator, member2, _) = setup_test_env();
+    let (env, client, creator, member2, _, token) = setup_test_env();
 
     // Create group
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Member joins
-    client.join_group(&member2, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
 
     // Advance time past grace period
     env.ledger().with_mut(|li| {
@@ -304,17 +451,23 @@ fn test_cannot_execute_before_voting_ends() {
 
 #[test]
 fn test_emergency_refund_by_admin() {
-    let (env, client, creator, member2, _) = setup_test_env();
-
-    // Initialize contract with admin
-    let admin = Address::generate(&env);
-    client.initialize(&admin);
+    let (_env, client, admin, creator, member2, _, token) = setup_test_env_with_admin();
 
     // Create group
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Member joins and contributes
-    client.join_group(&member2, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
     client.contribute(&creator, &group_id);
     client.contribute(&member2, &group_id);
 
@@ -332,15 +485,21 @@ fn test_emergency_refund_by_admin() {
 
 #[test]
 fn test_non_admin_cannot_emergency_refund() {
-    let (env, client, creator, member2, _) = setup_test_env();
-
-    // Initialize contract with admin
-    let admin = Address::generate(&env);
-    client.initialize(&admin);
+    let (_env, client, _admin, creator, member2, _, token) = setup_test_env_with_admin();
 
     // Create group
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Non-admin tries emergency refund - should fail
     let result = client.try_emergency_refund(&member2, &group_id);
     assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
@@ -348,13 +507,23 @@ fn test_non_admin_cannot_emergency_refund() {
 
 #[test]
 fn test_cannot_contribute_to_cancelled_group() {
-    let (_env, client, creator, member2, _) = setup_test_env();
+    let (_env, client, creator, member2, _, token) = setup_test_env();
 
     // Create group
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Member joins
-    client.join_group(&member2, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
 
     // Creator contributes and cancels
     client.contribute(&creator, &group_id);
@@ -367,14 +536,24 @@ fn test_cannot_contribute_to_cancelled_group() {
 
 #[test]
 fn test_cannot_execute_payout_on_cancelled_group() {
-    let (env, client, creator, member2, member3) = setup_test_env();
+    let (env, client, creator, member2, member3, token) = setup_test_env();
 
     // Create group
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Members join and contribute
-    client.join_group(&member2, &group_id);
-    client.join_group(&member3, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
+    client.join_group(&member3, &group_id, &1u32);
     client.contribute(&creator, &group_id);
     client.contribute(&member2, &group_id);
     client.contribute(&member3, &group_id);
@@ -394,13 +573,23 @@ fn test_cannot_execute_payout_on_cancelled_group() {
 
 #[test]
 fn test_refund_request_duplicate_prevention() {
-    let (env, client, creator, member2, _) = setup_test_env();
+    let (env, client, creator, member2, _, token) = setup_test_env();
 
     // Create group
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Member joins
-    client.join_group(&member2, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
 
     // Advance time past grace period
     env.ledger().with_mut(|li| {
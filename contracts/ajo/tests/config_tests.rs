@@ -0,0 +1,205 @@
+#![cfg(test)]
+
+//! Tests for the admin-configurable validation limits
+
+use soroban_ajo::events::{ConfigUpdated, EVENT_VERSION};
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, ContractConfig, CycleSchedule};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token::StellarAssetClient,
+    Address, Env, IntoVal, String,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const WEEK: u64 = 604_800;
+const DAY: u64 = 86_400;
+
+/// Helper function to create an initialized contract and a registered token
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+
+    (env, client, token)
+}
+
+/// Helper function to create a group with the given member cap
+fn try_create(
+    client: &AjoContractClient,
+    creator: &Address,
+    token: &Address,
+    max_members: u32,
+) -> Result<u64, AjoError> {
+    match client.try_create_group(
+        creator,
+        token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(WEEK),
+        &max_members,
+        &DAY,
+        &5u32,
+        &0u32,
+        &0u32,
+    ) {
+        Ok(Ok(group_id)) => Ok(group_id),
+        Err(Ok(error)) => Err(error),
+        _ => panic!("unexpected invocation failure"),
+    }
+}
+
+#[test]
+fn test_default_config() {
+    let (_env, client, _token) = setup_test_env();
+    let config = client.get_config();
+
+    assert_eq!(config.min_members, 2);
+    assert_eq!(config.max_members, 100);
+    assert_eq!(config.max_name_length, 50);
+    assert_eq!(config.voting_period, WEEK);
+    assert_eq!(config.refund_approval_threshold, 51);
+}
+
+#[test]
+fn test_set_config_emits_event() {
+    let (env, client, _token) = setup_test_env();
+    let config = ContractConfig {
+        max_members: 20,
+        ..client.get_config()
+    };
+
+    client.set_config(&config);
+    assert_eq!(client.get_config(), config);
+
+    let event = env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("config"), EVENT_VERSION).into_val(&env)
+    );
+    let data: ConfigUpdated = event.2.into_val(&env);
+    assert_eq!(data.config, config);
+}
+
+#[test]
+fn test_set_config_rejects_inconsistent_bounds() {
+    let (_env, client, _token) = setup_test_env();
+    let config = client.get_config();
+
+    let crossed = ContractConfig {
+        min_members: 10,
+        max_members: 5,
+        ..config
+    };
+    let result = client.try_set_config(&crossed);
+    assert_eq!(result, Err(Ok(AjoError::MaxMembersBelowMinimum)));
+
+    let too_many = ContractConfig {
+        max_members: 101,
+        ..config
+    };
+    let result = client.try_set_config(&too_many);
+    assert_eq!(result, Err(Ok(AjoError::MaxMembersAboveLimit)));
+
+    // Nothing was stored
+    assert_eq!(client.get_config(), config);
+}
+
+#[test]
+fn test_set_config_requires_admin() {
+    let (env, client, _token) = setup_test_env();
+    let config = client.get_config();
+    env.mock_auths(&[]);
+
+    assert!(client.try_set_config(&config).is_err());
+}
+
+#[test]
+fn test_member_bounds_apply_to_new_groups() {
+    let (env, client, token) = setup_test_env();
+    let creator = Address::generate(&env);
+    let existing = try_create(&client, &creator, &token, 50).unwrap();
+
+    client.set_config(&ContractConfig {
+        min_members: 3,
+        max_members: 10,
+        ..client.get_config()
+    });
+
+    assert_eq!(
+        try_create(&client, &creator, &token, 2),
+        Err(AjoError::MaxMembersBelowMinimum)
+    );
+    assert_eq!(
+        try_create(&client, &creator, &token, 11),
+        Err(AjoError::MaxMembersAboveLimit)
+    );
+    assert!(try_create(&client, &creator, &token, 10).is_ok());
+
+    // Existing groups keep their parameters
+    assert_eq!(client.get_group(&existing).max_members, 50);
+}
+
+#[test]
+fn test_metadata_limits_follow_config() {
+    let (env, client, token) = setup_test_env();
+    let creator = Address::generate(&env);
+    let group_id = try_create(&client, &creator, &token, 3).unwrap();
+    let name = String::from_str(&env, "A name longer than ten bytes");
+    let empty = String::from_str(&env, "");
+
+    client.set_group_metadata(&creator, &group_id, &name, &empty, &empty);
+
+    client.set_config(&ContractConfig {
+        max_name_length: 10,
+        ..client.get_config()
+    });
+    let result = client.try_set_group_metadata(&creator, &group_id, &name, &empty, &empty);
+    assert_eq!(result, Err(Ok(AjoError::MetadataTooLong)));
+}
+
+#[test]
+fn test_refund_voting_follows_config() {
+    let (env, client, token) = setup_test_env();
+    let members = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for member in &members {
+        StellarAssetClient::new(&env, &token).mint(member, &1_000_000_000_000);
+    }
+    let group_id = try_create(&client, &members[0], &token, 3).unwrap();
+    client.join_group(&members[1], &group_id, &1u32);
+    client.join_group(&members[2], &group_id, &1u32);
+
+    client.set_config(&ContractConfig {
+        voting_period: DAY,
+        refund_approval_threshold: 75,
+        ..client.get_config()
+    });
+
+    env.ledger().with_mut(|li| li.timestamp += WEEK + DAY + 1);
+    client.request_refund(&members[0], &group_id);
+    let request = client.get_refund_request(&group_id);
+    assert_eq!(request.voting_deadline, request.created_at + DAY);
+
+    // Two thirds in favour no longer meets the threshold
+    client.vote_refund(&members[0], &group_id, &true);
+    client.vote_refund(&members[1], &group_id, &true);
+    client.vote_refund(&members[2], &group_id, &false);
+    env.ledger().with_mut(|li| li.timestamp += DAY + 1);
+
+    let result = client.try_execute_refund(&members[0], &group_id);
+    assert_eq!(result, Err(Ok(AjoError::RefundNotApproved)));
+}
@@ -0,0 +1,206 @@
+#![cfg(test)]
+
+//! Tests for installment and prepaid contributions held as member credit
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, CycleSchedule};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    Address, Env,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+
+/// Helper function to create a full three-member group with a funded token
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    u64,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let member3 = Address::generate(&env);
+
+    // Register the group token and fund the test addresses
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    // The contract only accepts tokens registered by the admin
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+    for addr in [&creator, &member2, &member3] {
+        StellarAssetClient::new(&env, &token).mint(addr, &1_000_000_000_000);
+    }
+
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(CYCLE_DURATION),
+        &3u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&member2, &group_id, &1u32);
+    client.join_group(&member3, &group_id, &1u32);
+
+    (env, client, group_id, token, creator, member2, member3)
+}
+
+#[test]
+fn test_installments_count_once_complete() {
+    let (_env, client, group_id, _token, creator, _, _) = setup_test_env();
+
+    let credit = client.contribute_amount(&creator, &group_id, &40_000_000i128);
+    assert_eq!(credit, 40_000_000);
+
+    let progress = client.get_contribution_progress(&group_id, &creator);
+    assert!(!progress.contributed);
+    assert_eq!(progress.amount_due, 60_000_000);
+    assert_eq!(progress.credit, 40_000_000);
+    assert!(
+        !client
+            .get_contribution_status(&group_id, &1u32)
+            .get(0)
+            .unwrap()
+            .1
+    );
+
+    let credit = client.contribute_amount(&creator, &group_id, &60_000_000i128);
+    assert_eq!(credit, 0);
+
+    let progress = client.get_contribution_progress(&group_id, &creator);
+    assert!(progress.contributed);
+    assert_eq!(progress.amount_due, 0);
+
+    let record = client.get_contribution_detail(&group_id, &1u32, &creator);
+    assert_eq!(record.amount, CONTRIBUTION);
+    assert_eq!(record.paid_from_credit, CONTRIBUTION);
+}
+
+#[test]
+fn test_late_installment_includes_penalty() {
+    let (env, client, group_id, _token, creator, _, _) = setup_test_env();
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + 1;
+    });
+
+    // A full contribution is not enough inside the grace period
+    client.contribute_amount(&creator, &group_id, &CONTRIBUTION);
+    let progress = client.get_contribution_progress(&group_id, &creator);
+    assert!(!progress.contributed);
+    assert_eq!(progress.amount_due, 5_000_000);
+
+    client.contribute_amount(&creator, &group_id, &5_000_000i128);
+    assert!(
+        client
+            .get_contribution_progress(&group_id, &creator)
+            .contributed
+    );
+}
+
+#[test]
+fn test_prepaid_cycles_apply_after_payout() {
+    let (env, client, group_id, token, creator, member2, member3) = setup_test_env();
+    let token_client = TokenClient::new(&env, &token);
+    let balance_before = token_client.balance(&creator);
+
+    // Pay for the whole rotation up front
+    let credit = client.contribute_amount(&creator, &group_id, &(CONTRIBUTION * 3));
+    assert_eq!(credit, CONTRIBUTION * 2);
+    assert_eq!(
+        client
+            .get_contribution_progress(&group_id, &creator)
+            .prepaid_cycles,
+        2
+    );
+
+    client.contribute(&member2, &group_id);
+    client.contribute(&member3, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD + 1;
+    });
+    client.execute_payout(&group_id);
+
+    // The next cycle is settled from credit without another transfer
+    let progress = client.get_contribution_progress(&group_id, &creator);
+    assert_eq!(progress.cycle, 2);
+    assert!(progress.contributed);
+    assert_eq!(progress.credit, CONTRIBUTION);
+    assert_eq!(progress.prepaid_cycles, 1);
+    // The creator prepaid three cycles and received the first payout
+    assert_eq!(token_client.balance(&creator), balance_before);
+    assert!(
+        !client
+            .get_contribution_progress(&group_id, &member2)
+            .contributed
+    );
+}
+
+#[test]
+fn test_prepayment_capped_at_remaining_cycles() {
+    let (_env, client, group_id, _token, creator, _, _) = setup_test_env();
+
+    let result = client.try_contribute_amount(&creator, &group_id, &(CONTRIBUTION * 3 + 1));
+    assert_eq!(result, Err(Ok(AjoError::ContributionOutOfRange)));
+
+    client.contribute_amount(&creator, &group_id, &(CONTRIBUTION * 3));
+    let result = client.try_contribute_amount(&creator, &group_id, &1i128);
+    assert_eq!(result, Err(Ok(AjoError::ContributionOutOfRange)));
+}
+
+#[test]
+fn test_contribute_amount_rejects_invalid_amounts() {
+    let (env, client, group_id, _token, creator, _, _) = setup_test_env();
+
+    let result = client.try_contribute_amount(&creator, &group_id, &0i128);
+    assert_eq!(result, Err(Ok(AjoError::ContributionAmountZero)));
+
+    let result = client.try_contribute_amount(&creator, &group_id, &-1i128);
+    assert_eq!(result, Err(Ok(AjoError::ContributionAmountNegative)));
+
+    let stranger = Address::generate(&env);
+    let result = client.try_contribute_amount(&stranger, &group_id, &CONTRIBUTION);
+    assert_eq!(result, Err(Ok(AjoError::NotMember)));
+}
+
+#[test]
+fn test_cancel_refunds_credit() {
+    let (env, client, group_id, token, creator, member2, _) = setup_test_env();
+    let token_client = TokenClient::new(&env, &token);
+    let balance_before = token_client.balance(&member2);
+
+    client.contribute_amount(&member2, &group_id, &(CONTRIBUTION * 2 + 50_000_000));
+    client.contribute_amount(&creator, &group_id, &50_000_000i128);
+
+    client.cancel_group(&creator, &group_id);
+
+    assert_eq!(
+        client.get_refund_record(&group_id, &member2).amount,
+        CONTRIBUTION * 2 + 50_000_000
+    );
+    assert_eq!(
+        client.get_refund_record(&group_id, &creator).amount,
+        50_000_000
+    );
+    assert_eq!(token_client.balance(&member2), balance_before);
+    assert_eq!(client.get_member_credit(&group_id, &member2), 0);
+}
@@ -0,0 +1,191 @@
+#![cfg(test)]
+
+//! Tests for the contribution matrix and cycle summaries
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, ContributionState, CycleSchedule};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    vec, Address, Env,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+
+/// Helper function to create a contract, a registered token and three funded members
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    for addr in [&alice, &bob, &carol] {
+        StellarAssetClient::new(&env, &token).mint(addr, &1_000_000_000_000);
+    }
+
+    (env, client, admin, token, alice, bob, carol)
+}
+
+/// Helper function to create a three-member group with 1% insurance
+fn create_group(
+    client: &AjoContractClient,
+    token: &Address,
+    alice: &Address,
+    bob: &Address,
+    carol: &Address,
+) -> u64 {
+    let group_id = client.create_group(
+        alice,
+        token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &100u32,
+        &0u32,
+    );
+    client.join_group(bob, &group_id, &1u32);
+    client.join_group(carol, &group_id, &1u32);
+    group_id
+}
+
+/// Helper function to run the first cycle with carol paying late
+fn run_first_cycle(
+    env: &Env,
+    client: &AjoContractClient,
+    group_id: u64,
+    alice: &Address,
+    bob: &Address,
+    carol: &Address,
+) {
+    client.contribute(alice, &group_id);
+    client.contribute(bob, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 3600;
+    });
+    client.contribute(carol, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400;
+    });
+    client.execute_payout(&group_id);
+}
+
+#[test]
+fn test_contribution_matrix_reports_each_state() {
+    let (env, client, _admin, token, alice, bob, carol) = setup_test_env();
+    let group_id = create_group(&client, &token, &alice, &bob, &carol);
+    run_first_cycle(&env, &client, group_id, &alice, &bob, &carol);
+    client.contribute(&alice, &group_id);
+
+    use ContributionState::*;
+    let matrix = client.get_contribution_matrix(&group_id, &1, &5, &0, &50);
+    assert_eq!(matrix.len(), 2);
+    let first = matrix.get(0).unwrap();
+    assert_eq!(first.cycle, 1);
+    assert_eq!(first.states, vec![&env, OnTime, OnTime, Late]);
+    let second = matrix.get(1).unwrap();
+    assert_eq!(second.cycle, 2);
+    assert_eq!(second.states, vec![&env, OnTime, Pending, Pending]);
+
+    // Once the grace period is over the unpaid contributions are missed
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 86400 + 1;
+    });
+    let matrix = client.get_contribution_matrix(&group_id, &2, &2, &0, &50);
+    assert_eq!(matrix.len(), 1);
+    assert_eq!(
+        matrix.get(0).unwrap().states,
+        vec![&env, OnTime, Missed, Missed]
+    );
+
+    // Members are paged by their index in the member list
+    let matrix = client.get_contribution_matrix(&group_id, &1, &2, &1, &1);
+    assert_eq!(matrix.get(0).unwrap().states, vec![&env, OnTime]);
+    assert_eq!(matrix.get(1).unwrap().states, vec![&env, Missed]);
+    let matrix = client.get_contribution_matrix(&group_id, &1, &1, &2, &50);
+    assert_eq!(matrix.get(0).unwrap().states, vec![&env, Late]);
+    let matrix = client.get_contribution_matrix(&group_id, &1, &1, &3, &50);
+    assert!(matrix.get(0).unwrap().states.is_empty());
+
+    assert!(client
+        .get_contribution_matrix(&group_id, &2, &1, &0, &50)
+        .is_empty());
+    let result = client.try_get_contribution_matrix(&99, &1, &1, &0, &50);
+    assert_eq!(result, Err(Ok(AjoError::GroupNotFound)));
+}
+
+#[test]
+fn test_paid_insurance_claim_covers_missed_contribution() {
+    let (env, client, admin, token, alice, bob, carol) = setup_test_env();
+    let group_id = create_group(&client, &token, &alice, &bob, &carol);
+    run_first_cycle(&env, &client, group_id, &alice, &bob, &carol);
+    client.contribute(&alice, &group_id);
+    client.contribute(&bob, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 86400 + 1;
+    });
+
+    // Bob, the second cycle's recipient, claims for carol's default
+    let claim_id = client.file_insurance_claim(&bob, &group_id, &2u32, &carol, &1_000i128);
+    let states = |cycle: u32| client.get_contribution_matrix(&group_id, &cycle, &cycle, &0, &50);
+    assert_eq!(
+        states(2).get(0).unwrap().states.get(2),
+        Some(ContributionState::Missed)
+    );
+
+    client.process_insurance_claim(&admin, &claim_id, &true);
+    assert_eq!(
+        states(2).get(0).unwrap().states.get(2),
+        Some(ContributionState::CoveredByInsurance)
+    );
+
+    // A claim is processed only once
+    let result = client.try_process_insurance_claim(&admin, &claim_id, &false);
+    assert_eq!(result, Err(Ok(AjoError::ClaimAlreadyProcessed)));
+}
+
+#[test]
+fn test_cycle_summary_for_paid_cycles() {
+    let (env, client, _admin, token, alice, bob, carol) = setup_test_env();
+    let group_id = create_group(&client, &token, &alice, &bob, &carol);
+    run_first_cycle(&env, &client, group_id, &alice, &bob, &carol);
+
+    let penalty = CONTRIBUTION * 5 / 100;
+    let summary = client.get_cycle_summary(&group_id, &1);
+    assert_eq!(summary.group_id, group_id);
+    assert_eq!(summary.cycle, 1);
+    assert_eq!(summary.collected, CONTRIBUTION * 3 + penalty);
+    assert_eq!(summary.penalty_pool, penalty);
+    assert_eq!(summary.recipient, alice);
+    assert_eq!(summary.paid_at, env.ledger().timestamp());
+
+    let payout = client.get_payout_history(&group_id, &1, &1).get(0).unwrap();
+    assert_eq!(summary.payout_amount, payout.amount);
+
+    // The current cycle has not been paid out yet
+    let result = client.try_get_cycle_summary(&group_id, &2);
+    assert_eq!(result, Err(Ok(AjoError::OutsideCycleWindow)));
+    let result = client.try_get_cycle_summary(&99, &1);
+    assert_eq!(result, Err(Ok(AjoError::GroupNotFound)));
+}
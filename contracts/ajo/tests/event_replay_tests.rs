@@ -0,0 +1,399 @@
+#![cfg(test)]
+
+//! Tests that the contract state can be rebuilt from its events alone
+//!
+//! A scenario drives groups through joins, contributions, credit, payouts,
+//! freezes, ownership changes, insurance claims, cancellation and templates.
+//! Every event the contract emitted is then replayed into a model, and the
+//! model is compared against what the contract reports from storage.
+
+use std::collections::BTreeMap;
+
+use soroban_ajo::events::{
+    ClaimFiled, ClaimProcessed, CoOrganizerAdded, CoOrganizerRemoved, ContributionMade,
+    CreditDeposited, CycleAdvanced, GroupCreated, GroupStarted, InsuranceDeposited, MemberJoined,
+    MetadataUpdated, OwnershipTransferred, PauseChanged, PayoutExecuted, PlatformFeeSet,
+    RefundProcessed, TemplateRemoved, TemplateSaved, EVENT_VERSION,
+};
+use soroban_ajo::{
+    AjoContract, AjoContractClient, ClaimStatus, ContributionRecord, CycleSchedule, Group,
+    GroupMetadata, GroupState, GroupTemplate, InsuranceClaim, PauseFlag, PauseState, PayoutRecord,
+    RefundRecord, TemplateOverrides, TemplateParams,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token::StellarAssetClient,
+    Address, Env, IntoVal, String, Symbol, Val, Vec,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const GRACE: u64 = 86400;
+
+/// Contract state rebuilt from events
+#[derive(Default)]
+struct Model {
+    groups: BTreeMap<u64, Group>,
+    seats: BTreeMap<u64, std::vec::Vec<Address>>,
+    metadata: BTreeMap<u64, GroupMetadata>,
+    co_organizers: BTreeMap<u64, std::vec::Vec<Address>>,
+    credit: BTreeMap<(u64, Address), i128>,
+    contributions: BTreeMap<(u64, u32, Address), ContributionRecord>,
+    payouts: BTreeMap<(u64, u32), PayoutRecord>,
+    refunds: BTreeMap<(u64, Address), RefundRecord>,
+    treasury: BTreeMap<Address, i128>,
+    insurance_pools: BTreeMap<Address, i128>,
+    claims: BTreeMap<u64, InsuranceClaim>,
+    templates: BTreeMap<u64, GroupTemplate>,
+    pause_state: PauseState,
+    platform_fee_bps: u32,
+}
+
+impl Model {
+    /// Applies one event to the model
+    fn apply(&mut self, env: &Env, name: &str, data: Val) {
+        match name {
+            "created" => {
+                let event: GroupCreated = data.into_val(env);
+                // The creator holds the first seat
+                let seats = std::vec![event.group.creator.clone()];
+                self.seats.insert(event.group.id, seats);
+                self.groups.insert(event.group.id, event.group);
+            }
+            "joined" => {
+                let event: MemberJoined = data.into_val(env);
+                let group = self.groups.get_mut(&event.group_id).unwrap();
+                group.member_count += 1;
+                group.seat_count += event.seats;
+                let seats = self.seats.get_mut(&event.group_id).unwrap();
+                for _ in 0..event.seats {
+                    seats.insert(event.position as usize, event.member.clone());
+                }
+            }
+            "contrib" => {
+                let event: ContributionMade = data.into_val(env);
+                let record = event.record;
+                let key = (record.group_id, record.member.clone());
+                *self.credit.entry(key).or_default() -= record.paid_from_credit;
+                let key = (record.group_id, record.cycle, record.member.clone());
+                self.contributions.insert(key, record);
+            }
+            "credit" => {
+                let event: CreditDeposited = data.into_val(env);
+                let key = (event.group_id, event.member);
+                self.credit.insert(key, event.credit_balance);
+            }
+            "payout" => {
+                let event: PayoutExecuted = data.into_val(env);
+                let record = event.record;
+                let group = self.groups.get_mut(&record.group_id).unwrap();
+                group.payout_index += 1;
+                let token = group.token_address.clone();
+                *self.treasury.entry(token).or_default() += record.platform_fee;
+                self.payouts.insert((record.group_id, record.cycle), record);
+            }
+            "cycle" => {
+                let event: CycleAdvanced = data.into_val(env);
+                let group = self.groups.get_mut(&event.group_id).unwrap();
+                group.current_cycle = event.cycle;
+                group.cycle_start_time = event.cycle_start_time;
+            }
+            "complete" => {
+                let group_id: u64 = self.group_id(env, data);
+                let group = self.groups.get_mut(&group_id).unwrap();
+                group.is_complete = true;
+                group.state = GroupState::Complete;
+            }
+            "cancel" | "refexec" | "emrefund" => {
+                let group_id = self.group_id(env, data);
+                self.groups.get_mut(&group_id).unwrap().state = GroupState::Cancelled;
+            }
+            "refund" => {
+                let event: RefundProcessed = data.into_val(env);
+                let record = event.record;
+                let key = (record.group_id, record.member.clone());
+                self.credit.insert(key.clone(), 0);
+                self.refunds.insert(key, record);
+            }
+            "frozen" => {
+                let group_id = self.group_id(env, data);
+                self.groups.get_mut(&group_id).unwrap().state = GroupState::Frozen;
+            }
+            "started" => {
+                let event: GroupStarted = data.into_val(env);
+                let group = self.groups.get_mut(&event.group_id).unwrap();
+                group.state = GroupState::Active;
+                group.cycle_start_time = event.cycle_start_time;
+            }
+            "owner" => {
+                let event: OwnershipTransferred = data.into_val(env);
+                self.groups.get_mut(&event.group_id).unwrap().creator = event.new_creator.clone();
+                self.co_organizers
+                    .entry(event.group_id)
+                    .or_default()
+                    .retain(|address| *address != event.new_creator);
+            }
+            "metadata" => {
+                let event: MetadataUpdated = data.into_val(env);
+                self.metadata.insert(event.group_id, event.metadata);
+            }
+            "orgadd" => {
+                let event: CoOrganizerAdded = data.into_val(env);
+                let list = self.co_organizers.entry(event.group_id).or_default();
+                list.push(event.co_organizer);
+            }
+            "orgrem" => {
+                let event: CoOrganizerRemoved = data.into_val(env);
+                let list = self.co_organizers.entry(event.group_id).or_default();
+                list.retain(|address| *address != event.co_organizer);
+            }
+            "insdep" => {
+                let event: InsuranceDeposited = data.into_val(env);
+                self.insurance_pools.insert(event.token, event.pool_balance);
+            }
+            "claimfile" => {
+                let event: ClaimFiled = data.into_val(env);
+                self.claims.insert(event.claim.id, event.claim);
+            }
+            "claimproc" => {
+                let event: ClaimProcessed = data.into_val(env);
+                let claim = event.claim;
+                if claim.status == ClaimStatus::Paid {
+                    let token = self.groups[&claim.group_id].token_address.clone();
+                    *self.insurance_pools.get_mut(&token).unwrap() -= claim.amount;
+                }
+                self.claims.insert(claim.id, claim);
+            }
+            "tplsaved" => {
+                let event: TemplateSaved = data.into_val(env);
+                self.templates.insert(event.template.id, event.template);
+            }
+            "tplremove" => {
+                let event: TemplateRemoved = data.into_val(env);
+                self.templates.remove(&event.template_id);
+            }
+            "pause" => {
+                let event: PauseChanged = data.into_val(env);
+                self.pause_state = event.state;
+            }
+            "platfee" => {
+                let event: PlatformFeeSet = data.into_val(env);
+                self.platform_fee_bps = event.fee_bps;
+            }
+            _ => {}
+        }
+    }
+
+    /// Reads the `group_id` field that group-level events without other
+    /// replayed state carry
+    fn group_id(&self, env: &Env, data: Val) -> u64 {
+        let fields: soroban_sdk::Map<Symbol, Val> = data.into_val(env);
+        fields
+            .get(Symbol::new(env, "group_id"))
+            .unwrap()
+            .into_val(env)
+    }
+}
+
+/// Replays every event the contract has emitted into a fresh model
+fn replay(env: &Env, contract_id: &Address) -> Model {
+    let mut model = Model::default();
+    for (address, topics, data) in env.events().all().iter() {
+        if address != *contract_id {
+            continue;
+        }
+        let name: Symbol = topics.get(0).unwrap().into_val(env);
+        let version: u32 = topics.get(1).unwrap().into_val(env);
+        assert_eq!(version, EVENT_VERSION);
+        model.apply(env, &name.to_string(), data);
+    }
+    model
+}
+
+/// Helper function to move the ledger clock forward
+fn advance(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += seconds;
+    });
+}
+
+#[test]
+fn test_replayed_events_match_storage() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_platform_fee(&100u32);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let dave = Address::generate(&env);
+    for addr in [&alice, &bob, &carol, &dave] {
+        StellarAssetClient::new(&env, &token).mint(addr, &1_000_000_000_000);
+    }
+
+    // An insured group where bob holds two seats and carol prepays
+    let first = client.create_group(
+        &alice,
+        &token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(CYCLE),
+        &4u32,
+        &GRACE,
+        &5u32,
+        &100u32,
+        &0u32,
+    );
+    client.join_group(&bob, &first, &2u32);
+    client.join_group(&carol, &first, &1u32);
+    let text = String::from_str(&env, "Market ajo");
+    client.set_group_metadata(&alice, &first, &text, &text, &text);
+    client.add_co_organizer(&first, &carol);
+    client.add_co_organizer(&first, &dave);
+    client.remove_co_organizer(&first, &dave);
+
+    client.contribute(&alice, &first);
+    client.contribute(&bob, &first);
+    client.contribute_amount(&carol, &first, &(CONTRIBUTION * 2));
+    advance(&env, CYCLE + GRACE + 1);
+    client.execute_payout(&first);
+
+    // Carol's credit already covers cycle two; alice pays late
+    client.freeze_group(&alice, &first);
+    advance(&env, 3600);
+    client.start_group(&carol, &first);
+    client.contribute(&bob, &first);
+    advance(&env, CYCLE + 3600);
+    client.contribute(&alice, &first);
+    advance(&env, GRACE);
+    client.execute_payout(&first);
+
+    let claim_id = client.file_insurance_claim(&bob, &first, &2u32, &carol, &1_000i128);
+    client.process_insurance_claim(&admin, &claim_id, &true);
+    client.transfer_group_ownership(&first, &carol);
+    client.accept_group_ownership(&carol, &first);
+
+    // A group cancelled with a refund
+    let second = client.create_group(
+        &bob,
+        &token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(CYCLE),
+        &2u32,
+        &GRACE,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&dave, &second, &1u32);
+    client.contribute(&dave, &second);
+    client.cancel_group(&bob, &second);
+
+    // A group made from a template, and a removed template
+    let params = TemplateParams {
+        token_address: token.clone(),
+        contribution_amount: CONTRIBUTION,
+        schedule: CycleSchedule::Fixed(CYCLE),
+        max_members: 5,
+        grace_period: GRACE,
+        penalty_rate: 5,
+        insurance_rate_bps: 0,
+        organizer_fee_bps: 0,
+    };
+    let template_id = client.publish_template(&text, &params, &text);
+    let removed = client.save_template(&dave, &text, &params, &text);
+    client.remove_template(&dave, &removed);
+    let third =
+        client.create_group_from_template(&dave, &template_id, &TemplateOverrides::default());
+    client.pause_operation(&PauseFlag::Joins);
+
+    let model = replay(&env, &contract_id);
+
+    assert_eq!(model.groups.len(), 3);
+    for (group_id, group) in &model.groups {
+        assert_eq!(*group, client.get_group(group_id));
+        let co_organizers = model
+            .co_organizers
+            .get(group_id)
+            .cloned()
+            .unwrap_or_default();
+        let stored: std::vec::Vec<Address> = client.get_co_organizers(group_id).iter().collect();
+        assert_eq!(co_organizers, stored);
+        let seats: std::vec::Vec<Address> = client
+            .get_payout_schedule(group_id)
+            .iter()
+            .map(|position| position.member)
+            .collect();
+        assert_eq!(model.seats[group_id], seats);
+        for member in client.list_members(group_id).iter() {
+            let credit = model.credit.get(&(*group_id, member.clone()));
+            let stored = client.get_member_credit(group_id, &member);
+            assert_eq!(credit.copied().unwrap_or_default(), stored);
+        }
+    }
+    for (group_id, metadata) in &model.metadata {
+        assert_eq!(*metadata, client.get_group_metadata(group_id));
+    }
+    assert!(model.metadata.contains_key(&third));
+    assert_eq!(model.groups[&first].creator, carol);
+
+    for ((group_id, cycle, member), record) in &model.contributions {
+        assert_eq!(
+            *record,
+            client.get_contribution_detail(group_id, cycle, member)
+        );
+    }
+    assert_eq!(model.contributions.len(), 7);
+
+    let mut payouts = Vec::new(&env);
+    for record in model.payouts.values() {
+        payouts.push_back(record.clone());
+    }
+    assert_eq!(payouts, client.get_payout_history(&first, &1, &10));
+
+    for ((group_id, member), record) in &model.refunds {
+        assert_eq!(*record, client.get_refund_record(group_id, member));
+    }
+    assert_eq!(model.refunds.len(), 1);
+
+    assert_eq!(model.treasury[&token], client.get_treasury_balance(&token));
+    assert_eq!(
+        model.insurance_pools[&token],
+        client.get_insurance_pool(&token).balance
+    );
+    assert_eq!(
+        model.claims[&claim_id],
+        client.get_insurance_claim(&claim_id)
+    );
+
+    let stored: std::vec::Vec<u64> = client
+        .list_templates(&0, &10)
+        .iter()
+        .map(|template| template.id)
+        .collect();
+    assert_eq!(
+        model
+            .templates
+            .keys()
+            .copied()
+            .collect::<std::vec::Vec<_>>(),
+        stored
+    );
+    assert_eq!(
+        model.templates[&template_id],
+        client.get_template(&template_id)
+    );
+
+    assert_eq!(model.pause_state, client.get_pause_state());
+    assert_eq!(model.platform_fee_bps, client.get_platform_fee());
+}
@@ -0,0 +1,201 @@
+#![cfg(test)]
+
+//! Tests for platform and organizer fees and the fee treasury
+
+use soroban_ajo::events::{PayoutExecuted, EVENT_VERSION};
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, CycleSchedule};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    Address, Env, IntoVal,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+
+/// Helper function to create an initialized contract and a funded token
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+
+    (env, client, admin, token)
+}
+
+/// Helper function to create a two-member group that is ready for payout
+fn setup_payable_group(
+    env: &Env,
+    client: &AjoContractClient,
+    token: &Address,
+    organizer_fee_bps: u32,
+) -> (u64, Address, Address) {
+    let creator = Address::generate(env);
+    let member = Address::generate(env);
+    for addr in [&creator, &member] {
+        StellarAssetClient::new(env, token).mint(addr, &1_000_000_000_000);
+    }
+
+    let group_id = client.create_group(
+        &creator,
+        token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &organizer_fee_bps,
+    );
+    client.join_group(&member, &group_id, &1u32);
+    client.contribute(&creator, &group_id);
+    client.contribute(&member, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 86400 + 1;
+    });
+
+    (group_id, creator, member)
+}
+
+#[test]
+fn test_fees_taken_from_payout() {
+    let (env, client, _admin, token) = setup_test_env();
+    let token_client = TokenClient::new(&env, &token);
+    client.set_platform_fee(&100u32);
+
+    let (group_id, creator, _member) = setup_payable_group(&env, &client, &token, 200);
+    let balance_before = token_client.balance(&creator);
+    client.execute_payout(&group_id);
+
+    // 1% platform fee and 2% organizer fee on a 200_000_000 pot
+    assert_eq!(token_client.balance(&creator), balance_before + 194_000_000);
+    assert_eq!(client.get_treasury_balance(&token), 2_000_000);
+    assert_eq!(client.get_organizer_fees(&creator, &token), 4_000_000);
+}
+
+#[test]
+fn test_payout_event_includes_fees() {
+    let (env, client, _admin, token) = setup_test_env();
+    client.set_platform_fee(&100u32);
+
+    let (group_id, creator, _member) = setup_payable_group(&env, &client, &token, 200);
+    client.execute_payout(&group_id);
+
+    let topics = (symbol_short!("payout"), EVENT_VERSION, group_id).into_val(&env);
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, event_topics, _)| *event_topics == topics)
+        .unwrap();
+    let event: PayoutExecuted = data.into_val(&env);
+    let record = event.record;
+    assert_eq!(record.cycle, 1);
+    assert_eq!(
+        (
+            record.member,
+            record.amount,
+            record.platform_fee,
+            record.organizer_fee
+        ),
+        (creator, 194_000_000, 2_000_000, 4_000_000)
+    );
+}
+
+#[test]
+fn test_combined_fee_is_capped() {
+    let (env, client, _admin, token) = setup_test_env();
+    let creator = Address::generate(&env);
+
+    let result = client.try_set_platform_fee(&1_001u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidFee)));
+
+    client.set_platform_fee(&600u32);
+    let result = client.try_create_group(
+        &creator,
+        &token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &401u32,
+    );
+    assert_eq!(result, Err(Ok(AjoError::InvalidFee)));
+}
+
+#[test]
+fn test_raised_platform_fee_trims_organizer_fee() {
+    let (env, client, _admin, token) = setup_test_env();
+
+    let (group_id, creator, _member) = setup_payable_group(&env, &client, &token, 500);
+    client.set_platform_fee(&800u32);
+    client.execute_payout(&group_id);
+
+    assert_eq!(client.get_treasury_balance(&token), 16_000_000);
+    assert_eq!(client.get_organizer_fees(&creator, &token), 4_000_000);
+}
+
+#[test]
+fn test_fee_manager_withdraws_treasury() {
+    let (env, client, admin, token) = setup_test_env();
+    let token_client = TokenClient::new(&env, &token);
+    client.set_platform_fee(&100u32);
+
+    let (group_id, _creator, _member) = setup_payable_group(&env, &client, &token, 0);
+    client.execute_payout(&group_id);
+
+    // The admin is the fee manager until another is appointed
+    assert_eq!(client.get_fee_manager(), Some(admin));
+    let manager = Address::generate(&env);
+    client.set_fee_manager(&manager);
+    assert_eq!(client.get_fee_manager(), Some(manager));
+
+    let treasury = Address::generate(&env);
+    assert_eq!(client.withdraw_fees(&token, &treasury), 2_000_000);
+    assert_eq!(token_client.balance(&treasury), 2_000_000);
+    assert_eq!(client.get_treasury_balance(&token), 0);
+    assert_eq!(client.withdraw_fees(&token, &treasury), 0);
+}
+
+#[test]
+fn test_organizer_withdraws_own_fees() {
+    let (env, client, _admin, token) = setup_test_env();
+    let token_client = TokenClient::new(&env, &token);
+
+    let (group_id, creator, member) = setup_payable_group(&env, &client, &token, 300);
+    client.execute_payout(&group_id);
+
+    assert_eq!(client.withdraw_organizer_fees(&member, &token, &member), 0);
+
+    let destination = Address::generate(&env);
+    let withdrawn = client.withdraw_organizer_fees(&creator, &token, &destination);
+    assert_eq!(withdrawn, 6_000_000);
+    assert_eq!(token_client.balance(&destination), 6_000_000);
+    assert_eq!(client.get_organizer_fees(&creator, &token), 0);
+}
+
+#[test]
+fn test_fee_admin_requires_auth() {
+    let (env, client, _admin, token) = setup_test_env();
+
+    env.set_auths(&[]);
+    assert!(client.try_set_platform_fee(&100u32).is_err());
+    assert!(client
+        .try_set_fee_manager(&Address::generate(&env))
+        .is_err());
+    assert!(client
+        .try_withdraw_fees(&token, &Address::generate(&env))
+        .is_err());
+}
@@ -5,23 +5,46 @@
 //! These tests verify the complete flow from group creation through completion,
 //! including multiple groups and failure scenarios.
 
-use soroban_ajo::{AjoContract, AjoContractClient, AjoError};
-use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env};
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, CycleSchedule};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env,
+};
 
 /// Helper function to create a test environment and contract
-fn setup_test_env() -> (Env, AjoContractClient<'static>) {
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Address) {
     let env = Env::default();
     env.mock_all_auths();
 
+    // Lifecycle tests make many calls; budget usage is covered elsewhere
+    env.budget().reset_unlimited();
+
     let contract_id = env.register_contract(None, AjoContract);
     let client = AjoContractClient::new(&env, &contract_id);
 
-    (env, client)
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    // The contract only accepts tokens registered by the admin
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+
+    (env, client, token)
 }
 
-/// Helper function to generate multiple test addresses
-fn generate_addresses(env: &Env, count: usize) -> Vec<Address> {
-    (0..count).map(|_| Address::generate(env)).collect()
+/// Helper function to generate multiple funded test addresses
+fn generate_addresses(env: &Env, token: &Address, count: usize) -> Vec<Address> {
+    (0..count)
+        .map(|_| {
+            let address = Address::generate(env);
+            StellarAssetClient::new(env, token).mint(&address, &1_000_000_000_000_000);
+            address
+        })
+        .collect()
 }
 
 /// Helper function to run a complete cycle (all members contribute + payout)
@@ -29,19 +52,19 @@ fn complete_cycle(env: &Env, client: &AjoContractClient, group_id: &u64, members
     for member in members {
         client.contribute(member, group_id);
     }
-    
+
     // Advance time past grace period to allow payout
     env.ledger().with_mut(|li| {
         li.timestamp = li.timestamp + 604_800 + 86400 + 1;
     });
-    
+
     client.execute_payout(group_id);
 }
 
 #[test]
 fn test_full_lifecycle_create_join_contribute_payout_complete() {
-    let (env, client) = setup_test_env();
-    let members = generate_addresses(&env, 5);
+    let (env, client, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 5);
 
     // Step 1: Create group
     let creator = &members[0];
@@ -49,19 +72,29 @@ fn test_full_lifecycle_create_join_contribute_payout_complete() {
     let cycle_duration = 604_800u64; // 1 week
     let max_members = 5u32;
 
-    let group_id = client.create_group(creator, &contribution, &cycle_duration, &max_members, &86400u64, &5u32);
+    let group_id = client.create_group(
+        creator,
+        &token,
+        &contribution,
+        &CycleSchedule::Fixed(cycle_duration),
+        &max_members,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     assert_eq!(group_id, 1);
 
     // Verify initial state
     let group = client.get_group(&group_id);
-    assert_eq!(group.members.len(), 1);
+    assert_eq!(group.member_count, 1);
     assert_eq!(group.current_cycle, 1);
     assert_eq!(group.payout_index, 0);
-    assert_eq!(group.is_complete, false);
+    assert!(!group.is_complete);
 
     // Step 2: Join - remaining members join the group
     for member in &members[1..] {
-        client.join_group(member, &group_id);
+        client.join_group(member, &group_id, &1u32);
     }
 
     // Verify all members joined
@@ -69,7 +102,7 @@ fn test_full_lifecycle_create_join_contribute_payout_complete() {
     assert_eq!(member_list.len(), 5);
 
     for member in &members {
-        assert_eq!(client.is_member(&group_id, member), true);
+        assert!(client.is_member(&group_id, member));
     }
 
     // Step 3: Contribute & Payout - Complete all cycles
@@ -83,7 +116,7 @@ fn test_full_lifecycle_create_join_contribute_payout_complete() {
         let status = client.get_contribution_status(&group_id, &(cycle as u32));
         assert_eq!(status.len(), 5);
         for (_, has_paid) in status.iter() {
-            assert_eq!(has_paid, true);
+            assert!(has_paid);
         }
 
         // Execute payout
@@ -97,50 +130,70 @@ fn test_full_lifecycle_create_join_contribute_payout_complete() {
         if cycle < 5 {
             assert_eq!(group.current_cycle, (cycle + 1) as u32);
             assert_eq!(group.payout_index, cycle as u32);
-            assert_eq!(group.is_complete, false);
+            assert!(!group.is_complete);
         } else {
             // Step 4: Complete - After final cycle
-            assert_eq!(group.is_complete, true);
+            assert!(group.is_complete);
             assert_eq!(group.payout_index, 5);
         }
     }
 
     // Verify final completion state
-    assert_eq!(client.is_complete(&group_id), true);
+    assert!(client.is_complete(&group_id));
 }
 
 #[test]
 fn test_multiple_groups_independent_lifecycle() {
-    let (env, client) = setup_test_env();
+    let (env, client, token) = setup_test_env();
 
     // Create addresses for different groups
-    let group1_members = generate_addresses(&env, 3);
-    let group2_members = generate_addresses(&env, 4);
+    let group1_members = generate_addresses(&env, &token, 3);
+    let group2_members = generate_addresses(&env, &token, 4);
 
     // Create Group 1 (3 members, 10 XLM contribution)
-    let group_id1 = client.create_group(&group1_members[0], &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
+    let group_id1 = client.create_group(
+        &group1_members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
 
     // Create Group 2 (4 members, 20 XLM contribution)
-    let group_id2 = client.create_group(&group2_members[0], &200_000_000i128, &1_209_600u64, &4u32, &86400u64, &5u32);
+    let group_id2 = client.create_group(
+        &group2_members[0],
+        &token,
+        &200_000_000i128,
+        &CycleSchedule::Fixed(1_209_600u64),
+        &4u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
 
     assert_eq!(group_id1, 1);
     assert_eq!(group_id2, 2);
 
     // Join members to respective groups
     for member in &group1_members[1..] {
-        client.join_group(member, &group_id1);
+        client.join_group(member, &group_id1, &1u32);
     }
 
     for member in &group2_members[1..] {
-        client.join_group(member, &group_id2);
+        client.join_group(member, &group_id2, &1u32);
     }
 
     // Verify groups are independent
     let g1 = client.get_group(&group_id1);
     let g2 = client.get_group(&group_id2);
 
-    assert_eq!(g1.members.len(), 3);
-    assert_eq!(g2.members.len(), 4);
+    assert_eq!(g1.member_count, 3);
+    assert_eq!(g2.member_count, 4);
     assert_eq!(g1.contribution_amount, 100_000_000i128);
     assert_eq!(g2.contribution_amount, 200_000_000i128);
 
@@ -159,33 +212,53 @@ fn test_multiple_groups_independent_lifecycle() {
         complete_cycle(&env, &client, &group_id1, &group1_members);
     }
 
-    assert_eq!(client.is_complete(&group_id1), true);
-    assert_eq!(client.is_complete(&group_id2), false);
+    assert!(client.is_complete(&group_id1));
+    assert!(!client.is_complete(&group_id2));
 
     // Verify group 2 is still at cycle 1 and independent
     let g2_final = client.get_group(&group_id2);
     assert_eq!(g2_final.current_cycle, 1);
-    assert_eq!(g2_final.is_complete, false);
+    assert!(!g2_final.is_complete);
 }
 
 #[test]
 fn test_multiple_groups_with_overlapping_members() {
-    let (env, client) = setup_test_env();
-    let members = generate_addresses(&env, 5);
+    let (env, client, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 5);
 
     // Create Group 1 with members 0, 1, 2
-    let group_id1 = client.create_group(&members[0], &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    client.join_group(&members[1], &group_id1);
-    client.join_group(&members[2], &group_id1);
+    let group_id1 = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&members[1], &group_id1, &1u32);
+    client.join_group(&members[2], &group_id1, &1u32);
 
     // Create Group 2 with members 1, 3, 4 (member 1 is in both groups)
-    let group_id2 = client.create_group(&members[1], &150_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    client.join_group(&members[3], &group_id2);
-    client.join_group(&members[4], &group_id2);
+    let group_id2 = client.create_group(
+        &members[1],
+        &token,
+        &150_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&members[3], &group_id2, &1u32);
+    client.join_group(&members[4], &group_id2, &1u32);
 
     // Verify member 1 is in both groups
-    assert_eq!(client.is_member(&group_id1, &members[1]), true);
-    assert_eq!(client.is_member(&group_id2, &members[1]), true);
+    assert!(client.is_member(&group_id1, &members[1]));
+    assert!(client.is_member(&group_id2, &members[1]));
 
     // Member 1 can contribute to both groups independently
     client.contribute(&members[0], &group_id1);
@@ -201,7 +274,7 @@ fn test_multiple_groups_with_overlapping_members() {
         li.timestamp = li.timestamp + 604_800 + 86400 + 1;
     });
     client.execute_payout(&group_id1);
-    
+
     env.ledger().with_mut(|li| {
         li.timestamp = li.timestamp + 604_800 + 86400 + 1;
     });
@@ -216,13 +289,23 @@ fn test_multiple_groups_with_overlapping_members() {
 
 #[test]
 fn test_failure_scenario_incomplete_contributions() {
-    let (env, client) = setup_test_env();
-    let members = generate_addresses(&env, 4);
+    let (env, client, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 4);
 
     // Create group with 4 members
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &4u32, &86400u64, &5u32);
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &4u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     for member in &members[1..] {
-        client.join_group(member, &group_id);
+        client.join_group(member, &group_id, &1u32);
     }
 
     // Only 3 out of 4 members contribute
@@ -243,12 +326,22 @@ fn test_failure_scenario_incomplete_contributions() {
 
 #[test]
 fn test_failure_scenario_double_contribution() {
-    let (env, client) = setup_test_env();
-    let members = generate_addresses(&env, 3);
-
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
+    let (env, client, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 3);
+
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     for member in &members[1..] {
-        client.join_group(member, &group_id);
+        client.join_group(member, &group_id, &1u32);
     }
 
     // First contribution succeeds
@@ -261,16 +354,26 @@ fn test_failure_scenario_double_contribution() {
 
 #[test]
 fn test_failure_scenario_join_after_max_members() {
-    let (env, client) = setup_test_env();
-    let members = generate_addresses(&env, 4);
+    let (env, client, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 4);
 
     // Create group with max 3 members
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
-    client.join_group(&members[1], &group_id);
-    client.join_group(&members[2], &group_id);
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&members[1], &group_id, &1u32);
+    client.join_group(&members[2], &group_id, &1u32);
 
     // Fourth member tries to join
-    let result = client.try_join_group(&members[3], &group_id);
+    let result = client.try_join_group(&members[3], &group_id, &1u32);
     assert_eq!(result, Err(Ok(AjoError::MaxMembersExceeded)));
 
     // Verify member count is still 3
@@ -280,18 +383,28 @@ fn test_failure_scenario_join_after_max_members() {
 
 #[test]
 fn test_failure_scenario_contribute_after_completion() {
-    let (env, client) = setup_test_env();
-    let members = generate_addresses(&env, 2);
-
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &2u32, &86400u64, &5u32);
-    client.join_group(&members[1], &group_id);
+    let (env, client, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 2);
+
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&members[1], &group_id, &1u32);
 
     // Complete all cycles
     for _ in 0..2 {
         complete_cycle(&env, &client, &group_id, &members);
     }
 
-    assert_eq!(client.is_complete(&group_id), true);
+    assert!(client.is_complete(&group_id));
 
     // Try to contribute to completed group
     let result = client.try_contribute(&members[0], &group_id);
@@ -300,11 +413,21 @@ fn test_failure_scenario_contribute_after_completion() {
 
 #[test]
 fn test_failure_scenario_non_member_contribution() {
-    let (env, client) = setup_test_env();
-    let members = generate_addresses(&env, 3);
+    let (env, client, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 3);
     let non_member = Address::generate(&env);
 
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
 
     // Non-member tries to contribute
     let result = client.try_contribute(&non_member, &group_id);
@@ -313,47 +436,107 @@ fn test_failure_scenario_non_member_contribution() {
 
 #[test]
 fn test_failure_scenario_join_already_member() {
-    let (env, client) = setup_test_env();
-    let members = generate_addresses(&env, 2);
-
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &5u32, &86400u64, &5u32);
-    client.join_group(&members[1], &group_id);
+    let (env, client, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 2);
+
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &5u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&members[1], &group_id, &1u32);
 
     // Try to join again
-    let result = client.try_join_group(&members[1], &group_id);
+    let result = client.try_join_group(&members[1], &group_id, &1u32);
     assert_eq!(result, Err(Ok(AjoError::AlreadyMember)));
 }
 
 #[test]
 fn test_failure_scenario_invalid_group_creation() {
-    let (env, client) = setup_test_env();
+    let (env, client, token) = setup_test_env();
     let creator = Address::generate(&env);
 
     // Zero contribution amount
-    let result = client.try_create_group(&creator, &0i128, &604_800u64, &5u32, &86400u64, &5u32);
+    let result = client.try_create_group(
+        &creator,
+        &token,
+        &0i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &5u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     assert_eq!(result, Err(Ok(AjoError::ContributionAmountZero)));
 
     // Negative contribution amount
-    let result = client.try_create_group(&creator, &-100i128, &604_800u64, &5u32, &86400u64, &5u32);
+    let result = client.try_create_group(
+        &creator,
+        &token,
+        &-100i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &5u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     assert_eq!(result, Err(Ok(AjoError::ContributionAmountNegative)));
 
     // Zero cycle duration
-    let result = client.try_create_group(&creator, &100_000_000i128, &0u64, &5u32, &86400u64, &5u32);
+    let result = client.try_create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(0u64),
+        &5u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     assert_eq!(result, Err(Ok(AjoError::CycleDurationZero)));
 
     // Max members below minimum (less than 2)
-    let result = client.try_create_group(&creator, &100_000_000i128, &604_800u64, &1u32, &86400u64, &5u32);
+    let result = client.try_create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &1u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     assert_eq!(result, Err(Ok(AjoError::MaxMembersBelowMinimum)));
 }
 
 #[test]
 fn test_complex_scenario_partial_completion_with_recovery() {
-    let (env, client) = setup_test_env();
-    let members = generate_addresses(&env, 3);
-
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
+    let (env, client, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 3);
+
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     for member in &members[1..] {
-        client.join_group(member, &group_id);
+        client.join_group(member, &group_id, &1u32);
     }
 
     // Complete first cycle successfully
@@ -380,20 +563,30 @@ fn test_complex_scenario_partial_completion_with_recovery() {
 
     // Complete final cycle
     complete_cycle(&env, &client, &group_id, &members);
-    assert_eq!(client.is_complete(&group_id), true);
+    assert!(client.is_complete(&group_id));
 }
 
 #[test]
 fn test_large_group_full_lifecycle() {
-    let (env, client) = setup_test_env();
-    let members = generate_addresses(&env, 10);
+    let (env, client, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 10);
 
     // Create group with 10 members
-    let group_id = client.create_group(&members[0], &50_000_000i128, &604_800u64, &10u32, &86400u64, &5u32);
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &50_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &10u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
 
     // All members join
     for member in &members[1..] {
-        client.join_group(member, &group_id);
+        client.join_group(member, &group_id, &1u32);
     }
 
     // Verify all joined
@@ -406,36 +599,56 @@ fn test_large_group_full_lifecycle() {
         let group = client.get_group(&group_id);
         if cycle < 10 {
             assert_eq!(group.current_cycle, (cycle + 1) as u32);
-            assert_eq!(group.is_complete, false);
+            assert!(!group.is_complete);
         } else {
-            assert_eq!(group.is_complete, true);
+            assert!(group.is_complete);
         }
     }
 
-    assert_eq!(client.is_complete(&group_id), true);
+    assert!(client.is_complete(&group_id));
 }
 
 #[test]
 fn test_sequential_group_creation_and_completion() {
-    let (env, client) = setup_test_env();
-    let members = generate_addresses(&env, 3);
+    let (env, client, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 3);
 
     // Create and complete first group
-    let group_id1 = client.create_group(&members[0], &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
+    let group_id1 = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     for member in &members[1..] {
-        client.join_group(member, &group_id1);
+        client.join_group(member, &group_id1, &1u32);
     }
 
     for _ in 0..3 {
         complete_cycle(&env, &client, &group_id1, &members);
     }
 
-    assert_eq!(client.is_complete(&group_id1), true);
+    assert!(client.is_complete(&group_id1));
 
     // Create second group with same members
-    let group_id2 = client.create_group(&members[0], &150_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
+    let group_id2 = client.create_group(
+        &members[0],
+        &token,
+        &150_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     for member in &members[1..] {
-        client.join_group(member, &group_id2);
+        client.join_group(member, &group_id2, &1u32);
     }
 
     // Second group should be independent and functional
@@ -443,8 +656,8 @@ fn test_sequential_group_creation_and_completion() {
 
     let g2 = client.get_group(&group_id2);
     assert_eq!(g2.current_cycle, 2);
-    assert_eq!(g2.is_complete, false);
+    assert!(!g2.is_complete);
 
     // First group should still be complete
-    assert_eq!(client.is_complete(&group_id1), true);
+    assert!(client.is_complete(&group_id1));
 }
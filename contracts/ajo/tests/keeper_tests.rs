@@ -0,0 +1,283 @@
+#![cfg(test)]
+
+//! Tests for the permissionless `poke` driver and keeper rewards
+
+use soroban_ajo::events::DefaultsMarked;
+use soroban_ajo::{
+    AjoContract, AjoContractClient, AjoError, CycleSchedule, GroupState, PauseFlag, PokeAction,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    vec, Address, Env, IntoVal,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+
+/// Helper function to create a contract with a 1% platform fee and a registered token
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    TokenClient<'static>,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_platform_fee(&100u32);
+
+    let token_admin = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_supported_token(&token_id, &1i128, &i128::MAX);
+    let token = TokenClient::new(&env, &token_id);
+
+    let keeper = Address::generate(&env);
+
+    (env, client, token, keeper)
+}
+
+/// Helper function to create a funded two-member group
+fn setup_group(
+    env: &Env,
+    client: &AjoContractClient,
+    token: &TokenClient,
+) -> (u64, Address, Address) {
+    let creator = Address::generate(env);
+    let member = Address::generate(env);
+    for addr in [&creator, &member] {
+        StellarAssetClient::new(env, &token.address).mint(addr, &1_000_000_000_000);
+    }
+
+    let group_id = client.create_group(
+        &creator,
+        &token.address,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&member, &group_id, &1u32);
+
+    (group_id, creator, member)
+}
+
+/// Helper function to move past the current cycle's grace period
+fn advance_past_grace(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 86400 + 1;
+    });
+}
+
+#[test]
+fn test_poke_executes_due_payout_and_rewards_keeper() {
+    let (env, client, token, keeper) = setup_test_env();
+    client.set_keeper_reward(&5_000u32);
+    let (group_id, creator, member) = setup_group(&env, &client, &token);
+    client.contribute(&creator, &group_id);
+    client.contribute(&member, &group_id);
+
+    // Nothing is due before the grace period ends
+    let result = client.poke(&keeper, &group_id);
+    assert_eq!(result.group_id, group_id);
+    assert!(result.actions.is_empty());
+    assert_eq!(client.get_group(&group_id).payout_index, 0);
+
+    advance_past_grace(&env);
+    let result = client.poke(&keeper, &group_id);
+    assert_eq!(result.actions, vec![&env, PokeAction::PayoutExecuted(1)]);
+
+    // Half of the 2_000_000 platform fee goes to the keeper
+    assert_eq!(result.keeper_reward, 1_000_000);
+    assert_eq!(token.balance(&keeper), 1_000_000);
+    assert_eq!(client.get_treasury_balance(&token.address), 1_000_000);
+    assert_eq!(client.get_group(&group_id).payout_index, 1);
+}
+
+#[test]
+fn test_poke_collects_auto_debits() {
+    let (env, client, token, keeper) = setup_test_env();
+    let (group_id, creator, member) = setup_group(&env, &client, &token);
+
+    let expiration = env.ledger().sequence() + 1_000;
+    for addr in [&creator, &member] {
+        client.enable_auto_debit(addr, &group_id);
+    }
+    token.approve(&creator, &client.address, &CONTRIBUTION, &expiration);
+
+    // The member without an allowance is reported but not counted
+    let result = client.poke(&keeper, &group_id);
+    assert_eq!(
+        result.actions,
+        vec![&env, PokeAction::ContributionsCollected(1)]
+    );
+    assert_eq!(result.keeper_reward, 0);
+
+    // Collected late, inside the grace period, with the penalty
+    token.approve(&member, &client.address, &(CONTRIBUTION * 2), &expiration);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 3600;
+    });
+    let result = client.poke(&keeper, &group_id);
+    assert_eq!(
+        result.actions,
+        vec![&env, PokeAction::ContributionsCollected(1)]
+    );
+
+    // Once the grace period is over the payout follows
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400;
+    });
+    let result = client.poke(&keeper, &group_id);
+    assert_eq!(result.actions, vec![&env, PokeAction::PayoutExecuted(1)]);
+}
+
+#[test]
+fn test_poke_completes_group() {
+    let (env, client, token, keeper) = setup_test_env();
+    let (group_id, creator, member) = setup_group(&env, &client, &token);
+
+    for cycle in 1..=2u32 {
+        client.contribute(&creator, &group_id);
+        client.contribute(&member, &group_id);
+        advance_past_grace(&env);
+        let result = client.poke(&keeper, &group_id);
+        assert_eq!(
+            result.actions.get(0),
+            Some(PokeAction::PayoutExecuted(cycle))
+        );
+    }
+
+    let group = client.get_group(&group_id);
+    assert!(group.is_complete);
+    assert_eq!(group.state, GroupState::Complete);
+
+    // Without a keeper reward set, the keeper is paid nothing
+    assert_eq!(token.balance(&keeper), 0);
+
+    // A completed group has nothing left to do
+    let result = client.poke(&keeper, &group_id);
+    assert!(result.actions.is_empty());
+}
+
+#[test]
+fn test_poke_reports_completion() {
+    let (env, client, token, keeper) = setup_test_env();
+    let (group_id, creator, member) = setup_group(&env, &client, &token);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member, &group_id);
+    advance_past_grace(&env);
+    client.execute_payout(&group_id);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member, &group_id);
+    advance_past_grace(&env);
+    let result = client.poke(&keeper, &group_id);
+    assert_eq!(
+        result.actions,
+        vec![&env, PokeAction::PayoutExecuted(2), PokeAction::Completed]
+    );
+}
+
+#[test]
+fn test_poke_skips_paused_steps() {
+    let (env, client, token, keeper) = setup_test_env();
+    let (group_id, creator, member) = setup_group(&env, &client, &token);
+    client.contribute(&creator, &group_id);
+    client.contribute(&member, &group_id);
+    advance_past_grace(&env);
+
+    client.pause_operation(&PauseFlag::Payouts);
+    let result = client.poke(&keeper, &group_id);
+    assert!(result.actions.is_empty());
+    assert_eq!(client.get_group(&group_id).payout_index, 0);
+
+    client.unpause_operation(&PauseFlag::Payouts);
+    let result = client.poke(&keeper, &group_id);
+    assert_eq!(result.actions, vec![&env, PokeAction::PayoutExecuted(1)]);
+}
+
+#[test]
+fn test_poke_marks_defaults_after_grace() {
+    let (env, client, token, keeper) = setup_test_env();
+    let (group_id, creator, member) = setup_group(&env, &client, &token);
+    client.contribute(&creator, &group_id);
+
+    // Nothing is marked while the member can still pay late
+    let result = client.poke(&keeper, &group_id);
+    assert!(result.actions.is_empty());
+
+    advance_past_grace(&env);
+    let result = client.poke(&keeper, &group_id);
+    assert_eq!(result.actions, vec![&env, PokeAction::DefaultsMarked(1)]);
+    assert_eq!(result.keeper_reward, 0);
+
+    let (_, _, data) = env.events().all().last().unwrap();
+    let event: DefaultsMarked = data.into_val(&env);
+    assert_eq!(event.group_id, group_id);
+    assert_eq!(event.cycle, 1);
+    assert_eq!(event.defaulters, vec![&env, member]);
+
+    // A cycle is marked once, and the payout stays blocked
+    let result = client.poke(&keeper, &group_id);
+    assert!(result.actions.is_empty());
+    assert_eq!(client.get_group(&group_id).payout_index, 0);
+}
+
+#[test]
+fn test_poke_many_drives_each_group() {
+    let (env, client, token, keeper) = setup_test_env();
+    client.set_keeper_reward(&10_000u32);
+    let (first, creator1, member1) = setup_group(&env, &client, &token);
+    let (second, _creator2, _member2) = setup_group(&env, &client, &token);
+    client.contribute(&creator1, &first);
+    client.contribute(&member1, &first);
+    advance_past_grace(&env);
+
+    // The call stops after the first group with a transition
+    let results = client.poke_many(&keeper, &vec![&env, first, second]);
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results.get(0).unwrap().actions,
+        vec![&env, PokeAction::PayoutExecuted(1)]
+    );
+    assert_eq!(token.balance(&keeper), 2_000_000);
+
+    // A group with nothing due is passed over, and the unpaid group's members
+    // are marked in default
+    let (third, _creator3, _member3) = setup_group(&env, &client, &token);
+    let results = client.poke_many(&keeper, &vec![&env, third, second]);
+    assert_eq!(results.len(), 2);
+    assert!(results.get(0).unwrap().actions.is_empty());
+    assert_eq!(
+        results.get(1).unwrap().actions,
+        vec![&env, PokeAction::DefaultsMarked(2)]
+    );
+
+    let result = client.try_poke_many(&keeper, &vec![&env, first, second, third]);
+    assert_eq!(result, Err(Ok(AjoError::LimitExceeded)));
+
+    let result = client.try_poke_many(&keeper, &vec![&env, first, 99]);
+    assert_eq!(result, Err(Ok(AjoError::GroupNotFound)));
+}
+
+#[test]
+fn test_set_keeper_reward_bounds() {
+    let (_env, client, _token, _keeper) = setup_test_env();
+
+    let result = client.try_set_keeper_reward(&10_001u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidFee)));
+
+    client.set_keeper_reward(&2_500u32);
+    assert_eq!(client.get_keeper_reward(), 2_500);
+}
@@ -0,0 +1,196 @@
+#![cfg(test)]
+
+//! Tests for member obligations across groups
+
+use soroban_ajo::{AjoContract, AjoContractClient, CycleSchedule};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const GRACE: u64 = 86400;
+
+/// Helper function to create a contract, a registered token and three funded members
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    for addr in [&alice, &bob, &carol] {
+        StellarAssetClient::new(&env, &token).mint(addr, &1_000_000_000_000);
+    }
+
+    (env, client, token, alice, bob, carol)
+}
+
+/// Helper function to create a group of `creator` and the given members
+fn create_group(
+    client: &AjoContractClient,
+    token: &Address,
+    creator: &Address,
+    members: &[&Address],
+) -> u64 {
+    let group_id = client.create_group(
+        creator,
+        token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(CYCLE),
+        &(members.len() as u32 + 1),
+        &GRACE,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    for member in members {
+        client.join_group(member, &group_id, &1u32);
+    }
+    group_id
+}
+
+#[test]
+fn test_obligations_show_next_contribution_and_payout() {
+    let (env, client, token, alice, bob, carol) = setup_test_env();
+    let start = env.ledger().timestamp();
+    let group_id = create_group(&client, &token, &alice, &[&bob, &carol]);
+
+    let obligations = client.get_member_obligations(&carol, &0, &5);
+    assert_eq!(obligations.len(), 1);
+    let obligation = obligations.get(0).unwrap();
+    assert_eq!(obligation.group_id, group_id);
+    assert_eq!(obligation.token_address, token);
+    assert_eq!(obligation.cycle, 1);
+    assert_eq!(obligation.amount_due, CONTRIBUTION);
+    assert_eq!(obligation.deadline, start + CYCLE);
+    assert_eq!(obligation.grace_end, start + CYCLE + GRACE);
+    assert_eq!(obligation.payout_position, Some(2));
+    assert_eq!(
+        obligation.estimated_payout_at,
+        Some(start + 3 * CYCLE + GRACE)
+    );
+
+    // Once paid, the next cycle's contribution is the one due
+    client.contribute(&alice, &group_id);
+    let obligation = client
+        .get_member_obligations(&alice, &0, &5)
+        .get(0)
+        .unwrap();
+    assert_eq!(obligation.cycle, 2);
+    assert_eq!(obligation.amount_due, CONTRIBUTION);
+    assert_eq!(obligation.deadline, start + 2 * CYCLE);
+    assert_eq!(obligation.payout_position, Some(0));
+    assert_eq!(obligation.estimated_payout_at, Some(start + CYCLE + GRACE));
+
+    // Paying during the grace period includes the penalty
+    client.contribute(&bob, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + 3600;
+    });
+    let obligation = client
+        .get_member_obligations(&carol, &0, &5)
+        .get(0)
+        .unwrap();
+    assert_eq!(obligation.amount_due, CONTRIBUTION + CONTRIBUTION * 5 / 100);
+    client.contribute(&carol, &group_id);
+
+    // Once alice is paid out there is no payout left to wait for
+    env.ledger().with_mut(|li| {
+        li.timestamp += GRACE;
+    });
+    client.execute_payout(&group_id);
+    let obligation = client
+        .get_member_obligations(&alice, &0, &5)
+        .get(0)
+        .unwrap();
+    assert_eq!(obligation.cycle, 2);
+    assert_eq!(obligation.payout_position, None);
+    assert_eq!(obligation.estimated_payout_at, None);
+}
+
+#[test]
+fn test_obligations_cover_every_running_group() {
+    let (env, client, token, alice, bob, carol) = setup_test_env();
+    let first = create_group(&client, &token, &alice, &[&bob]);
+    let second = create_group(&client, &token, &bob, &[&carol]);
+    let third = create_group(&client, &token, &carol, &[&bob]);
+
+    let groups = |member: &Address| -> std::vec::Vec<u64> {
+        client
+            .get_member_obligations(member, &0, &5)
+            .iter()
+            .map(|obligation| obligation.group_id)
+            .collect()
+    };
+    assert_eq!(groups(&bob), [first, second, third]);
+    assert_eq!(groups(&carol), [second, third]);
+
+    // Cancelled groups are no longer owed anything
+    client.cancel_group(&bob, &second);
+    assert_eq!(groups(&bob), [first, third]);
+
+    let stranger = Address::generate(&env);
+    assert!(client.get_member_obligations(&stranger, &0, &5).is_empty());
+}
+
+#[test]
+fn test_obligations_are_paged_by_group_id() {
+    let (_env, client, token, alice, bob, carol) = setup_test_env();
+    let older = client.create_group(
+        &alice,
+        &token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(CYCLE),
+        &2u32,
+        &GRACE,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    let group_ids: std::vec::Vec<u64> = (0..6)
+        .map(|_| create_group(&client, &token, &bob, &[&carol]))
+        .collect();
+
+    // Joining an older group keeps the list in group ID order
+    client.join_group(&carol, &older, &1u32);
+
+    let page = |start_id: u64, limit: u32| -> std::vec::Vec<u64> {
+        client
+            .get_member_obligations(&carol, &start_id, &limit)
+            .iter()
+            .map(|obligation| obligation.group_id)
+            .collect()
+    };
+    assert_eq!(page(0, 2), [older, group_ids[0]]);
+    assert_eq!(page(group_ids[0] + 1, 2), [group_ids[1], group_ids[2]]);
+
+    // Pages are capped at five groups
+    assert_eq!(page(0, 50).len(), 5);
+    assert_eq!(page(group_ids[3] + 1, 50), [group_ids[4], group_ids[5]]);
+
+    // Finished groups are left out
+    client.cancel_group(&bob, &group_ids[4]);
+    assert_eq!(page(group_ids[3] + 1, 50), [group_ids[5]]);
+}
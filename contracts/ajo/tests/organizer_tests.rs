@@ -0,0 +1,234 @@
+#![cfg(test)]
+
+//! Tests for co-organizers, freezing and group ownership transfer
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, CycleSchedule, GroupState};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env,
+};
+
+/// Helper function to create a test environment with a three-seat group
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    u64,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let member3 = Address::generate(&env);
+
+    // Register the group token and fund the test addresses
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    // The contract only accepts tokens registered by the admin
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+    for addr in [&creator, &member2, &member3] {
+        StellarAssetClient::new(&env, &token).mint(addr, &1_000_000_000_000);
+    }
+
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
+    (env, client, group_id, creator, member2, member3)
+}
+
+#[test]
+fn test_add_and_remove_co_organizer() {
+    let (env, client, group_id, _creator, member2, _) = setup_test_env();
+    let co_organizer = Address::generate(&env);
+
+    client.add_co_organizer(&group_id, &co_organizer);
+    client.add_co_organizer(&group_id, &member2);
+
+    let co_organizers = client.get_co_organizers(&group_id);
+    assert_eq!(co_organizers.len(), 2);
+    assert_eq!(co_organizers.get(0).unwrap(), co_organizer);
+
+    client.remove_co_organizer(&group_id, &co_organizer);
+    let co_organizers = client.get_co_organizers(&group_id);
+    assert_eq!(co_organizers.len(), 1);
+    assert_eq!(co_organizers.get(0).unwrap(), member2);
+}
+
+#[test]
+fn test_add_co_organizer_rejects_duplicates_and_limit() {
+    let (env, client, group_id, creator, _, _) = setup_test_env();
+
+    let result = client.try_add_co_organizer(&group_id, &creator);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyMember)));
+
+    for _ in 0..5 {
+        client.add_co_organizer(&group_id, &Address::generate(&env));
+    }
+    let existing = client.get_co_organizers(&group_id).get(0).unwrap();
+    let result = client.try_add_co_organizer(&group_id, &existing);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyMember)));
+
+    let result = client.try_add_co_organizer(&group_id, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(AjoError::MaxMembersExceeded)));
+}
+
+#[test]
+fn test_remove_unknown_co_organizer() {
+    let (env, client, group_id, _, _, _) = setup_test_env();
+
+    let result = client.try_remove_co_organizer(&group_id, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(AjoError::NotMember)));
+}
+
+#[test]
+fn test_co_organizer_can_cancel() {
+    let (_env, client, group_id, creator, member2, _) = setup_test_env();
+
+    client.join_group(&member2, &group_id, &1u32);
+    client.add_co_organizer(&group_id, &member2);
+    client.contribute(&creator, &group_id);
+
+    client.cancel_group(&member2, &group_id);
+
+    let group = client.get_group(&group_id);
+    assert_eq!(group.state, GroupState::Cancelled);
+    assert_eq!(
+        client.get_refund_record(&group_id, &creator).amount,
+        100_000_000i128
+    );
+}
+
+#[test]
+fn test_freeze_blocks_activity_until_started() {
+    let (env, client, group_id, creator, member2, member3) = setup_test_env();
+    client.join_group(&member2, &group_id, &1u32);
+
+    client.freeze_group(&creator, &group_id);
+    assert_eq!(client.get_group(&group_id).state, GroupState::Frozen);
+
+    assert_eq!(
+        client.try_join_group(&member3, &group_id, &1u32),
+        Err(Ok(AjoError::GroupFrozen))
+    );
+    assert_eq!(
+        client.try_contribute(&creator, &group_id),
+        Err(Ok(AjoError::GroupFrozen))
+    );
+    assert_eq!(
+        client.try_freeze_group(&creator, &group_id),
+        Err(Ok(AjoError::GroupFrozen))
+    );
+
+    // Starting restarts the current cycle window
+    env.ledger().with_mut(|li| {
+        li.timestamp += 1_000_000;
+    });
+    client.start_group(&creator, &group_id);
+
+    let group = client.get_group(&group_id);
+    assert_eq!(group.state, GroupState::Active);
+    assert_eq!(group.cycle_start_time, env.ledger().timestamp());
+
+    client.join_group(&member3, &group_id, &1u32);
+    client.contribute(&creator, &group_id);
+}
+
+#[test]
+fn test_start_requires_frozen_group() {
+    let (_env, client, group_id, creator, _, _) = setup_test_env();
+
+    let result = client.try_start_group(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::InvalidGroupState)));
+}
+
+#[test]
+fn test_freeze_requires_organizer() {
+    let (_env, client, group_id, _creator, member2, _) = setup_test_env();
+    client.join_group(&member2, &group_id, &1u32);
+
+    let result = client.try_freeze_group(&member2, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+
+    client.add_co_organizer(&group_id, &member2);
+    client.freeze_group(&member2, &group_id);
+    client.start_group(&member2, &group_id);
+}
+
+#[test]
+fn test_frozen_group_can_be_cancelled() {
+    let (_env, client, group_id, creator, _, _) = setup_test_env();
+
+    client.contribute(&creator, &group_id);
+    client.freeze_group(&creator, &group_id);
+    client.cancel_group(&creator, &group_id);
+
+    assert_eq!(client.get_group(&group_id).state, GroupState::Cancelled);
+}
+
+#[test]
+fn test_transfer_ownership_requires_acceptance() {
+    let (env, client, group_id, creator, member2, _) = setup_test_env();
+    client.join_group(&member2, &group_id, &1u32);
+    client.add_co_organizer(&group_id, &member2);
+
+    client.transfer_group_ownership(&group_id, &member2);
+    assert_eq!(client.get_pending_owner(&group_id), Some(member2.clone()));
+
+    // Ownership does not move until accepted
+    assert_eq!(client.get_group(&group_id).creator, creator);
+
+    // Only the offered address can accept
+    let stranger = Address::generate(&env);
+    let result = client.try_accept_group_ownership(&stranger, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+
+    client.accept_group_ownership(&member2, &group_id);
+
+    let group = client.get_group(&group_id);
+    assert_eq!(group.creator, member2);
+    assert_eq!(client.get_pending_owner(&group_id), None);
+    assert_eq!(client.get_co_organizers(&group_id).len(), 0);
+
+    // The previous creator no longer has organizer powers
+    let result = client.try_freeze_group(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+    client.freeze_group(&member2, &group_id);
+}
+
+#[test]
+fn test_transfer_ownership_to_self_rejected() {
+    let (_env, client, group_id, creator, _, _) = setup_test_env();
+
+    let result = client.try_transfer_group_ownership(&group_id, &creator);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyMember)));
+}
+
+#[test]
+fn test_transfer_ownership_requires_creator_auth() {
+    let (env, client, group_id, _creator, member2, _) = setup_test_env();
+
+    env.set_auths(&[]);
+    let result = client.try_transfer_group_ownership(&group_id, &member2);
+    assert!(result.is_err());
+}
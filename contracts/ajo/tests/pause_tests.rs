@@ -0,0 +1,273 @@
+#![cfg(test)]
+
+//! Tests for the granular pause switches
+//!
+//! These tests verify that each pause flag blocks exactly the entry points
+//! listed in the pause matrix and leaves the other operation classes running.
+
+use soroban_ajo::events::{PauseChanged, EVENT_VERSION};
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, CycleSchedule, PauseFlag, PauseState};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token::StellarAssetClient,
+    Address, Env, IntoVal, String, Symbol,
+};
+
+/// Helper function to create a test environment with an initialized contract and token
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+
+    (env, client, admin, token)
+}
+
+/// Helper function to generate a funded test address
+fn funded_address(env: &Env, token: &Address) -> Address {
+    let address = Address::generate(env);
+    StellarAssetClient::new(env, token).mint(&address, &1_000_000_000_000);
+    address
+}
+
+/// Helper function to create a two-member group where both members have contributed
+fn setup_contributed_group(
+    env: &Env,
+    client: &AjoContractClient,
+    token: &Address,
+) -> (u64, Address, Address) {
+    let creator = funded_address(env, token);
+    let member = funded_address(env, token);
+    let group_id = client.create_group(
+        &creator,
+        token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &100u32,
+        &0u32,
+    );
+    client.join_group(&member, &group_id, &1u32);
+    client.contribute(&creator, &group_id);
+    client.contribute(&member, &group_id);
+    (group_id, creator, member)
+}
+
+#[test]
+fn test_default_pause_state_is_unpaused() {
+    let (_env, client, _admin, _token) = setup_test_env();
+
+    assert_eq!(client.get_pause_state(), PauseState::default());
+}
+
+#[test]
+fn test_global_pause_sets_every_flag() {
+    let (_env, client, _admin, _token) = setup_test_env();
+
+    client.pause();
+    let state = client.get_pause_state();
+    assert!(state.group_creation);
+    assert!(state.joins);
+    assert!(state.contributions);
+    assert!(state.payouts);
+    assert!(state.refunds);
+    assert!(state.insurance);
+
+    client.unpause();
+    assert_eq!(client.get_pause_state(), PauseState::default());
+}
+
+#[test]
+fn test_pause_operation_only_blocks_its_class() {
+    let (env, client, _admin, token) = setup_test_env();
+    let creator = funded_address(&env, &token);
+    let member = funded_address(&env, &token);
+
+    client.pause_operation(&PauseFlag::Contributions);
+
+    let state = client.get_pause_state();
+    assert!(state.contributions);
+    assert!(!state.joins);
+
+    // Creation and joins are unaffected
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&member, &group_id, &1u32);
+
+    // Contributions are blocked
+    let result = client.try_contribute(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::ContractPaused)));
+
+    // Unpausing the class restores it
+    client.unpause_operation(&PauseFlag::Contributions);
+    client.contribute(&creator, &group_id);
+}
+
+#[test]
+fn test_pause_group_creation_blocks_metadata() {
+    let (env, client, _admin, token) = setup_test_env();
+    let creator = funded_address(&env, &token);
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
+    client.pause_operation(&PauseFlag::GroupCreation);
+
+    let result = client.try_create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    assert_eq!(result, Err(Ok(AjoError::ContractPaused)));
+
+    // The pause is reported before any parameter validation
+    let result = client.try_create_group(
+        &creator,
+        &token,
+        &0i128,
+        &CycleSchedule::Fixed(0u64),
+        &1u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    assert_eq!(result, Err(Ok(AjoError::ContractPaused)));
+
+    let name = String::from_str(&env, "Name");
+    let desc = String::from_str(&env, "Desc");
+    let rules = String::from_str(&env, "Rules");
+    let result = client.try_set_group_metadata(&creator, &group_id, &name, &desc, &rules);
+    assert_eq!(result, Err(Ok(AjoError::ContractPaused)));
+}
+
+#[test]
+fn test_pause_refunds_blocks_emergency_refund_and_cancel() {
+    let (env, client, admin, token) = setup_test_env();
+    let (group_id, creator, _member) = setup_contributed_group(&env, &client, &token);
+
+    client.pause_operation(&PauseFlag::Refunds);
+
+    let result = client.try_emergency_refund(&admin, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::ContractPaused)));
+
+    let result = client.try_cancel_group(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::ContractPaused)));
+
+    // Payouts keep working while refunds are paused
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 86400 + 1;
+    });
+    client.execute_payout(&group_id);
+}
+
+#[test]
+fn test_pause_insurance_blocks_claims() {
+    let (env, client, admin, token) = setup_test_env();
+    let (group_id, creator, member) = setup_contributed_group(&env, &client, &token);
+
+    let claim_id = client.file_insurance_claim(&creator, &group_id, &1u32, &member, &1_000i128);
+
+    client.pause_operation(&PauseFlag::Insurance);
+
+    let result = client.try_file_insurance_claim(&creator, &group_id, &1u32, &member, &1_000i128);
+    assert_eq!(result, Err(Ok(AjoError::ContractPaused)));
+
+    let result = client.try_process_insurance_claim(&admin, &claim_id, &false);
+    assert_eq!(result, Err(Ok(AjoError::ContractPaused)));
+}
+
+#[test]
+fn test_pause_payouts_only() {
+    let (env, client, _admin, token) = setup_test_env();
+    let (group_id, _creator, _member) = setup_contributed_group(&env, &client, &token);
+
+    client.pause_operation(&PauseFlag::Payouts);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 86400 + 1;
+    });
+
+    let result = client.try_execute_payout(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::ContractPaused)));
+}
+
+#[test]
+fn test_pause_change_emits_event() {
+    let (env, client, _admin, _token) = setup_test_env();
+
+    client.pause_operation(&PauseFlag::Joins);
+
+    let expected = PauseState {
+        joins: true,
+        ..Default::default()
+    };
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&env, "pause"), EVENT_VERSION).into_val(&env)
+    );
+    let event: PauseChanged = data.into_val(&env);
+    assert_eq!(event.state, expected);
+}
+
+#[test]
+fn test_pause_operation_requires_admin() {
+    let (env, client, _admin, _token) = setup_test_env();
+
+    env.set_auths(&[]);
+    let result = client.try_pause_operation(&PauseFlag::Payouts);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_pause_and_unpause_before_initialize_are_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let result = client.try_pause();
+    assert_eq!(result, Err(Ok(AjoError::UnauthorizedPause)));
+    let result = client.try_pause_operation(&PauseFlag::Joins);
+    assert_eq!(result, Err(Ok(AjoError::UnauthorizedPause)));
+    let result = client.try_unpause();
+    assert_eq!(result, Err(Ok(AjoError::UnauthorizedUnpause)));
+    let result = client.try_unpause_operation(&PauseFlag::Joins);
+    assert_eq!(result, Err(Ok(AjoError::UnauthorizedUnpause)));
+}
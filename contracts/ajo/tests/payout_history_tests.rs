@@ -0,0 +1,182 @@
+#![cfg(test)]
+
+//! Tests for the stored payout history
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, CycleSchedule, PayoutRecord};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+
+/// Helper function to create a contract with a 1% platform fee, a registered
+/// token and three funded members
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_platform_fee(&100u32);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    for addr in [&alice, &bob, &carol] {
+        StellarAssetClient::new(&env, &token).mint(addr, &1_000_000_000_000);
+    }
+
+    (env, client, token, alice, bob, carol)
+}
+
+/// Helper function to create a group where bob holds two of four seats
+fn create_group(
+    client: &AjoContractClient,
+    token: &Address,
+    alice: &Address,
+    bob: &Address,
+    carol: &Address,
+) -> u64 {
+    let group_id = client.create_group(
+        alice,
+        token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(604_800u64),
+        &4u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(bob, &group_id, &2u32);
+    client.join_group(carol, &group_id, &1u32);
+    group_id
+}
+
+/// Helper function to move past the current cycle's grace period
+fn advance_past_grace(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 86400 + 1;
+    });
+}
+
+/// Helper function to have every member pay on time and run the payout
+fn run_cycle(env: &Env, client: &AjoContractClient, group_id: u64) {
+    for member in client.list_members(&group_id).iter() {
+        if !client
+            .get_contribution_progress(&group_id, &member)
+            .contributed
+        {
+            client.contribute(&member, &group_id);
+        }
+    }
+    advance_past_grace(env);
+    client.execute_payout(&group_id);
+}
+
+#[test]
+fn test_payout_record_breaks_down_amounts() {
+    let (env, client, token, alice, bob, carol) = setup_test_env();
+    let group_id = create_group(&client, &token, &alice, &bob, &carol);
+
+    // Carol pays late, adding a 5% penalty to the first payout
+    client.contribute(&alice, &group_id);
+    client.contribute(&bob, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 3600;
+    });
+    client.contribute(&carol, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400;
+    });
+    client.execute_payout(&group_id);
+
+    let base_amount = CONTRIBUTION * 4;
+    let penalty_bonus = CONTRIBUTION * 5 / 100;
+    let platform_fee = (base_amount + penalty_bonus) / 100;
+    let history = client.get_payout_history(&group_id, &0, &10);
+    assert_eq!(history.len(), 1);
+    assert_eq!(
+        history.get(0).unwrap(),
+        PayoutRecord {
+            group_id,
+            cycle: 1,
+            member: alice,
+            base_amount,
+            penalty_bonus,
+            vault_yield: 0,
+            amount: base_amount + penalty_bonus - platform_fee,
+            platform_fee,
+            organizer_fee: 0,
+            timestamp: env.ledger().timestamp(),
+        }
+    );
+}
+
+#[test]
+fn test_payout_history_pages_through_cycles() {
+    let (env, client, token, alice, bob, carol) = setup_test_env();
+    let group_id = create_group(&client, &token, &alice, &bob, &carol);
+    assert!(client.get_payout_history(&group_id, &0, &10).is_empty());
+
+    for _ in 0..4 {
+        run_cycle(&env, &client, group_id);
+    }
+
+    let history = client.get_payout_history(&group_id, &1, &10);
+    let recipients = [&alice, &bob, &bob, &carol];
+    assert_eq!(history.len(), 4);
+    for (index, record) in history.iter().enumerate() {
+        assert_eq!(record.cycle, index as u32 + 1);
+        assert_eq!(&record.member, recipients[index]);
+    }
+
+    let page = client.get_payout_history(&group_id, &2, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().cycle, 2);
+    assert_eq!(page.get(1).unwrap().cycle, 3);
+    assert!(client.get_payout_history(&group_id, &5, &10).is_empty());
+
+    let result = client.try_get_payout_history(&99, &0, &10);
+    assert_eq!(result, Err(Ok(AjoError::GroupNotFound)));
+}
+
+#[test]
+fn test_get_payout_for_member() {
+    let (env, client, token, alice, bob, carol) = setup_test_env();
+    let group_id = create_group(&client, &token, &alice, &bob, &carol);
+    for _ in 0..3 {
+        run_cycle(&env, &client, group_id);
+    }
+
+    // Bob's two seats were paid in cycles 2 and 3
+    let payouts = client.get_payout_for_member(&group_id, &bob);
+    assert_eq!(payouts.len(), 2);
+    assert_eq!(payouts.get(0).unwrap().cycle, 2);
+    assert_eq!(payouts.get(1).unwrap().cycle, 3);
+    assert_eq!(payouts, client.get_payout_history(&group_id, &2, &2));
+
+    assert!(client.get_payout_for_member(&group_id, &carol).is_empty());
+
+    let stranger = Address::generate(&env);
+    let result = client.try_get_payout_for_member(&group_id, &stranger);
+    assert_eq!(result, Err(Ok(AjoError::NotMember)));
+}
@@ -1,10 +1,21 @@
 #![cfg(test)]
 
-use soroban_ajo::{AjoContract, AjoContractClient};
-use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env};
+use soroban_ajo::{AjoContract, AjoContractClient, CycleSchedule};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env,
+};
 
 /// Helper function to create a test environment and contract
-fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address, Address) {
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -16,12 +27,31 @@ fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address, Addre
     let member2 = Address::generate(&env);
     let member3 = Address::generate(&env);
 
-    (env, client, creator, member2, member3)
+    // Register the group token and fund the test addresses
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    // The contract only accepts tokens registered by the admin
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+    for addr in [&creator, &member2, &member3] {
+        fund(&env, &token, addr);
+    }
+
+    (env, client, creator, member2, member3, token)
+}
+
+/// Helper function to mint enough tokens for an address to cover any test scenario
+fn fund(env: &Env, token: &Address, address: &Address) {
+    StellarAssetClient::new(env, token).mint(address, &1_000_000_000_000_000);
 }
 
 #[test]
 fn test_create_group_with_grace_period_and_penalty() {
-    let (_env, client, creator, _, _) = setup_test_env();
+    let (_env, client, creator, _, _, token) = setup_test_env();
 
     let contribution = 100_000_000i128; // 10 XLM
     let cycle_duration = 604_800u64; // 1 week
@@ -31,11 +61,14 @@ fn test_create_group_with_grace_period_and_penalty() {
 
     let group_id = client.create_group(
         &creator,
+        &token,
         &contribution,
-        &cycle_duration,
+        &CycleSchedule::Fixed(cycle_duration),
         &max_members,
         &grace_period,
         &penalty_rate,
+        &0u32,
+        &0u32,
     );
 
     assert_eq!(group_id, 1);
@@ -48,7 +81,7 @@ fn test_create_group_with_grace_period_and_penalty() {
 
 #[test]
 fn test_on_time_contribution() {
-    let (env, client, creator, member2, _) = setup_test_env();
+    let (_env, client, creator, member2, _, token) = setup_test_env();
 
     let contribution = 100_000_000i128;
     let cycle_duration = 604_800u64;
@@ -58,15 +91,18 @@ fn test_on_time_contribution() {
 
     let group_id = client.create_group(
         &creator,
+        &token,
         &contribution,
-        &cycle_duration,
+        &CycleSchedule::Fixed(cycle_duration),
         &max_members,
         &grace_period,
         &penalty_rate,
+        &0u32,
+        &0u32,
     );
 
     // Join group
-    client.join_group(&member2, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
 
     // Contribute on time (within cycle window)
     client.contribute(&creator, &group_id);
@@ -86,7 +122,7 @@ fn test_on_time_contribution() {
 
 #[test]
 fn test_late_contribution_with_penalty() {
-    let (env, client, creator, member2, _) = setup_test_env();
+    let (env, client, creator, member2, _, token) = setup_test_env();
 
     let contribution = 100_000_000i128; // 10 XLM
     let cycle_duration = 604_800u64; // 1 week
@@ -96,15 +132,18 @@ fn test_late_contribution_with_penalty() {
 
     let group_id = client.create_group(
         &creator,
+        &token,
         &contribution,
-        &cycle_duration,
+        &CycleSchedule::Fixed(cycle_duration),
         &max_members,
         &grace_period,
         &penalty_rate,
+        &0u32,
+        &0u32,
     );
 
     // Join group
-    client.join_group(&member2, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
 
     // Creator contributes on time
     client.contribute(&creator, &group_id);
@@ -133,13 +172,13 @@ fn test_late_contribution_with_penalty() {
 
     // Check contribution detail
     let contrib_detail = client.get_contribution_detail(&group_id, &1u32, &member2);
-    assert_eq!(contrib_detail.is_late, true);
+    assert!(contrib_detail.is_late);
     assert_eq!(contrib_detail.penalty_amount, expected_penalty);
 }
 
 #[test]
 fn test_contribution_after_grace_period_fails() {
-    let (env, client, creator, member2, _) = setup_test_env();
+    let (env, client, creator, member2, _, token) = setup_test_env();
 
     let contribution = 100_000_000i128;
     let cycle_duration = 604_800u64;
@@ -149,14 +188,17 @@ fn test_contribution_after_grace_period_fails() {
 
     let group_id = client.create_group(
         &creator,
+        &token,
         &contribution,
-        &cycle_duration,
+        &CycleSchedule::Fixed(cycle_duration),
         &max_members,
         &grace_period,
         &penalty_rate,
+        &0u32,
+        &0u32,
     );
 
-    client.join_group(&member2, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
 
     // Creator contributes on time
     client.contribute(&creator, &group_id);
@@ -174,7 +216,7 @@ fn test_contribution_after_grace_period_fails() {
 
 #[test]
 fn test_payout_includes_penalties() {
-    let (env, client, creator, member2, _) = setup_test_env();
+    let (env, client, creator, member2, _, token) = setup_test_env();
 
     let contribution = 100_000_000i128; // 10 XLM
     let cycle_duration = 604_800u64;
@@ -184,14 +226,17 @@ fn test_payout_includes_penalties() {
 
     let group_id = client.create_group(
         &creator,
+        &token,
         &contribution,
-        &cycle_duration,
+        &CycleSchedule::Fixed(cycle_duration),
         &max_members,
         &grace_period,
         &penalty_rate,
+        &0u32,
+        &0u32,
     );
 
-    client.join_group(&member2, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
 
     // Creator contributes on time
     client.contribute(&creator, &group_id);
@@ -206,7 +251,7 @@ fn test_payout_includes_penalties() {
 
     // Advance time past grace period
     env.ledger().with_mut(|li| {
-        li.timestamp = li.timestamp + grace_period;
+        li.timestamp += grace_period;
     });
 
     // Execute payout
@@ -220,7 +265,7 @@ fn test_payout_includes_penalties() {
 
 #[test]
 fn test_payout_delayed_during_grace_period() {
-    let (env, client, creator, member2, _) = setup_test_env();
+    let (env, client, creator, member2, _, token) = setup_test_env();
 
     let contribution = 100_000_000i128;
     let cycle_duration = 604_800u64;
@@ -230,14 +275,17 @@ fn test_payout_delayed_during_grace_period() {
 
     let group_id = client.create_group(
         &creator,
+        &token,
         &contribution,
-        &cycle_duration,
+        &CycleSchedule::Fixed(cycle_duration),
         &max_members,
         &grace_period,
         &penalty_rate,
+        &0u32,
+        &0u32,
     );
 
-    client.join_group(&member2, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
 
     // Both contribute on time
     client.contribute(&creator, &group_id);
@@ -256,7 +304,7 @@ fn test_payout_delayed_during_grace_period() {
 
 #[test]
 fn test_reliability_score_calculation() {
-    let (env, client, creator, member2, member3) = setup_test_env();
+    let (env, client, creator, member2, member3, token) = setup_test_env();
 
     let contribution = 100_000_000i128;
     let cycle_duration = 604_800u64;
@@ -266,15 +314,18 @@ fn test_reliability_score_calculation() {
 
     let group_id = client.create_group(
         &creator,
+        &token,
         &contribution,
-        &cycle_duration,
+        &CycleSchedule::Fixed(cycle_duration),
         &max_members,
         &grace_period,
         &penalty_rate,
+        &0u32,
+        &0u32,
     );
 
-    client.join_group(&member2, &group_id);
-    client.join_group(&member3, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
+    client.join_group(&member3, &group_id, &1u32);
 
     // Cycle 1: All on time
     client.contribute(&creator, &group_id);
@@ -290,8 +341,10 @@ fn test_reliability_score_calculation() {
     client.contribute(&creator, &group_id);
     client.contribute(&member3, &group_id);
 
+    // Cycle 2 starts when cycle 1 was scheduled to end, not at the payout
+    let cycle_start = client.get_group(&group_id).cycle_start_time;
     env.ledger().with_mut(|li| {
-        li.timestamp = li.timestamp + cycle_duration + 3600;
+        li.timestamp = cycle_start + cycle_duration + 3600;
     });
     client.contribute(&member2, &group_id);
 
@@ -308,7 +361,7 @@ fn test_reliability_score_calculation() {
 
 #[test]
 fn test_group_status_includes_penalty_info() {
-    let (env, client, creator, member2, _) = setup_test_env();
+    let (env, client, creator, member2, _, token) = setup_test_env();
 
     let contribution = 100_000_000i128;
     let cycle_duration = 604_800u64;
@@ -318,19 +371,22 @@ fn test_group_status_includes_penalty_info() {
 
     let group_id = client.create_group(
         &creator,
+        &token,
         &contribution,
-        &cycle_duration,
+        &CycleSchedule::Fixed(cycle_duration),
         &max_members,
         &grace_period,
         &penalty_rate,
+        &0u32,
+        &0u32,
     );
 
-    client.join_group(&member2, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
 
     // Get status during cycle
     let status = client.get_group_status(&group_id);
-    assert_eq!(status.is_cycle_active, true);
-    assert_eq!(status.is_in_grace_period, false);
+    assert!(status.is_cycle_active);
+    assert!(!status.is_in_grace_period);
     assert_eq!(status.cycle_penalty_pool, 0);
 
     // Advance to grace period
@@ -339,13 +395,13 @@ fn test_group_status_includes_penalty_info() {
     });
 
     let status = client.get_group_status(&group_id);
-    assert_eq!(status.is_cycle_active, false);
-    assert_eq!(status.is_in_grace_period, true);
+    assert!(!status.is_cycle_active);
+    assert!(status.is_in_grace_period);
 }
 
 #[test]
 fn test_invalid_penalty_rate() {
-    let (_env, client, creator, _, _) = setup_test_env();
+    let (_env, client, creator, _, _, token) = setup_test_env();
 
     let contribution = 100_000_000i128;
     let cycle_duration = 604_800u64;
@@ -355,20 +411,23 @@ fn test_invalid_penalty_rate() {
 
     let result = client.try_create_group(
         &creator,
+        &token,
         &contribution,
-        &cycle_duration,
+        &CycleSchedule::Fixed(cycle_duration),
         &max_members,
         &grace_period,
         &penalty_rate,
+        &0u32,
+        &0u32,
     );
-    
+
     assert!(result.is_err());
     assert_eq!(result, Err(Ok(soroban_ajo::AjoError::InvalidPenaltyRate)));
 }
 
 #[test]
 fn test_invalid_grace_period() {
-    let (_env, client, creator, _, _) = setup_test_env();
+    let (_env, client, creator, _, _, token) = setup_test_env();
 
     let contribution = 100_000_000i128;
     let cycle_duration = 604_800u64;
@@ -378,13 +437,16 @@ fn test_invalid_grace_period() {
 
     let result = client.try_create_group(
         &creator,
+        &token,
         &contribution,
-        &cycle_duration,
+        &CycleSchedule::Fixed(cycle_duration),
         &max_members,
         &grace_period,
         &penalty_rate,
+        &0u32,
+        &0u32,
     );
-    
+
     assert!(result.is_err());
     assert_eq!(result, Err(Ok(soroban_ajo::AjoError::InvalidGracePeriod)));
 }
@@ -0,0 +1,274 @@
+#![cfg(test)]
+
+//! Tests for the anti-spam limits on creating and joining groups
+
+use soroban_ajo::events::{RateLimitsSet, EVENT_VERSION};
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, CycleSchedule, RateLimits};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token::StellarAssetClient,
+    Address, Env, IntoVal,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const DAY: u64 = 86_400;
+
+/// Helper function to create an initialized contract and a registered token
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+
+    (env, client, token)
+}
+
+/// Helper function to create a two-member group
+fn try_create(
+    client: &AjoContractClient,
+    creator: &Address,
+    token: &Address,
+) -> Result<u64, AjoError> {
+    match client.try_create_group(
+        creator,
+        token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &DAY,
+        &5u32,
+        &0u32,
+        &0u32,
+    ) {
+        Ok(Ok(group_id)) => Ok(group_id),
+        Err(Ok(error)) => Err(error),
+        _ => panic!("unexpected invocation failure"),
+    }
+}
+
+/// Helper function to build limits with only the given fields set
+fn limits(
+    creation_window: u64,
+    max_creations_per_window: u32,
+    max_active_groups: u32,
+    max_memberships: u32,
+) -> RateLimits {
+    RateLimits {
+        creation_window,
+        max_creations_per_window,
+        max_active_groups,
+        max_memberships,
+    }
+}
+
+#[test]
+fn test_no_limits_by_default() {
+    let (env, client, token) = setup_test_env();
+    let creator = Address::generate(&env);
+
+    assert_eq!(client.get_rate_limits(), RateLimits::default());
+    for _ in 0..5 {
+        assert!(try_create(&client, &creator, &token).is_ok());
+    }
+}
+
+#[test]
+fn test_set_rate_limits_emits_event() {
+    let (env, client, _token) = setup_test_env();
+    let new_limits = limits(DAY, 3, 5, 10);
+
+    client.set_rate_limits(&new_limits);
+    assert_eq!(client.get_rate_limits(), new_limits);
+
+    let event = env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("ratelim"), EVENT_VERSION).into_val(&env)
+    );
+    let data: RateLimitsSet = event.2.into_val(&env);
+    assert_eq!(data.limits, new_limits);
+}
+
+#[test]
+fn test_creation_rate_uses_sliding_window() {
+    let (env, client, token) = setup_test_env();
+    let creator = Address::generate(&env);
+    client.set_rate_limits(&limits(DAY, 2, 0, 0));
+
+    try_create(&client, &creator, &token).unwrap();
+    env.ledger().with_mut(|li| li.timestamp += DAY / 2);
+    try_create(&client, &creator, &token).unwrap();
+    assert_eq!(
+        try_create(&client, &creator, &token),
+        Err(AjoError::LimitExceeded)
+    );
+
+    // Another address has its own window
+    assert!(try_create(&client, &Address::generate(&env), &token).is_ok());
+
+    // The first creation leaves the window, the second is still in it
+    env.ledger().with_mut(|li| li.timestamp += DAY / 2);
+    try_create(&client, &creator, &token).unwrap();
+    assert_eq!(
+        try_create(&client, &creator, &token),
+        Err(AjoError::LimitExceeded)
+    );
+}
+
+#[test]
+fn test_active_group_limit_frees_cancelled_groups() {
+    let (env, client, token) = setup_test_env();
+    let creator = Address::generate(&env);
+    client.set_rate_limits(&limits(0, 0, 2, 0));
+
+    let first = try_create(&client, &creator, &token).unwrap();
+    try_create(&client, &creator, &token).unwrap();
+    assert_eq!(
+        try_create(&client, &creator, &token),
+        Err(AjoError::LimitExceeded)
+    );
+
+    client.cancel_group(&creator, &first);
+    assert!(try_create(&client, &creator, &token).is_ok());
+}
+
+#[test]
+fn test_accepting_ownership_counts_toward_active_groups() {
+    let (env, client, token) = setup_test_env();
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+    let group_id = try_create(&client, &owner, &token).unwrap();
+    try_create(&client, &new_owner, &token).unwrap();
+
+    client.set_rate_limits(&limits(0, 0, 1, 0));
+    client.transfer_group_ownership(&group_id, &new_owner);
+    let result = client.try_accept_group_ownership(&new_owner, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::LimitExceeded)));
+
+    // The previous owner's slot is freed once the transfer goes through
+    client.set_rate_limits(&limits(0, 0, 2, 0));
+    client.accept_group_ownership(&new_owner, &group_id);
+    client.set_rate_limits(&limits(0, 0, 1, 0));
+    assert!(try_create(&client, &owner, &token).is_ok());
+}
+
+#[test]
+fn test_membership_limit_applies_to_joins_and_creation() {
+    let (env, client, token) = setup_test_env();
+    let member = Address::generate(&env);
+    let first = try_create(&client, &Address::generate(&env), &token).unwrap();
+    let second = try_create(&client, &Address::generate(&env), &token).unwrap();
+    client.set_rate_limits(&limits(0, 0, 0, 1));
+
+    client.join_group(&member, &first, &1u32);
+    let result = client.try_join_group(&member, &second, &1u32);
+    assert_eq!(result, Err(Ok(AjoError::LimitExceeded)));
+    assert_eq!(
+        try_create(&client, &member, &token),
+        Err(AjoError::LimitExceeded)
+    );
+
+    client.cancel_group(&client.get_group(&first).creator, &first);
+    client.join_group(&member, &second, &1u32);
+}
+
+#[test]
+fn test_exposure_limit_counts_remaining_cycles_per_token() {
+    let (env, client, token) = setup_test_env();
+    let other_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_supported_token(&other_token, &1i128, &i128::MAX);
+    let member = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&member, &1_000_000_000_000);
+
+    client.set_exposure_limit(&token, &(CONTRIBUTION * 13));
+    assert_eq!(client.get_exposure_limit(&token), CONTRIBUTION * 13);
+
+    // Two seats in a group of up to five: two contributions for each of five cycles
+    let group_id = client.create_group(
+        &Address::generate(&env),
+        &token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(604_800u64),
+        &5u32,
+        &DAY,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&member, &group_id, &2u32);
+    assert_eq!(
+        client.get_member_exposure(&member, &token),
+        CONTRIBUTION * 10
+    );
+
+    // Later joiners do not add to it
+    client.join_group(&Address::generate(&env), &group_id, &1u32);
+    assert_eq!(
+        client.get_member_exposure(&member, &token),
+        CONTRIBUTION * 10
+    );
+
+    // A seat in a two-seat group adds two more; a third group would pass the limit
+    let second = try_create(&client, &Address::generate(&env), &token).unwrap();
+    let third = try_create(&client, &Address::generate(&env), &token).unwrap();
+    client.join_group(&member, &second, &1u32);
+    assert_eq!(
+        client.get_member_exposure(&member, &token),
+        CONTRIBUTION * 12
+    );
+    let result = client.try_join_group(&member, &third, &1u32);
+    assert_eq!(result, Err(Ok(AjoError::LimitExceeded)));
+
+    // Paying the current cycle takes it off the member's exposure
+    client.contribute(&member, &group_id);
+    client.contribute(&member, &second);
+    assert_eq!(
+        client.get_member_exposure(&member, &token),
+        CONTRIBUTION * 9
+    );
+    client.join_group(&member, &third, &1u32);
+    assert_eq!(
+        client.get_member_exposure(&member, &token),
+        CONTRIBUTION * 11
+    );
+
+    // Other tokens have their own limit
+    let elsewhere = try_create(&client, &Address::generate(&env), &other_token).unwrap();
+    client.join_group(&member, &elsewhere, &1u32);
+    assert_eq!(
+        client.get_member_exposure(&member, &token),
+        CONTRIBUTION * 11
+    );
+}
+
+#[test]
+fn test_limits_require_admin() {
+    let (env, client, token) = setup_test_env();
+    env.mock_auths(&[]);
+
+    assert!(client.try_set_rate_limits(&limits(DAY, 1, 1, 1)).is_err());
+    assert!(client
+        .try_set_exposure_limit(&token, &CONTRIBUTION)
+        .is_err());
+}
+
+#[test]
+fn test_set_exposure_limit_rejects_negative() {
+    let (_env, client, token) = setup_test_env();
+
+    let result = client.try_set_exposure_limit(&token, &-1i128);
+    assert_eq!(result, Err(Ok(AjoError::ContributionOutOfRange)));
+}
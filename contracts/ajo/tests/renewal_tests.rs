@@ -0,0 +1,245 @@
+#![cfg(test)]
+
+//! Tests for renewing completed groups into a new rotation
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, CycleSchedule};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    vec, Address, Env, String, Vec,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const RENEWAL_WINDOW: u64 = 604_800;
+
+/// Helper function to create a contract, a registered token and three funded members
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    for addr in [&alice, &bob, &carol] {
+        StellarAssetClient::new(&env, &token).mint(addr, &1_000_000_000_000);
+    }
+
+    (env, client, token, alice, bob, carol)
+}
+
+/// Helper function to create a three-member group paying alice, bob, then carol
+fn create_group(
+    client: &AjoContractClient,
+    token: &Address,
+    alice: &Address,
+    bob: &Address,
+    carol: &Address,
+) -> u64 {
+    let group_id = client.create_group(
+        alice,
+        token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(bob, &group_id, &1u32);
+    client.join_group(carol, &group_id, &1u32);
+    group_id
+}
+
+/// Helper function to move past the current cycle's grace period
+fn advance_past_grace(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 86400 + 1;
+    });
+}
+
+/// Helper function to run every remaining cycle of a group to completion
+fn complete_group(env: &Env, client: &AjoContractClient, group_id: u64) {
+    while !client.is_complete(&group_id) {
+        for member in client.list_members(&group_id).iter() {
+            if !client
+                .get_contribution_progress(&group_id, &member)
+                .contributed
+            {
+                client.contribute(&member, &group_id);
+            }
+        }
+        advance_past_grace(env);
+        client.execute_payout(&group_id);
+    }
+}
+
+/// Helper function to list who holds each payout position, in payout order
+fn payout_order(env: &Env, client: &AjoContractClient, group_id: u64) -> Vec<Address> {
+    let mut order = Vec::new(env);
+    for position in client.get_payout_schedule(&group_id).iter() {
+        order.push_back(position.member);
+    }
+    order
+}
+
+#[test]
+fn test_renew_group_copies_settings_and_links_groups() {
+    let (env, client, token, alice, bob, carol) = setup_test_env();
+    let group_id = create_group(&client, &token, &alice, &bob, &carol);
+    let name = String::from_str(&env, "Market traders");
+    let text = String::from_str(&env, "Weekly savings");
+    client.set_group_metadata(&alice, &group_id, &name, &text, &text);
+    let co_organizer = Address::generate(&env);
+    client.add_co_organizer(&group_id, &co_organizer);
+
+    let result = client.try_renew_group(&alice, &group_id, &false);
+    assert_eq!(result, Err(Ok(AjoError::InvalidGroupState)));
+
+    complete_group(&env, &client, group_id);
+    let result = client.try_renew_group(&bob, &group_id, &false);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+
+    let successor_id = client.renew_group(&co_organizer, &group_id, &false);
+    let group = client.get_group(&group_id);
+    let successor = client.get_group(&successor_id);
+    assert_eq!(successor.creator, alice);
+    assert_eq!(successor.contribution_amount, group.contribution_amount);
+    assert_eq!(successor.max_members, group.max_members);
+    assert_eq!(successor.grace_period, group.grace_period);
+    assert_eq!(successor.penalty_rate, group.penalty_rate);
+    assert_eq!(successor.schedule, group.schedule);
+    assert_eq!(
+        client.list_members(&successor_id),
+        vec![&env, alice.clone()]
+    );
+    assert_eq!(successor.current_cycle, 1);
+    assert!(!successor.is_complete);
+    assert_eq!(client.get_group_metadata(&successor_id).name, name);
+    assert_eq!(
+        client.get_co_organizers(&successor_id),
+        vec![&env, co_organizer]
+    );
+
+    let renewal = client.get_renewal(&successor_id).unwrap();
+    assert_eq!(renewal.predecessor, group_id);
+    assert_eq!(renewal.invitees, vec![&env, bob, carol, alice.clone()]);
+    assert_eq!(
+        renewal.opt_in_deadline,
+        env.ledger().timestamp() + RENEWAL_WINDOW
+    );
+    assert_eq!(client.get_successor(&group_id), Some(successor_id));
+    assert_eq!(client.get_successor(&successor_id), None);
+    assert_eq!(client.get_renewal(&group_id), None);
+
+    let result = client.try_renew_group(&alice, &group_id, &false);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyInitialized)));
+}
+
+#[test]
+fn test_renewed_payout_order_rotates() {
+    let (env, client, token, alice, bob, carol) = setup_test_env();
+    let group_id = create_group(&client, &token, &alice, &bob, &carol);
+    complete_group(&env, &client, group_id);
+
+    // Invitees take their rotated place whatever order they opt in
+    let second = client.renew_group(&alice, &group_id, &false);
+    client.join_group(&carol, &second, &1u32);
+    client.join_group(&bob, &second, &1u32);
+    assert_eq!(
+        payout_order(&env, &client, second),
+        vec![&env, bob.clone(), carol.clone(), alice.clone()]
+    );
+
+    complete_group(&env, &client, second);
+    let third = client.renew_group(&alice, &second, &false);
+    client.join_group(&bob, &third, &1u32);
+    client.join_group(&carol, &third, &1u32);
+    assert_eq!(
+        payout_order(&env, &client, third),
+        vec![&env, carol, alice, bob]
+    );
+}
+
+#[test]
+fn test_opt_in_window_is_reserved_for_invitees() {
+    let (env, client, token, alice, bob, carol) = setup_test_env();
+    let group_id = create_group(&client, &token, &alice, &bob, &carol);
+    complete_group(&env, &client, group_id);
+    let successor_id = client.renew_group(&alice, &group_id, &false);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_join_group(&outsider, &successor_id, &1u32);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+
+    // After the window the group is open and joiners are appended
+    env.ledger().with_mut(|li| {
+        li.timestamp += RENEWAL_WINDOW + 1;
+    });
+    client.join_group(&outsider, &successor_id, &1u32);
+    client.join_group(&bob, &successor_id, &1u32);
+    assert_eq!(
+        payout_order(&env, &client, successor_id),
+        vec![&env, alice, outsider, bob]
+    );
+
+    let result = client.try_join_group(&carol, &successor_id, &1u32);
+    assert_eq!(result, Err(Ok(AjoError::MaxMembersExceeded)));
+}
+
+#[test]
+fn test_renewal_can_carry_reliability() {
+    let (env, client, token, alice, bob, carol) = setup_test_env();
+    let first = create_group(&client, &token, &alice, &bob, &carol);
+    let second = create_group(&client, &token, &alice, &bob, &carol);
+
+    // Carol pays late in the first cycle of both groups
+    for group_id in [first, second] {
+        client.contribute(&alice, &group_id);
+        client.contribute(&bob, &group_id);
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 3600;
+    });
+    for group_id in [first, second] {
+        client.contribute(&carol, &group_id);
+    }
+    for group_id in [first, second] {
+        complete_group(&env, &client, group_id);
+    }
+
+    let carried = client.renew_group(&alice, &first, &true);
+    let fresh = client.renew_group(&alice, &second, &false);
+    for group_id in [carried, fresh] {
+        client.join_group(&carol, &group_id, &1u32);
+    }
+
+    let record = client.get_member_penalty_record(&carried, &carol);
+    assert_eq!(record.group_id, carried);
+    assert_eq!(record.late_count, 1);
+    assert_eq!(record.on_time_count, 2);
+    assert_eq!(record.reliability_score, 66);
+
+    let record = client.get_member_penalty_record(&fresh, &carol);
+    assert_eq!(record.late_count, 0);
+    assert_eq!(record.reliability_score, 100);
+}
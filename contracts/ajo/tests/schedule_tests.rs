@@ -0,0 +1,294 @@
+#![cfg(test)]
+
+//! Tests for weekly and monthly calendar cycle schedules
+
+use soroban_ajo::events::{CycleAdvanced, EVENT_VERSION};
+use soroban_ajo::{
+    AjoContract, AjoContractClient, AjoError, CycleSchedule, MonthlySchedule, WeeklySchedule,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token::StellarAssetClient,
+    Address, Env, IntoVal,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const DAY: u64 = 86_400;
+const HOUR: u64 = 3_600;
+
+/// 2024-01-01 00:00:00 UTC, a Monday
+const JAN_1_2024: u64 = 1_704_067_200;
+
+/// 2023-01-01 00:00:00 UTC, a Sunday
+const JAN_1_2023: u64 = 1_672_531_200;
+
+/// Helper function to create a contract and a registered, funded token
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+
+    let creator = Address::generate(&env);
+    let member = Address::generate(&env);
+    for addr in [&creator, &member] {
+        StellarAssetClient::new(&env, &token).mint(addr, &1_000_000_000_000);
+    }
+
+    (env, client, token, creator, member)
+}
+
+/// Helper function to create a two-member group at `now` and return its cycle end
+fn cycle_end_for(schedule: CycleSchedule, now: u64) -> u64 {
+    let (env, client, token, creator, _member) = setup_test_env();
+    env.ledger().with_mut(|li| li.timestamp = now);
+
+    let group_id = try_create(&client, &creator, &token, schedule).unwrap();
+    client.get_group_status(&group_id).cycle_end_time
+}
+
+/// Helper function to create a group with the given schedule
+fn try_create(
+    client: &AjoContractClient,
+    creator: &Address,
+    token: &Address,
+    schedule: CycleSchedule,
+) -> Result<u64, AjoError> {
+    match client.try_create_group(
+        creator,
+        token,
+        &CONTRIBUTION,
+        &schedule,
+        &2u32,
+        &DAY,
+        &5u32,
+        &0u32,
+        &0u32,
+    ) {
+        Ok(Ok(group_id)) => Ok(group_id),
+        Err(Ok(error)) => Err(error),
+        _ => panic!("unexpected invocation failure"),
+    }
+}
+
+/// Helper function to create a full two-member group at `now` where both have paid
+fn setup_paid_group(schedule: CycleSchedule, now: u64) -> (Env, AjoContractClient<'static>, u64) {
+    let (env, client, token, creator, member) = setup_test_env();
+    env.ledger().with_mut(|li| li.timestamp = now);
+
+    let group_id = try_create(&client, &creator, &token, schedule).unwrap();
+    client.join_group(&member, &group_id, &1u32);
+    client.contribute(&creator, &group_id);
+    client.contribute(&member, &group_id);
+
+    (env, client, group_id)
+}
+
+/// Helper function to run the payout at `now` and return the `cycle` event data
+fn payout_at(env: &Env, client: &AjoContractClient, group_id: u64, now: u64) -> (u32, u64, u64) {
+    env.ledger().with_mut(|li| li.timestamp = now);
+    client.execute_payout(&group_id);
+
+    let topics = (symbol_short!("cycle"), EVENT_VERSION, group_id).into_val(env);
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, event_topics, _)| *event_topics == topics)
+        .unwrap();
+    let event: CycleAdvanced = data.into_val(env);
+    (event.cycle, event.cycle_start_time, event.cycle_end_time)
+}
+
+fn weekly(weekday: u32, time_of_day: u32, utc_offset: i32) -> CycleSchedule {
+    CycleSchedule::Weekly(WeeklySchedule {
+        weekday,
+        time_of_day,
+        utc_offset,
+    })
+}
+
+fn monthly(day: u32, time_of_day: u32, utc_offset: i32) -> CycleSchedule {
+    CycleSchedule::Monthly(MonthlySchedule {
+        day,
+        time_of_day,
+        utc_offset,
+    })
+}
+
+#[test]
+fn test_weekly_schedule_ends_on_weekday() {
+    // Monday 10:00 to Friday 18:00 the same week
+    let now = JAN_1_2024 + 10 * HOUR;
+    assert_eq!(
+        cycle_end_for(weekly(4, 18 * HOUR as u32, 0), now),
+        JAN_1_2024 + 4 * DAY + 18 * HOUR
+    );
+
+    // Monday 09:00 has already passed, so the cycle runs to next Monday
+    assert_eq!(
+        cycle_end_for(weekly(0, 9 * HOUR as u32, 0), now),
+        JAN_1_2024 + 7 * DAY + 9 * HOUR
+    );
+
+    // A cycle started exactly on the boundary runs a full week
+    assert_eq!(
+        cycle_end_for(weekly(0, 0, 0), JAN_1_2024),
+        JAN_1_2024 + 7 * DAY
+    );
+}
+
+#[test]
+fn test_weekly_schedule_uses_utc_offset() {
+    // Midnight on Monday in UTC+1 is 23:00 UTC on Sunday
+    let now = JAN_1_2024 + 10 * HOUR;
+    assert_eq!(
+        cycle_end_for(weekly(0, 0, 3_600), now),
+        JAN_1_2024 + 7 * DAY - HOUR
+    );
+
+    // At 03:00 UTC on Monday it is still Sunday in UTC-5
+    let now = JAN_1_2024 + 3 * HOUR;
+    assert_eq!(
+        cycle_end_for(weekly(0, 0, -18_000), now),
+        JAN_1_2024 + 5 * HOUR
+    );
+}
+
+#[test]
+fn test_monthly_schedule_clamps_to_month_end() {
+    // From January 31st, a day-31 schedule ends on the last day of February
+    let now = JAN_1_2024 + 30 * DAY + 12 * HOUR;
+    assert_eq!(
+        cycle_end_for(monthly(31, 0, 0), now),
+        JAN_1_2024 + (31 + 28) * DAY
+    );
+
+    let now = JAN_1_2023 + 30 * DAY + 12 * HOUR;
+    assert_eq!(
+        cycle_end_for(monthly(31, 0, 0), now),
+        JAN_1_2023 + (31 + 27) * DAY
+    );
+
+    // Earlier in the month the schedule ends on the 31st itself
+    let now = JAN_1_2024 + 5 * DAY;
+    assert_eq!(cycle_end_for(monthly(31, 0, 0), now), JAN_1_2024 + 30 * DAY);
+}
+
+#[test]
+fn test_monthly_schedule_rolls_over_year_and_offset() {
+    // From December 20th, 2023 to January 15th, 2024 at 09:00
+    let now = JAN_1_2024 - 12 * DAY;
+    assert_eq!(
+        cycle_end_for(monthly(15, 9 * HOUR as u32, 0), now),
+        JAN_1_2024 + 14 * DAY + 9 * HOUR
+    );
+
+    // 09:00 on the 1st in UTC+9 is midnight UTC
+    let now = JAN_1_2024 - 12 * DAY;
+    assert_eq!(
+        cycle_end_for(monthly(1, 9 * HOUR as u32, 32_400), now),
+        JAN_1_2024
+    );
+}
+
+#[test]
+fn test_grace_period_follows_schedule() {
+    let (env, client, token, creator, member) = setup_test_env();
+    let now = JAN_1_2024 + 30 * DAY + 12 * HOUR;
+    env.ledger().with_mut(|li| li.timestamp = now);
+
+    let group_id = try_create(&client, &creator, &token, monthly(31, 0, 0)).unwrap();
+    client.join_group(&member, &group_id, &1u32);
+    client.contribute(&creator, &group_id);
+    client.contribute(&member, &group_id);
+
+    let cycle_end = JAN_1_2024 + (31 + 28) * DAY;
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.cycle_end_time, cycle_end);
+    assert_eq!(status.grace_period_end_time, cycle_end + DAY);
+
+    env.ledger().with_mut(|li| li.timestamp = cycle_end + 1);
+    assert!(client.get_group_status(&group_id).is_in_grace_period);
+    let result = client.try_execute_payout(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::OutsideCycleWindow)));
+
+    env.ledger().with_mut(|li| li.timestamp = cycle_end + DAY);
+    client.execute_payout(&group_id);
+    assert_eq!(client.get_group(&group_id).payout_index, 1);
+}
+
+#[test]
+fn test_invalid_schedules_rejected() {
+    let (_env, client, token, creator, _member) = setup_test_env();
+
+    for schedule in [
+        weekly(7, 0, 0),
+        weekly(0, DAY as u32, 0),
+        weekly(0, 0, 50_401),
+        monthly(0, 0, 0),
+        monthly(32, 0, 0),
+        monthly(1, 0, -50_401),
+    ] {
+        assert_eq!(
+            try_create(&client, &creator, &token, schedule),
+            Err(AjoError::InvalidSchedule)
+        );
+    }
+
+    // The extreme offsets themselves are allowed
+    assert!(try_create(&client, &creator, &token, weekly(6, 0, 50_400)).is_ok());
+    assert!(try_create(&client, &creator, &token, monthly(31, 0, -50_400)).is_ok());
+}
+
+#[test]
+fn test_late_payout_does_not_shift_schedule() {
+    let (env, client, group_id) = setup_paid_group(CycleSchedule::Fixed(7 * DAY), JAN_1_2024);
+
+    // Paid three days after the grace period ended
+    let advanced = payout_at(&env, &client, group_id, JAN_1_2024 + 11 * DAY);
+    assert_eq!(advanced, (2, JAN_1_2024 + 7 * DAY, JAN_1_2024 + 14 * DAY));
+
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.current_cycle, 2);
+    assert_eq!(status.cycle_start_time, JAN_1_2024 + 7 * DAY);
+    assert_eq!(status.cycle_end_time, JAN_1_2024 + 14 * DAY);
+}
+
+#[test]
+fn test_payout_after_missed_windows_skips_them() {
+    let (env, client, group_id) = setup_paid_group(CycleSchedule::Fixed(7 * DAY), JAN_1_2024);
+
+    // Cycle 2's whole window ended before the payout, so cycle 2 takes the next one
+    let advanced = payout_at(&env, &client, group_id, JAN_1_2024 + 15 * DAY + HOUR);
+    assert_eq!(advanced, (2, JAN_1_2024 + 14 * DAY, JAN_1_2024 + 21 * DAY));
+    assert!(client.get_group_status(&group_id).is_cycle_active);
+}
+
+#[test]
+fn test_calendar_schedule_survives_late_payout() {
+    let start = JAN_1_2024 + 30 * DAY + 12 * HOUR;
+    let (env, client, group_id) = setup_paid_group(monthly(31, 0, 0), start);
+    let feb_29 = JAN_1_2024 + (31 + 28) * DAY;
+    let mar_31 = feb_29 + 31 * DAY;
+
+    // Paid on March 3rd, the next cycle still runs from February 29th to March 31st
+    let advanced = payout_at(&env, &client, group_id, feb_29 + 3 * DAY);
+    assert_eq!(advanced, (2, feb_29, mar_31));
+
+    // Weekly windows that were missed are skipped on the calendar too
+    let (env, client, group_id) = setup_paid_group(weekly(0, 0, 0), JAN_1_2024 + HOUR);
+    let advanced = payout_at(&env, &client, group_id, JAN_1_2024 + 22 * DAY);
+    assert_eq!(advanced, (2, JAN_1_2024 + 21 * DAY, JAN_1_2024 + 28 * DAY));
+}
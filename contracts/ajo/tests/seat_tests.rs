@@ -0,0 +1,185 @@
+#![cfg(test)]
+
+//! Tests for members holding several seats ("hands") in one group
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, CycleSchedule};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    Address, Env,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE_DURATION: u64 = 604_800;
+const GRACE_PERIOD: u64 = 86_400;
+
+/// Helper function to create a four-seat group with a funded token
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    u64,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let member3 = Address::generate(&env);
+
+    // Register the group token and fund the test addresses
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    // The contract only accepts tokens registered by the admin
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+    for addr in [&creator, &member2, &member3] {
+        StellarAssetClient::new(&env, &token).mint(addr, &1_000_000_000_000);
+    }
+
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(CYCLE_DURATION),
+        &4u32,
+        &GRACE_PERIOD,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
+    (env, client, group_id, token, creator, member2, member3)
+}
+
+/// Helper function to move past the current cycle's grace period
+fn advance_past_grace(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_DURATION + GRACE_PERIOD + 1;
+    });
+}
+
+#[test]
+fn test_join_with_multiple_seats() {
+    let (_env, client, group_id, _token, creator, member2, member3) = setup_test_env();
+
+    client.join_group(&member2, &group_id, &2u32);
+    client.join_group(&member3, &group_id, &1u32);
+
+    let group = client.get_group(&group_id);
+    assert_eq!(group.member_count, 3);
+    assert_eq!(group.seat_count, 4);
+
+    let schedule = client.get_payout_schedule(&group_id);
+    assert_eq!(schedule.len(), 4);
+    assert_eq!(schedule.get(0).unwrap().member, creator);
+    let second = schedule.get(2).unwrap();
+    assert_eq!(second.member, member2);
+    assert_eq!(second.seat, 2);
+    assert_eq!(second.cycle, 3);
+    assert!(!second.paid_out);
+    assert_eq!(schedule.get(3).unwrap().member, member3);
+
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.total_members, 3);
+    assert_eq!(status.total_seats, 4);
+    assert_eq!(status.next_payout_position, 0);
+}
+
+#[test]
+fn test_join_rejects_invalid_seat_counts() {
+    let (_env, client, group_id, _token, _creator, member2, member3) = setup_test_env();
+
+    let result = client.try_join_group(&member2, &group_id, &0u32);
+    assert_eq!(result, Err(Ok(AjoError::NoMembers)));
+
+    // Only three seats remain after the creator's
+    let result = client.try_join_group(&member2, &group_id, &4u32);
+    assert_eq!(result, Err(Ok(AjoError::MaxMembersExceeded)));
+
+    client.join_group(&member2, &group_id, &2u32);
+    let result = client.try_join_group(&member3, &group_id, &2u32);
+    assert_eq!(result, Err(Ok(AjoError::MaxMembersExceeded)));
+
+    let result = client.try_join_group(&member2, &group_id, &1u32);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyMember)));
+}
+
+#[test]
+fn test_contribution_covers_every_seat() {
+    let (env, client, group_id, token, _creator, member2, _) = setup_test_env();
+    let token_client = TokenClient::new(&env, &token);
+    client.join_group(&member2, &group_id, &3u32);
+
+    let balance_before = token_client.balance(&member2);
+    client.contribute(&member2, &group_id);
+
+    assert_eq!(
+        token_client.balance(&member2),
+        balance_before - CONTRIBUTION * 3
+    );
+    let record = client.get_contribution_detail(&group_id, &1u32, &member2);
+    assert_eq!(record.amount, CONTRIBUTION * 3);
+}
+
+#[test]
+fn test_multi_seat_member_receives_payout_per_seat() {
+    let (env, client, group_id, token, creator, member2, member3) = setup_test_env();
+    let token_client = TokenClient::new(&env, &token);
+    client.join_group(&member2, &group_id, &2u32);
+    client.join_group(&member3, &group_id, &1u32);
+
+    let expected = [&creator, &member2, &member2, &member3];
+    for (index, recipient) in expected.iter().enumerate() {
+        for member in [&creator, &member2, &member3] {
+            client.contribute(member, &group_id);
+        }
+        advance_past_grace(&env);
+
+        let status = client.get_group_status(&group_id);
+        assert_eq!(status.next_payout_position, index as u32);
+        assert_eq!(&&status.next_recipient, recipient);
+
+        let balance_before = token_client.balance(recipient);
+        client.execute_payout(&group_id);
+        assert_eq!(
+            token_client.balance(recipient),
+            balance_before + CONTRIBUTION * 4
+        );
+    }
+
+    assert!(client.is_complete(&group_id));
+    let schedule = client.get_payout_schedule(&group_id);
+    assert!(schedule.iter().all(|position| position.paid_out));
+}
+
+#[test]
+fn test_multi_seat_prepayment_and_refund() {
+    let (_env, client, group_id, _token, creator, member2, _) = setup_test_env();
+    client.join_group(&member2, &group_id, &2u32);
+
+    // Two seats over three cycles
+    let result = client.try_contribute_amount(&member2, &group_id, &(CONTRIBUTION * 6 + 1));
+    assert_eq!(result, Err(Ok(AjoError::ContributionOutOfRange)));
+
+    client.contribute_amount(&member2, &group_id, &(CONTRIBUTION * 4));
+    let progress = client.get_contribution_progress(&group_id, &member2);
+    assert!(progress.contributed);
+    assert_eq!(progress.prepaid_cycles, 1);
+
+    client.cancel_group(&creator, &group_id);
+    assert_eq!(
+        client.get_refund_record(&group_id, &member2).amount,
+        CONTRIBUTION * 4
+    );
+}
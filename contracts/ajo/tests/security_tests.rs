@@ -5,26 +5,54 @@
 //! These tests specifically target security vulnerabilities and edge cases
 //! that could lead to fund loss, unauthorized access, or state corruption.
 
-use soroban_sdk::{testutils::Address as _, Address, Env};
-use soroban_ajo::{AjoContract, AjoContractClient, AjoError};
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, CycleSchedule};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    Address, Env,
+};
 
 /// Helper function to create a test environment and contract
-fn setup_test_env() -> (Env, AjoContractClient<'static>, Address) {
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address) {
     let env = Env::default();
     env.mock_all_auths();
-    
+
     let contract_id = env.register_contract(None, AjoContract);
     let client = AjoContractClient::new(&env, &contract_id);
-    
+
     let admin = Address::generate(&env);
     client.initialize(&admin);
-    
-    (env, client, admin)
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+
+    (env, client, admin, token)
+}
+
+/// Generate multiple funded test addresses
+fn generate_addresses(env: &Env, token: &Address, count: usize) -> Vec<Address> {
+    (0..count)
+        .map(|_| {
+            let address = Address::generate(env);
+            fund(env, token, &address);
+            address
+        })
+        .collect()
+}
+
+/// Advance the ledger past the cycle end and grace period so a payout can run
+fn advance_past_grace_period(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 86_400 + 1;
+    });
 }
 
-/// Generate multiple test addresses
-fn generate_addresses(env: &Env, count: usize) -> Vec<Address> {
-    (0..count).map(|_| Address::generate(env)).collect()
+/// Helper function to mint enough tokens for an address to cover any test scenario
+fn fund(env: &Env, token: &Address, address: &Address) {
+    StellarAssetClient::new(env, token).mint(address, &1_000_000_000_000_000_000);
 }
 
 // ============================================================================
@@ -33,49 +61,59 @@ fn generate_addresses(env: &Env, count: usize) -> Vec<Address> {
 
 #[test]
 fn test_security_unauthorized_pause() {
-    let (env, client, _admin) = setup_test_env();
-    let attacker = Address::generate(&env);
-    
-    // Attacker tries to pause without being admin
+    let (env, client, _admin, _token) = setup_test_env();
+
+    // Attacker tries to pause without the admin's signature
+    env.set_auths(&[]);
     let result = client.try_pause();
-    assert_eq!(result, Err(Ok(AjoError::UnauthorizedPause)));
+    assert!(result.is_err());
 }
 
 #[test]
 fn test_security_unauthorized_unpause() {
-    let (env, client, admin) = setup_test_env();
-    
+    let (env, client, _admin, _token) = setup_test_env();
+
     // Admin pauses
     client.pause();
-    
-    // Attacker tries to unpause
-    let attacker = Address::generate(&env);
+
+    // Attacker tries to unpause without the admin's signature
+    env.set_auths(&[]);
     let result = client.try_unpause();
-    assert_eq!(result, Err(Ok(AjoError::UnauthorizedUnpause)));
+    assert!(result.is_err());
 }
 
 #[test]
 fn test_security_unauthorized_upgrade() {
-    let (env, client, _admin) = setup_test_env();
-    let attacker = Address::generate(&env);
-    
+    let (env, client, _admin, _token) = setup_test_env();
+
     // Create fake wasm hash
     let fake_wasm = [0u8; 32];
     let wasm_hash = soroban_sdk::BytesN::from_array(&env, &fake_wasm);
-    
-    // Attacker tries to upgrade
+
+    // Attacker tries to upgrade without the admin's signature
+    env.set_auths(&[]);
     let result = client.try_upgrade(&wasm_hash);
-    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+    assert!(result.is_err());
 }
 
 #[test]
 fn test_security_non_member_contribution() {
-    let (env, client, _admin) = setup_test_env();
+    let (env, client, _admin, token) = setup_test_env();
     let creator = Address::generate(&env);
     let attacker = Address::generate(&env);
-    
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &5u32);
-    
+
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &5u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Attacker tries to contribute without being a member
     let result = client.try_contribute(&attacker, &group_id);
     assert_eq!(result, Err(Ok(AjoError::NotMember)));
@@ -87,16 +125,26 @@ fn test_security_non_member_contribution() {
 
 #[test]
 fn test_security_double_contribution_same_cycle() {
-    let (env, client, _admin) = setup_test_env();
-    let members = generate_addresses(&env, 3);
-    
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &3u32);
-    client.join_group(&members[1], &group_id);
-    client.join_group(&members[2], &group_id);
-    
+    let (env, client, _admin, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 3);
+
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&members[1], &group_id, &1u32);
+    client.join_group(&members[2], &group_id, &1u32);
+
     // First contribution succeeds
     client.contribute(&members[0], &group_id);
-    
+
     // Second contribution from same member should fail
     let result = client.try_contribute(&members[0], &group_id);
     assert_eq!(result, Err(Ok(AjoError::AlreadyContributed)));
@@ -104,32 +152,54 @@ fn test_security_double_contribution_same_cycle() {
 
 #[test]
 fn test_security_double_payout_attempt() {
-    let (env, client, _admin) = setup_test_env();
-    let members = generate_addresses(&env, 2);
-    
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &2u32);
-    client.join_group(&members[1], &group_id);
-    
+    let (env, client, _admin, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 2);
+
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&members[1], &group_id, &1u32);
+
     // Complete first cycle
     client.contribute(&members[0], &group_id);
     client.contribute(&members[1], &group_id);
+    advance_past_grace_period(&env);
     client.execute_payout(&group_id);
-    
+
     // Try to execute payout again without contributions
+    advance_past_grace_period(&env);
     let result = client.try_execute_payout(&group_id);
     assert_eq!(result, Err(Ok(AjoError::IncompleteContributions)));
 }
 
 #[test]
 fn test_security_join_after_already_member() {
-    let (env, client, _admin) = setup_test_env();
-    let members = generate_addresses(&env, 2);
-    
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &5u32);
-    client.join_group(&members[1], &group_id);
-    
+    let (env, client, _admin, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 2);
+
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &5u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&members[1], &group_id, &1u32);
+
     // Try to join again
-    let result = client.try_join_group(&members[1], &group_id);
+    let result = client.try_join_group(&members[1], &group_id, &1u32);
     assert_eq!(result, Err(Ok(AjoError::AlreadyMember)));
 }
 
@@ -139,56 +209,116 @@ fn test_security_join_after_already_member() {
 
 #[test]
 fn test_security_zero_contribution_amount() {
-    let (env, client, _admin) = setup_test_env();
+    let (env, client, _admin, token) = setup_test_env();
     let creator = Address::generate(&env);
-    
-    let result = client.try_create_group(&creator, &0i128, &604_800u64, &5u32);
+
+    let result = client.try_create_group(
+        &creator,
+        &token,
+        &0i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &5u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     assert_eq!(result, Err(Ok(AjoError::ContributionAmountZero)));
 }
 
 #[test]
 fn test_security_negative_contribution_amount() {
-    let (env, client, _admin) = setup_test_env();
+    let (env, client, _admin, token) = setup_test_env();
     let creator = Address::generate(&env);
-    
-    let result = client.try_create_group(&creator, &-1000i128, &604_800u64, &5u32);
+
+    let result = client.try_create_group(
+        &creator,
+        &token,
+        &-1000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &5u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     assert_eq!(result, Err(Ok(AjoError::ContributionAmountNegative)));
 }
 
 #[test]
 fn test_security_zero_cycle_duration() {
-    let (env, client, _admin) = setup_test_env();
+    let (env, client, _admin, token) = setup_test_env();
     let creator = Address::generate(&env);
-    
-    let result = client.try_create_group(&creator, &100_000_000i128, &0u64, &5u32);
+
+    let result = client.try_create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(0u64),
+        &5u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     assert_eq!(result, Err(Ok(AjoError::CycleDurationZero)));
 }
 
 #[test]
 fn test_security_max_members_below_minimum() {
-    let (env, client, _admin) = setup_test_env();
+    let (env, client, _admin, token) = setup_test_env();
     let creator = Address::generate(&env);
-    
-    let result = client.try_create_group(&creator, &100_000_000i128, &604_800u64, &1u32);
+
+    let result = client.try_create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &1u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     assert_eq!(result, Err(Ok(AjoError::MaxMembersBelowMinimum)));
 }
 
 #[test]
 fn test_security_max_members_above_limit() {
-    let (env, client, _admin) = setup_test_env();
+    let (env, client, _admin, token) = setup_test_env();
     let creator = Address::generate(&env);
-    
-    let result = client.try_create_group(&creator, &100_000_000i128, &604_800u64, &101u32);
+
+    let result = client.try_create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &101u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     assert_eq!(result, Err(Ok(AjoError::MaxMembersAboveLimit)));
 }
 
 #[test]
 fn test_security_max_members_at_limit() {
-    let (env, client, _admin) = setup_test_env();
+    let (env, client, _admin, token) = setup_test_env();
     let creator = Address::generate(&env);
-    
+
     // Should succeed with exactly 100 members
-    let result = client.try_create_group(&creator, &100_000_000i128, &604_800u64, &100u32);
+    let result = client.try_create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &100u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     assert!(result.is_ok());
 }
 
@@ -198,37 +328,59 @@ fn test_security_max_members_at_limit() {
 
 #[test]
 fn test_security_payout_without_all_contributions() {
-    let (env, client, _admin) = setup_test_env();
-    let members = generate_addresses(&env, 3);
-    
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &3u32);
-    client.join_group(&members[1], &group_id);
-    client.join_group(&members[2], &group_id);
-    
+    let (env, client, _admin, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 3);
+
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&members[1], &group_id, &1u32);
+    client.join_group(&members[2], &group_id, &1u32);
+
     // Only 2 out of 3 contribute
     client.contribute(&members[0], &group_id);
     client.contribute(&members[1], &group_id);
-    
+
     // Payout should fail
+    advance_past_grace_period(&env);
     let result = client.try_execute_payout(&group_id);
     assert_eq!(result, Err(Ok(AjoError::IncompleteContributions)));
 }
 
 #[test]
 fn test_security_contribute_to_completed_group() {
-    let (env, client, _admin) = setup_test_env();
-    let members = generate_addresses(&env, 2);
-    
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &2u32);
-    client.join_group(&members[1], &group_id);
-    
+    let (env, client, _admin, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 2);
+
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&members[1], &group_id, &1u32);
+
     // Complete all cycles
     for _ in 0..2 {
         client.contribute(&members[0], &group_id);
         client.contribute(&members[1], &group_id);
+        advance_past_grace_period(&env);
         client.execute_payout(&group_id);
     }
-    
+
     // Try to contribute to completed group
     let result = client.try_contribute(&members[0], &group_id);
     assert_eq!(result, Err(Ok(AjoError::GroupComplete)));
@@ -236,40 +388,63 @@ fn test_security_contribute_to_completed_group() {
 
 #[test]
 fn test_security_join_completed_group() {
-    let (env, client, _admin) = setup_test_env();
-    let members = generate_addresses(&env, 3);
-    
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &2u32);
-    client.join_group(&members[1], &group_id);
-    
+    let (env, client, _admin, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 3);
+
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&members[1], &group_id, &1u32);
+
     // Complete all cycles
     for _ in 0..2 {
         client.contribute(&members[0], &group_id);
         client.contribute(&members[1], &group_id);
+        advance_past_grace_period(&env);
         client.execute_payout(&group_id);
     }
-    
+
     // Try to join completed group
-    let result = client.try_join_group(&members[2], &group_id);
+    let result = client.try_join_group(&members[2], &group_id, &1u32);
     assert_eq!(result, Err(Ok(AjoError::GroupComplete)));
 }
 
 #[test]
 fn test_security_payout_to_completed_group() {
-    let (env, client, _admin) = setup_test_env();
-    let members = generate_addresses(&env, 2);
-    
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &2u32);
-    client.join_group(&members[1], &group_id);
-    
+    let (env, client, _admin, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 2);
+
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&members[1], &group_id, &1u32);
+
     // Complete all cycles
     for _ in 0..2 {
         client.contribute(&members[0], &group_id);
         client.contribute(&members[1], &group_id);
+        advance_past_grace_period(&env);
         client.execute_payout(&group_id);
     }
-    
+
     // Try to execute another payout
+    advance_past_grace_period(&env);
     let result = client.try_execute_payout(&group_id);
     assert_eq!(result, Err(Ok(AjoError::GroupComplete)));
 }
@@ -280,40 +455,65 @@ fn test_security_payout_to_completed_group() {
 
 #[test]
 fn test_security_join_full_group() {
-    let (env, client, _admin) = setup_test_env();
-    let members = generate_addresses(&env, 4);
-    
+    let (env, client, _admin, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 4);
+
     // Create group with max 3 members
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &3u32);
-    client.join_group(&members[1], &group_id);
-    client.join_group(&members[2], &group_id);
-    
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&members[1], &group_id, &1u32);
+    client.join_group(&members[2], &group_id, &1u32);
+
     // Try to add 4th member
-    let result = client.try_join_group(&members[3], &group_id);
+    let result = client.try_join_group(&members[3], &group_id, &1u32);
     assert_eq!(result, Err(Ok(AjoError::MaxMembersExceeded)));
 }
 
 #[test]
 fn test_security_large_group_operations() {
-    let (env, client, _admin) = setup_test_env();
-    let members = generate_addresses(&env, 50);
-    
+    let (env, client, _admin, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 50);
+
     // Create group with 50 members
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &50u32);
-    
-    // Add all members
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &50u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
+    // Add all members. The test budget accumulates across calls, so each call
+    // gets a fresh budget as it would on-chain.
     for member in &members[1..] {
-        client.join_group(member, &group_id);
+        env.budget().reset_default();
+        client.join_group(member, &group_id, &1u32);
     }
-    
+
     // All members contribute
     for member in &members {
+        env.budget().reset_default();
         client.contribute(member, &group_id);
     }
-    
+
     // Execute payout should work
+    advance_past_grace_period(&env);
+    env.budget().reset_default();
     client.execute_payout(&group_id);
-    
+
     let group = client.get_group(&group_id);
     assert_eq!(group.current_cycle, 2);
 }
@@ -324,44 +524,74 @@ fn test_security_large_group_operations() {
 
 #[test]
 fn test_security_pause_blocks_create_group() {
-    let (env, client, admin) = setup_test_env();
+    let (env, client, _admin, token) = setup_test_env();
     let creator = Address::generate(&env);
-    
+
     // Pause contract
     client.pause();
-    
+
     // Try to create group
-    let result = client.try_create_group(&creator, &100_000_000i128, &604_800u64, &5u32);
+    let result = client.try_create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &5u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     assert_eq!(result, Err(Ok(AjoError::ContractPaused)));
 }
 
 #[test]
 fn test_security_pause_blocks_join_group() {
-    let (env, client, admin) = setup_test_env();
-    let members = generate_addresses(&env, 2);
-    
+    let (env, client, _admin, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 2);
+
     // Create group before pause
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &5u32);
-    
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &5u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Pause contract
     client.pause();
-    
+
     // Try to join
-    let result = client.try_join_group(&members[1], &group_id);
+    let result = client.try_join_group(&members[1], &group_id, &1u32);
     assert_eq!(result, Err(Ok(AjoError::ContractPaused)));
 }
 
 #[test]
 fn test_security_pause_blocks_contribute() {
-    let (env, client, admin) = setup_test_env();
+    let (env, client, _admin, token) = setup_test_env();
     let creator = Address::generate(&env);
-    
+
     // Create group before pause
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &5u32);
-    
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &5u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Pause contract
     client.pause();
-    
+
     // Try to contribute
     let result = client.try_contribute(&creator, &group_id);
     assert_eq!(result, Err(Ok(AjoError::ContractPaused)));
@@ -369,67 +599,98 @@ fn test_security_pause_blocks_contribute() {
 
 #[test]
 fn test_security_pause_blocks_payout() {
-    let (env, client, admin) = setup_test_env();
-    let members = generate_addresses(&env, 2);
-    
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &2u32);
-    client.join_group(&members[1], &group_id);
-    
+    let (env, client, _admin, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 2);
+
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&members[1], &group_id, &1u32);
+
     // All contribute
     client.contribute(&members[0], &group_id);
     client.contribute(&members[1], &group_id);
-    
+
     // Pause contract
     client.pause();
-    
+
     // Try to execute payout
+    advance_past_grace_period(&env);
     let result = client.try_execute_payout(&group_id);
     assert_eq!(result, Err(Ok(AjoError::ContractPaused)));
 }
 
 #[test]
 fn test_security_pause_allows_queries() {
-    let (env, client, admin) = setup_test_env();
+    let (env, client, _admin, token) = setup_test_env();
     let creator = Address::generate(&env);
-    
+
     // Create group before pause
-    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &5u32);
-    
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &5u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Pause contract
     client.pause();
-    
+
     // Queries should still work
     let group = client.get_group(&group_id);
     assert_eq!(group.id, group_id);
-    
+
     let members = client.list_members(&group_id);
     assert_eq!(members.len(), 1);
-    
+
     let is_member = client.is_member(&group_id, &creator);
-    assert_eq!(is_member, true);
+    assert!(is_member);
 }
 
 #[test]
 fn test_security_unpause_restores_functionality() {
-    let (env, client, admin) = setup_test_env();
-    let members = generate_addresses(&env, 2);
-    
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &5u32);
-    
+    let (env, client, _admin, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 2);
+
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &5u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     // Pause
     client.pause();
-    
+
     // Verify paused
-    let result = client.try_join_group(&members[1], &group_id);
+    let result = client.try_join_group(&members[1], &group_id, &1u32);
     assert_eq!(result, Err(Ok(AjoError::ContractPaused)));
-    
+
     // Unpause
     client.unpause();
-    
+
     // Should work now
-    client.join_group(&members[1], &group_id);
+    client.join_group(&members[1], &group_id, &1u32);
     let group = client.get_group(&group_id);
-    assert_eq!(group.members.len(), 2);
+    assert_eq!(group.member_count, 2);
 }
 
 // ============================================================================
@@ -438,36 +699,57 @@ fn test_security_unpause_restores_functionality() {
 
 #[test]
 fn test_security_large_contribution_amount() {
-    let (env, client, _admin) = setup_test_env();
+    let (env, client, _admin, token) = setup_test_env();
     let creator = Address::generate(&env);
-    
+
     // Try with very large amount (should succeed if within i128 range)
     let large_amount = 1_000_000_000_000_000i128; // 100 million XLM
-    let result = client.try_create_group(&creator, &large_amount, &604_800u64, &5u32);
+    let result = client.try_create_group(
+        &creator,
+        &token,
+        &large_amount,
+        &CycleSchedule::Fixed(604_800u64),
+        &5u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     assert!(result.is_ok());
 }
 
 #[test]
 fn test_security_payout_calculation_no_overflow() {
-    let (env, client, _admin) = setup_test_env();
-    let members = generate_addresses(&env, 10);
-    
+    let (env, client, _admin, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 10);
+
     // Large contribution with multiple members
     let contribution = 100_000_000_000i128; // 10,000 XLM
-    let group_id = client.create_group(&members[0], &contribution, &604_800u64, &10u32);
-    
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &contribution,
+        &CycleSchedule::Fixed(604_800u64),
+        &10u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
     for member in &members[1..] {
-        client.join_group(member, &group_id);
+        client.join_group(member, &group_id, &1u32);
     }
-    
+
     // All contribute
     for member in &members {
         client.contribute(member, &group_id);
     }
-    
+
     // Payout should calculate correctly (10,000 XLM × 10 members = 100,000 XLM)
+    advance_past_grace_period(&env);
     client.execute_payout(&group_id);
-    
+
     let group = client.get_group(&group_id);
     assert_eq!(group.current_cycle, 2);
 }
@@ -478,18 +760,28 @@ fn test_security_payout_calculation_no_overflow() {
 
 #[test]
 fn test_security_metadata_unauthorized_update() {
-    let (env, client, _admin) = setup_test_env();
-    let members = generate_addresses(&env, 2);
-    
-    let group_id = client.create_group(&members[0], &100_000_000i128, &604_800u64, &5u32);
-    client.join_group(&members[1], &group_id);
-    
+    let (env, client, _admin, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 2);
+
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &5u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&members[1], &group_id, &1u32);
+
     // Non-creator tries to set metadata
     let name = soroban_sdk::String::from_str(&env, "Test Group");
     let desc = soroban_sdk::String::from_str(&env, "Description");
     let rules = soroban_sdk::String::from_str(&env, "Rules");
-    
-    let result = client.try_set_group_metadata(&group_id, &name, &desc, &rules);
+
+    let result = client.try_set_group_metadata(&members[1], &group_id, &name, &desc, &rules);
     assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
 }
 
@@ -499,29 +791,50 @@ fn test_security_metadata_unauthorized_update() {
 
 #[test]
 fn test_security_multiple_groups_isolation() {
-    let (env, client, _admin) = setup_test_env();
-    let members = generate_addresses(&env, 4);
-    
+    let (env, client, _admin, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 4);
+
     // Create two groups
-    let group_id1 = client.create_group(&members[0], &100_000_000i128, &604_800u64, &2u32);
-    let group_id2 = client.create_group(&members[2], &200_000_000i128, &1_209_600u64, &2u32);
-    
-    client.join_group(&members[1], &group_id1);
-    client.join_group(&members[3], &group_id2);
-    
+    let group_id1 = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    let group_id2 = client.create_group(
+        &members[2],
+        &token,
+        &200_000_000i128,
+        &CycleSchedule::Fixed(1_209_600u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
+    client.join_group(&members[1], &group_id1, &1u32);
+    client.join_group(&members[3], &group_id2, &1u32);
+
     // Contribute to group 1
     client.contribute(&members[0], &group_id1);
     client.contribute(&members[1], &group_id1);
-    
+
     // Group 2 should not be affected
     let status2 = client.get_contribution_status(&group_id2, &1u32);
     for (_, contributed) in status2.iter() {
-        assert_eq!(contributed, false);
+        assert!(!contributed);
     }
-    
+
     // Execute payout for group 1
+    advance_past_grace_period(&env);
     client.execute_payout(&group_id1);
-    
+
     // Group 2 should still be on cycle 1
     let group2 = client.get_group(&group_id2);
     assert_eq!(group2.current_cycle, 1);
@@ -529,27 +842,53 @@ fn test_security_multiple_groups_isolation() {
 
 #[test]
 fn test_security_member_in_multiple_groups() {
-    let (env, client, _admin) = setup_test_env();
-    let members = generate_addresses(&env, 3);
-    
+    let (env, client, _admin, token) = setup_test_env();
+    let members = generate_addresses(&env, &token, 3);
+
     // Member 0 creates and joins both groups
-    let group_id1 = client.create_group(&members[0], &100_000_000i128, &604_800u64, &2u32);
-    let group_id2 = client.create_group(&members[0], &200_000_000i128, &604_800u64, &2u32);
-    
-    client.join_group(&members[1], &group_id1);
-    client.join_group(&members[2], &group_id2);
-    
+    let group_id1 = client.create_group(
+        &members[0],
+        &token,
+        &100_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    let group_id2 = client.create_group(
+        &members[0],
+        &token,
+        &200_000_000i128,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
+    client.join_group(&members[1], &group_id1, &1u32);
+    client.join_group(&members[2], &group_id2, &1u32);
+
     // Member 0 can contribute to both independently
     client.contribute(&members[0], &group_id1);
     client.contribute(&members[0], &group_id2);
-    
+
     // Verify contributions are tracked separately
-    assert_eq!(
-        client.get_contribution_status(&group_id1, &1u32).get(0).unwrap().1,
-        true
+    assert!(
+        client
+            .get_contribution_status(&group_id1, &1u32)
+            .get(0)
+            .unwrap()
+            .1
     );
-    assert_eq!(
-        client.get_contribution_status(&group_id2, &1u32).get(0).unwrap().1,
-        true
+    assert!(
+        client
+            .get_contribution_status(&group_id2, &1u32)
+            .get(0)
+            .unwrap()
+            .1
     );
 }
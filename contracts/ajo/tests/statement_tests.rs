@@ -0,0 +1,177 @@
+#![cfg(test)]
+
+//! Tests for member statements
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, CycleSchedule, RefundReason};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    vec, Address, Env, Vec,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+
+/// Helper function to create a contract, a registered token and three funded members
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    for addr in [&alice, &bob, &carol] {
+        StellarAssetClient::new(&env, &token).mint(addr, &1_000_000_000_000);
+    }
+
+    (env, client, token, alice, bob, carol)
+}
+
+/// Helper function to create a three-member group with 1% insurance
+fn create_group(
+    client: &AjoContractClient,
+    token: &Address,
+    alice: &Address,
+    bob: &Address,
+    carol: &Address,
+) -> u64 {
+    let group_id = client.create_group(
+        alice,
+        token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(604_800u64),
+        &3u32,
+        &86400u64,
+        &5u32,
+        &100u32,
+        &0u32,
+    );
+    client.join_group(bob, &group_id, &1u32);
+    client.join_group(carol, &group_id, &1u32);
+    group_id
+}
+
+#[test]
+fn test_member_statement_tracks_money_in_and_out() {
+    let (env, client, token, alice, bob, carol) = setup_test_env();
+    let group_id = create_group(&client, &token, &alice, &bob, &carol);
+
+    // Carol pays the first cycle late; alice is paid out
+    client.contribute(&alice, &group_id);
+    client.contribute(&bob, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 3600;
+    });
+    client.contribute(&carol, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400;
+    });
+    client.execute_payout(&group_id);
+
+    // Alice prepays a cycle on top of the second one
+    client.contribute_amount(&alice, &group_id, &(CONTRIBUTION * 2));
+
+    let statement = client.get_member_statement(&group_id, &alice);
+    assert_eq!(statement.seats, 1);
+    assert_eq!(statement.contributions.len(), 2);
+    assert!(statement.unpaid_cycles.is_empty());
+    assert_eq!(statement.payouts.len(), 1);
+    assert!(statement.refunds.is_empty());
+
+    let penalty = CONTRIBUTION * 5 / 100;
+    let totals = statement.totals;
+    assert_eq!(totals.contributed, CONTRIBUTION * 2);
+    assert_eq!(totals.penalties, 0);
+    assert_eq!(totals.insurance_premiums, CONTRIBUTION * 2 / 100);
+    assert_eq!(totals.credit, CONTRIBUTION);
+    assert_eq!(totals.payouts_received, CONTRIBUTION * 3 + penalty);
+    assert_eq!(totals.amount_due, 0);
+    assert_eq!(totals.net_position, penalty);
+
+    let statement = client.get_member_statement(&group_id, &carol);
+    let late = statement.contributions.get(0).unwrap();
+    assert!(late.is_late);
+    assert_eq!(late.penalty_amount, penalty);
+    assert_eq!(statement.unpaid_cycles, vec![&env, 2u32]);
+    assert!(statement.payouts.is_empty());
+
+    let totals = statement.totals;
+    assert_eq!(totals.penalties, penalty);
+    assert_eq!(totals.insurance_premiums, CONTRIBUTION / 100);
+    assert_eq!(totals.amount_due, CONTRIBUTION);
+    assert_eq!(totals.net_position, -(CONTRIBUTION + penalty));
+}
+
+#[test]
+fn test_member_statement_includes_refund() {
+    let (env, client, token, alice, bob, carol) = setup_test_env();
+    let group_id = create_group(&client, &token, &alice, &bob, &carol);
+    client.contribute(&bob, &group_id);
+    client.cancel_group(&alice, &group_id);
+
+    let statement = client.get_member_statement(&group_id, &bob);
+    assert_eq!(statement.refunds.len(), 1);
+    assert_eq!(
+        statement.refunds.get(0).unwrap().reason,
+        RefundReason::CreatorCancellation
+    );
+    assert_eq!(statement.totals.refunded, CONTRIBUTION);
+    assert_eq!(statement.totals.net_position, 0);
+
+    // A cancelled group asks for nothing more
+    let statement = client.get_member_statement(&group_id, &carol);
+    assert_eq!(statement.unpaid_cycles, vec![&env, 1u32]);
+    assert_eq!(statement.totals.amount_due, 0);
+
+    let stranger = Address::generate(&env);
+    let result = client.try_get_member_statement(&group_id, &stranger);
+    assert_eq!(result, Err(Ok(AjoError::NotMember)));
+}
+
+#[test]
+fn test_member_summary_lists_totals_per_group() {
+    let (env, client, token, alice, bob, carol) = setup_test_env();
+    let first = create_group(&client, &token, &alice, &bob, &carol);
+    let second = create_group(&client, &token, &bob, &alice, &carol);
+    client.contribute(&alice, &second);
+
+    let summary = client.get_member_summary(&alice, &vec![&env, second, first]);
+    assert_eq!(
+        summary,
+        vec![
+            &env,
+            client.get_member_statement(&second, &alice).totals,
+            client.get_member_statement(&first, &alice).totals,
+        ]
+    );
+    assert_eq!(summary.get(0).unwrap().contributed, CONTRIBUTION);
+
+    let stranger = Address::generate(&env);
+    let result = client.try_get_member_summary(&stranger, &vec![&env, first]);
+    assert_eq!(result, Err(Ok(AjoError::NotMember)));
+
+    let mut too_many = Vec::new(&env);
+    for _ in 0..11 {
+        too_many.push_back(first);
+    }
+    let result = client.try_get_member_summary(&alice, &too_many);
+    assert_eq!(result, Err(Ok(AjoError::LimitExceeded)));
+}
@@ -0,0 +1,237 @@
+#![cfg(test)]
+
+//! Tests for group templates
+
+use soroban_ajo::{
+    AjoContract, AjoContractClient, AjoError, CycleSchedule, MonthlySchedule, PauseFlag,
+    ScheduleOverride, TemplateOverrides, TemplateParams, WeeklySchedule,
+};
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+const CONTRIBUTION: i128 = 100_000_000;
+
+/// Helper function to create a contract with a registered token bounded to
+/// contributions between 1 and 1_000 XLM
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_supported_token(&token, &10_000_000i128, &10_000_000_000i128);
+
+    (env, client, admin, token)
+}
+
+fn weekly_params(token: &Address) -> TemplateParams {
+    TemplateParams {
+        token_address: token.clone(),
+        contribution_amount: CONTRIBUTION,
+        schedule: CycleSchedule::Fixed(604_800),
+        max_members: 10,
+        grace_period: 86400,
+        penalty_rate: 5,
+        insurance_rate_bps: 0,
+        organizer_fee_bps: 0,
+    }
+}
+
+#[test]
+fn test_create_group_from_published_template() {
+    let (env, client, _admin, token) = setup_test_env();
+    let name = String::from_str(&env, "Weekly market ajo");
+    let rules = String::from_str(&env, "Pay by Friday evening");
+    let template_id = client.publish_template(&name, &weekly_params(&token), &rules);
+
+    let template = client.get_template(&template_id);
+    assert_eq!(template.owner, None);
+    assert_eq!(template.name, name);
+
+    let creator = Address::generate(&env);
+    let group_id =
+        client.create_group_from_template(&creator, &template_id, &TemplateOverrides::default());
+
+    let group = client.get_group(&group_id);
+    assert_eq!(group.creator, creator);
+    assert_eq!(group.token_address, token);
+    assert_eq!(group.contribution_amount, CONTRIBUTION);
+    assert_eq!(group.schedule, CycleSchedule::Fixed(604_800));
+    assert_eq!(group.max_members, 10);
+    assert_eq!(group.grace_period, 86400);
+    assert_eq!(group.penalty_rate, 5);
+
+    let metadata = client.get_group_metadata(&group_id);
+    assert_eq!(metadata.name, name);
+    assert_eq!(metadata.rules, rules);
+}
+
+#[test]
+fn test_overrides_are_validated_like_create_group() {
+    let (env, client, _admin, token) = setup_test_env();
+    let name = String::from_str(&env, "Weekly");
+    let template_id = client.publish_template(&name, &weekly_params(&token), &name);
+    let creator = Address::generate(&env);
+
+    let overrides = TemplateOverrides {
+        contribution_amount: Some(CONTRIBUTION * 5),
+        max_members: Some(4),
+        ..Default::default()
+    };
+    let group_id = client.create_group_from_template(&creator, &template_id, &overrides);
+    let group = client.get_group(&group_id);
+    assert_eq!(group.contribution_amount, CONTRIBUTION * 5);
+    assert_eq!(group.max_members, 4);
+    assert_eq!(group.penalty_rate, 5);
+
+    let overrides = TemplateOverrides {
+        max_members: Some(1),
+        ..Default::default()
+    };
+    let result = client.try_create_group_from_template(&creator, &template_id, &overrides);
+    assert_eq!(result, Err(Ok(AjoError::MaxMembersBelowMinimum)));
+
+    let overrides = TemplateOverrides {
+        penalty_rate: Some(101),
+        ..Default::default()
+    };
+    let result = client.try_create_group_from_template(&creator, &template_id, &overrides);
+    assert_eq!(result, Err(Ok(AjoError::InvalidPenaltyRate)));
+
+    // The grace period must stay shorter than the template's weekly cycle
+    let overrides = TemplateOverrides {
+        grace_period: Some(604_800),
+        ..Default::default()
+    };
+    let result = client.try_create_group_from_template(&creator, &template_id, &overrides);
+    assert_eq!(result, Err(Ok(AjoError::InvalidGracePeriod)));
+
+    let overrides = TemplateOverrides {
+        contribution_amount: Some(1),
+        ..Default::default()
+    };
+    let result = client.try_create_group_from_template(&creator, &template_id, &overrides);
+    assert_eq!(result, Err(Ok(AjoError::ContributionOutOfRange)));
+
+    let result =
+        client.try_create_group_from_template(&creator, &99, &TemplateOverrides::default());
+    assert_eq!(result, Err(Ok(AjoError::TemplateNotFound)));
+}
+
+#[test]
+fn test_schedule_override_is_validated() {
+    let (env, client, _admin, token) = setup_test_env();
+    let name = String::from_str(&env, "Weekly");
+    let template_id = client.publish_template(&name, &weekly_params(&token), &name);
+    let creator = Address::generate(&env);
+
+    let friday = CycleSchedule::Weekly(WeeklySchedule {
+        weekday: 4,
+        time_of_day: 18 * 3600,
+        utc_offset: 3600,
+    });
+    let overrides = TemplateOverrides {
+        schedule: ScheduleOverride::Replace(friday.clone()),
+        ..Default::default()
+    };
+    let group_id = client.create_group_from_template(&creator, &template_id, &overrides);
+    assert_eq!(client.get_group(&group_id).schedule, friday);
+
+    let overrides = TemplateOverrides {
+        schedule: ScheduleOverride::Replace(CycleSchedule::Monthly(MonthlySchedule {
+            day: 0,
+            time_of_day: 0,
+            utc_offset: 0,
+        })),
+        ..Default::default()
+    };
+    let result = client.try_create_group_from_template(&creator, &template_id, &overrides);
+    assert_eq!(result, Err(Ok(AjoError::InvalidSchedule)));
+
+    // A daily cycle is no longer than the template's one-day grace period
+    let overrides = TemplateOverrides {
+        schedule: ScheduleOverride::Replace(CycleSchedule::Fixed(86400)),
+        ..Default::default()
+    };
+    let result = client.try_create_group_from_template(&creator, &template_id, &overrides);
+    assert_eq!(result, Err(Ok(AjoError::InvalidGracePeriod)));
+
+    // While group creation is paused, the pause is reported before the
+    // overrides are validated
+    client.pause_operation(&PauseFlag::GroupCreation);
+    let result = client.try_create_group_from_template(&creator, &template_id, &overrides);
+    assert_eq!(result, Err(Ok(AjoError::ContractPaused)));
+}
+
+#[test]
+fn test_save_template_rejects_invalid_configurations() {
+    let (env, client, _admin, token) = setup_test_env();
+    let owner = Address::generate(&env);
+    let name = String::from_str(&env, "Mine");
+
+    let mut params = weekly_params(&token);
+    params.grace_period = 700_000;
+    let result = client.try_save_template(&owner, &name, &params, &name);
+    assert_eq!(result, Err(Ok(AjoError::InvalidGracePeriod)));
+
+    let mut params = weekly_params(&token);
+    params.token_address = Address::generate(&env);
+    let result = client.try_save_template(&owner, &name, &params, &name);
+    assert_eq!(result, Err(Ok(AjoError::InvalidTokenAddress)));
+
+    let long_name = String::from_str(&env, &"x".repeat(51));
+    let result = client.try_save_template(&owner, &long_name, &weekly_params(&token), &name);
+    assert_eq!(result, Err(Ok(AjoError::MetadataTooLong)));
+
+    let template_id = client.save_template(&owner, &name, &weekly_params(&token), &name);
+    assert_eq!(client.get_template(&template_id).owner, Some(owner));
+}
+
+#[test]
+fn test_list_and_remove_templates() {
+    let (env, client, _admin, token) = setup_test_env();
+    let owner = Address::generate(&env);
+    let name = String::from_str(&env, "Template");
+    let published = client.publish_template(&name, &weekly_params(&token), &name);
+    let removed = client.save_template(&owner, &name, &weekly_params(&token), &name);
+    let saved = client.save_template(&owner, &name, &weekly_params(&token), &name);
+
+    let stranger = Address::generate(&env);
+    let result = client.try_remove_template(&stranger, &removed);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+    let result = client.try_remove_template(&owner, &published);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+
+    client.remove_template(&owner, &removed);
+    let result = client.try_get_template(&removed);
+    assert_eq!(result, Err(Ok(AjoError::TemplateNotFound)));
+
+    let ids = |start: u64, limit: u32| -> std::vec::Vec<u64> {
+        client
+            .list_templates(&start, &limit)
+            .iter()
+            .map(|template| template.id)
+            .collect()
+    };
+    assert_eq!(ids(0, 10), [published, saved]);
+    assert_eq!(ids(removed, 1), [saved]);
+    assert!(ids(saved + 1, 10).is_empty());
+}
+
+#[test]
+fn test_admin_can_remove_any_template() {
+    let (env, client, admin, token) = setup_test_env();
+    let owner = Address::generate(&env);
+    let name = String::from_str(&env, "Template");
+    let template_id = client.save_template(&owner, &name, &weekly_params(&token), &name);
+
+    client.remove_template(&admin, &template_id);
+    assert!(client.list_templates(&0, &10).is_empty());
+}
@@ -0,0 +1,173 @@
+#![cfg(test)]
+
+//! Tests for the admin-managed token registry
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, CycleSchedule};
+use soroban_sdk::{
+    testutils::Address as _,
+    token::{Client as TokenClient, StellarAssetClient},
+    Address, Env,
+};
+
+/// Helper function to create an initialized contract and an unregistered token
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    (env, client, admin, token)
+}
+
+/// Helper function to create a group with the given contribution amount
+fn try_create(
+    client: &AjoContractClient,
+    creator: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<u64, AjoError> {
+    match client.try_create_group(
+        creator,
+        token,
+        &amount,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    ) {
+        Ok(Ok(group_id)) => Ok(group_id),
+        Err(Ok(error)) => Err(error),
+        _ => panic!("unexpected invocation failure"),
+    }
+}
+
+#[test]
+fn test_add_supported_token_reads_metadata() {
+    let (env, client, _admin, token) = setup_test_env();
+    let token_client = TokenClient::new(&env, &token);
+
+    let info = client.add_supported_token(&token, &1_000i128, &1_000_000_000i128);
+    assert_eq!(info.address, token);
+    assert_eq!(info.decimals, token_client.decimals());
+    assert_eq!(info.symbol, token_client.symbol());
+
+    let tokens = client.list_supported_tokens();
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens.get(0).unwrap(), info);
+    assert_eq!(client.get_supported_token(&token), Some(info));
+}
+
+#[test]
+fn test_readding_token_updates_limits() {
+    let (_env, client, _admin, token) = setup_test_env();
+
+    client.add_supported_token(&token, &1_000i128, &1_000_000i128);
+    client.add_supported_token(&token, &10i128, &100i128);
+
+    let tokens = client.list_supported_tokens();
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens.get(0).unwrap().min_contribution, 10);
+    assert_eq!(tokens.get(0).unwrap().max_contribution, 100);
+}
+
+#[test]
+fn test_create_group_rejects_unlisted_token() {
+    let (env, client, _admin, token) = setup_test_env();
+    let creator = Address::generate(&env);
+
+    assert_eq!(
+        try_create(&client, &creator, &token, 100_000_000),
+        Err(AjoError::InvalidTokenAddress)
+    );
+
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+    assert!(try_create(&client, &creator, &token, 100_000_000).is_ok());
+}
+
+#[test]
+fn test_create_group_enforces_token_bounds() {
+    let (env, client, _admin, token) = setup_test_env();
+    let creator = Address::generate(&env);
+    client.add_supported_token(&token, &1_000i128, &5_000i128);
+
+    assert_eq!(
+        try_create(&client, &creator, &token, 999),
+        Err(AjoError::ContributionOutOfRange)
+    );
+    assert_eq!(
+        try_create(&client, &creator, &token, 5_001),
+        Err(AjoError::ContributionOutOfRange)
+    );
+    assert!(try_create(&client, &creator, &token, 1_000).is_ok());
+    assert!(try_create(&client, &creator, &token, 5_000).is_ok());
+}
+
+#[test]
+fn test_add_supported_token_rejects_invalid_bounds() {
+    let (_env, client, _admin, token) = setup_test_env();
+
+    let result = client.try_add_supported_token(&token, &0i128, &100i128);
+    assert_eq!(result, Err(Ok(AjoError::ContributionOutOfRange)));
+
+    let result = client.try_add_supported_token(&token, &100i128, &99i128);
+    assert_eq!(result, Err(Ok(AjoError::ContributionOutOfRange)));
+}
+
+#[test]
+fn test_add_supported_token_rejects_non_token_contract() {
+    let (env, client, _admin, _token) = setup_test_env();
+
+    // A contract that does not implement the SEP-41 interface
+    let not_a_token = env.register_contract(None, AjoContract);
+    let result = client.try_add_supported_token(&not_a_token, &1i128, &100i128);
+    assert_eq!(result, Err(Ok(AjoError::InvalidTokenAddress)));
+    assert_eq!(client.list_supported_tokens().len(), 0);
+}
+
+#[test]
+fn test_removed_token_only_blocks_new_groups() {
+    let (env, client, _admin, token) = setup_test_env();
+    let creator = Address::generate(&env);
+    StellarAssetClient::new(&env, &token).mint(&creator, &1_000_000_000_000);
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+
+    let group_id = try_create(&client, &creator, &token, 100_000_000).unwrap();
+    client.remove_supported_token(&token);
+
+    assert_eq!(client.list_supported_tokens().len(), 0);
+    assert_eq!(
+        try_create(&client, &creator, &token, 100_000_000),
+        Err(AjoError::InvalidTokenAddress)
+    );
+    client.contribute(&creator, &group_id);
+
+    let result = client.try_remove_supported_token(&token);
+    assert_eq!(result, Err(Ok(AjoError::InvalidTokenAddress)));
+}
+
+#[test]
+fn test_registry_requires_admin() {
+    let (env, client, _admin, token) = setup_test_env();
+
+    env.set_auths(&[]);
+    assert!(client
+        .try_add_supported_token(&token, &1i128, &100i128)
+        .is_err());
+
+    // Before initialization there is no admin to manage the registry
+    let contract_id = env.register_contract(None, AjoContract);
+    let uninitialized = AjoContractClient::new(&env, &contract_id);
+    let result = uninitialized.try_add_supported_token(&token, &1i128, &100i128);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+}
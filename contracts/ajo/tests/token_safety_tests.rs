@@ -0,0 +1,294 @@
+#![cfg(test)]
+
+//! Tests for transfer error mapping and balance-delta checks against tokens
+//! that misbehave
+
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, CycleSchedule};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error,
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+
+/// How the mock token handles transfers
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode {
+    /// Moves exactly the requested amount
+    Normal,
+    /// Burns 1% of every transfer before crediting the recipient
+    FeeOnTransfer,
+    /// Credits the recipient 1% more than was sent
+    Rebasing,
+    /// Reports success without moving anything
+    NoOp,
+    /// Fails with the Stellar Asset Contract balance error code
+    RejectBalance,
+    /// Fails with the Stellar Asset Contract allowance error code
+    RejectAllowance,
+    /// Panics without an error code
+    Panic,
+}
+
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MockTokenError {
+    Allowance = 9,
+    Balance = 10,
+}
+
+#[contracttype]
+enum DataKey {
+    Mode,
+    Balance(Address),
+    Allowance(Address, Address),
+}
+
+/// A SEP-41 token whose transfer behaviour can be switched at runtime
+#[contract]
+pub struct MockToken;
+
+#[contractimpl]
+impl MockToken {
+    pub fn set_mode(env: Env, mode: Mode) {
+        env.storage().instance().set(&DataKey::Mode, &mode);
+    }
+
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Balance(to), &(balance + amount));
+    }
+
+    pub fn decimals(_env: Env) -> u32 {
+        7
+    }
+
+    pub fn symbol(env: Env) -> String {
+        String::from_str(&env, "MOCK")
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Balance(id))
+            .unwrap_or(0)
+    }
+
+    pub fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Allowance(from, spender))
+            .unwrap_or(0)
+    }
+
+    pub fn approve(env: Env, from: Address, spender: Address, amount: i128, _expiration: u32) {
+        from.require_auth();
+        env.storage()
+            .persistent()
+            .set(&DataKey::Allowance(from, spender), &amount);
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        Self::move_funds(&env, &from, &to, amount);
+    }
+
+    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        let allowance = Self::allowance(env.clone(), from.clone(), spender.clone());
+        if allowance < amount {
+            panic_with_error!(&env, MockTokenError::Allowance);
+        }
+        env.storage().persistent().set(
+            &DataKey::Allowance(from.clone(), spender),
+            &(allowance - amount),
+        );
+        Self::move_funds(&env, &from, &to, amount);
+    }
+}
+
+impl MockToken {
+    fn move_funds(env: &Env, from: &Address, to: &Address, amount: i128) {
+        let mode = env
+            .storage()
+            .instance()
+            .get(&DataKey::Mode)
+            .unwrap_or(Mode::Normal);
+        let credited = match mode {
+            Mode::Normal => amount,
+            Mode::FeeOnTransfer => amount - amount / 100,
+            Mode::Rebasing => amount + amount / 100,
+            Mode::NoOp => return,
+            Mode::RejectBalance => panic_with_error!(env, MockTokenError::Balance),
+            Mode::RejectAllowance => panic_with_error!(env, MockTokenError::Allowance),
+            Mode::Panic => panic!("mock token failure"),
+        };
+
+        let from_balance = Self::balance(env.clone(), from.clone());
+        if from_balance < amount {
+            panic_with_error!(env, MockTokenError::Balance);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Balance(from.clone()), &(from_balance - amount));
+        Self::mint(env.clone(), to.clone(), credited);
+    }
+}
+
+/// Helper function to create a two-member group that uses the mock token
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    MockTokenClient<'static>,
+    u64,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let token_id = env.register_contract(None, MockToken);
+    let token = MockTokenClient::new(&env, &token_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.add_supported_token(&token_id, &1i128, &i128::MAX);
+
+    let creator = Address::generate(&env);
+    let member = Address::generate(&env);
+    for addr in [&creator, &member] {
+        token.mint(addr, &1_000_000_000_000);
+    }
+
+    let group_id = client.create_group(
+        &creator,
+        &token_id,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&member, &group_id, &1u32);
+
+    (env, client, token, group_id, creator, member)
+}
+
+#[test]
+fn test_well_behaved_token_transfers() {
+    let (env, client, token, group_id, creator, member) = setup_test_env();
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member, &group_id);
+    assert_eq!(token.balance(&client.address), CONTRIBUTION * 2);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 86400 + 1;
+    });
+    let balance_before = token.balance(&creator);
+    client.execute_payout(&group_id);
+    assert_eq!(token.balance(&creator), balance_before + CONTRIBUTION * 2);
+}
+
+#[test]
+fn test_fee_on_transfer_token_rejected() {
+    let (_env, client, token, group_id, creator, _member) = setup_test_env();
+    token.set_mode(&Mode::FeeOnTransfer);
+    let balance_before = token.balance(&creator);
+
+    let result = client.try_contribute(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::TransferFailed)));
+
+    // The failed call leaves no trace
+    assert_eq!(token.balance(&creator), balance_before);
+    assert_eq!(token.balance(&client.address), 0);
+    assert!(
+        !client
+            .get_contribution_progress(&group_id, &creator)
+            .contributed
+    );
+}
+
+#[test]
+fn test_rebasing_token_rejected() {
+    let (_env, client, token, group_id, creator, _member) = setup_test_env();
+    token.set_mode(&Mode::Rebasing);
+
+    let result = client.try_contribute_amount(&creator, &group_id, &CONTRIBUTION);
+    assert_eq!(result, Err(Ok(AjoError::TransferFailed)));
+}
+
+#[test]
+fn test_silent_noop_transfer_rejected() {
+    let (_env, client, token, group_id, creator, _member) = setup_test_env();
+    token.set_mode(&Mode::NoOp);
+
+    let result = client.try_contribute(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::TransferFailed)));
+}
+
+#[test]
+fn test_token_errors_are_mapped() {
+    let (_env, client, token, group_id, creator, _member) = setup_test_env();
+
+    token.set_mode(&Mode::RejectBalance);
+    let result = client.try_contribute(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::InsufficientBalance)));
+
+    token.set_mode(&Mode::RejectAllowance);
+    let result = client.try_contribute(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::InsufficientAllowance)));
+
+    token.set_mode(&Mode::Panic);
+    let result = client.try_contribute(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::TransferFailed)));
+
+    token.set_mode(&Mode::Normal);
+    client.contribute(&creator, &group_id);
+}
+
+#[test]
+fn test_payout_rejected_when_token_starts_charging_fees() {
+    let (env, client, token, group_id, creator, member) = setup_test_env();
+    client.contribute(&creator, &group_id);
+    client.contribute(&member, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 86400 + 1;
+    });
+
+    token.set_mode(&Mode::FeeOnTransfer);
+    let result = client.try_execute_payout(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::TransferFailed)));
+    assert_eq!(client.get_group(&group_id).payout_index, 0);
+    assert_eq!(token.balance(&client.address), CONTRIBUTION * 2);
+}
+
+#[test]
+fn test_auto_debit_with_fee_on_transfer_token_aborts() {
+    let (env, client, token, group_id, creator, _member) = setup_test_env();
+    client.enable_auto_debit(&creator, &group_id);
+    let expiration = env.ledger().sequence() + 1_000;
+    token.approve(&creator, &client.address, &CONTRIBUTION, &expiration);
+
+    token.set_mode(&Mode::FeeOnTransfer);
+    let result = client.try_collect_due(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::TransferFailed)));
+
+    token.set_mode(&Mode::Normal);
+    let results = client.collect_due(&group_id);
+    assert_eq!(results.len(), 1);
+    assert!(
+        client
+            .get_contribution_progress(&group_id, &creator)
+            .contributed
+    );
+}
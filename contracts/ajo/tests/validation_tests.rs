@@ -1,30 +1,42 @@
 #![cfg(test)]
 
-use soroban_ajo::{AjoContract, AjoContractClient, AjoError};
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, CycleSchedule};
 use soroban_sdk::{testutils::Address as _, Address, Env};
 
-fn setup_test() -> (Env, AjoContractClient<'static>, Address) {
+fn setup_test() -> (Env, AjoContractClient<'static>, Address, Address) {
     let env = Env::default();
     env.mock_all_auths();
 
     let contract_id = env.register_contract(None, AjoContract);
     let client = AjoContractClient::new(&env, &contract_id);
     let creator = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
 
-    (env, client, creator)
+    // The contract only accepts tokens registered by the admin
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+
+    (env, client, creator, token)
 }
 
 #[test]
 fn test_invalid_contribution_amount_zero() {
-    let (_env, client, creator) = setup_test();
+    let (_env, client, creator, token) = setup_test();
 
     let result = client.try_create_group(
         &creator,
-        &0,     // Invalid: zero contribution
-        &86400, // 1 day
+        &token,
+        &0,                           // Invalid: zero contribution
+        &CycleSchedule::Fixed(86400), // 1 day
         &5,
         &86400u64,
         &5u32,
+        &0u32,
+        &0u32,
     );
 
     assert_eq!(result, Err(Ok(AjoError::ContributionAmountZero)));
@@ -32,15 +44,18 @@ fn test_invalid_contribution_amount_zero() {
 
 #[test]
 fn test_invalid_contribution_amount_negative() {
-    let (_env, client, creator) = setup_test();
+    let (_env, client, creator, token) = setup_test();
 
     let result = client.try_create_group(
         &creator,
+        &token,
         &-100, // Invalid: negative contribution
-        &86400,
+        &CycleSchedule::Fixed(86400),
         &5,
         &86400u64,
         &5u32,
+        &0u32,
+        &0u32,
     );
 
     assert_eq!(result, Err(Ok(AjoError::ContributionAmountNegative)));
@@ -48,15 +63,18 @@ fn test_invalid_contribution_amount_negative() {
 
 #[test]
 fn test_invalid_cycle_duration_zero() {
-    let (_env, client, creator) = setup_test();
+    let (_env, client, creator, token) = setup_test();
 
     let result = client.try_create_group(
         &creator,
+        &token,
         &1000,
-        &0, // Invalid: zero duration
+        &CycleSchedule::Fixed(0), // Invalid: zero duration
         &5,
         &86400u64,
         &5u32,
+        &0u32,
+        &0u32,
     );
 
     assert_eq!(result, Err(Ok(AjoError::CycleDurationZero)));
@@ -64,15 +82,18 @@ fn test_invalid_cycle_duration_zero() {
 
 #[test]
 fn test_max_members_below_minimum() {
-    let (_env, client, creator) = setup_test();
+    let (_env, client, creator, token) = setup_test();
 
     let result = client.try_create_group(
         &creator,
+        &token,
         &1000,
-        &86400,
+        &CycleSchedule::Fixed(86400),
         &1, // Invalid: only 1 member (need at least 2)
         &86400u64,
         &5u32,
+        &0u32,
+        &0u32,
     );
 
     assert_eq!(result, Err(Ok(AjoError::MaxMembersBelowMinimum)));
@@ -80,15 +101,18 @@ fn test_max_members_below_minimum() {
 
 #[test]
 fn test_max_members_above_limit() {
-    let (_env, client, creator) = setup_test();
+    let (_env, client, creator, token) = setup_test();
 
     let result = client.try_create_group(
         &creator,
+        &token,
         &1000,
-        &86400,
+        &CycleSchedule::Fixed(86400),
         &101, // Invalid: exceeds limit of 100
         &86400u64,
         &5u32,
+        &0u32,
+        &0u32,
     );
 
     assert_eq!(result, Err(Ok(AjoError::MaxMembersAboveLimit)));
@@ -96,35 +120,94 @@ fn test_max_members_above_limit() {
 
 #[test]
 fn test_max_members_exceeded_on_join() {
-    let (_env, client, creator) = setup_test();
+    let (_env, client, creator, token) = setup_test();
 
     // Create group with max 2 members
-    let group_id = client.create_group(&creator, &1000, &86400, &2, &86400u64, &5u32);
+    let group_id = client.create_group(
+        &creator,
+        &token,
+        &1000,
+        &CycleSchedule::Fixed(86400),
+        &2,
+        &3600u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
 
     // Second member joins successfully
     let member2 = Address::generate(&_env);
-    client.join_group(&member2, &group_id);
+    client.join_group(&member2, &group_id, &1u32);
 
     // Third member tries to join - should fail
     let member3 = Address::generate(&_env);
-    let result = client.try_join_group(&member3, &group_id);
+    let result = client.try_join_group(&member3, &group_id, &1u32);
 
     assert_eq!(result, Err(Ok(AjoError::MaxMembersExceeded)));
 }
 
 #[test]
 fn test_valid_group_creation() {
-    let (_env, client, creator) = setup_test();
+    let (_env, client, creator, token) = setup_test();
 
     // All valid parameters
     let result = client.try_create_group(
         &creator,
-        &1000,  // Valid: positive amount
-        &86400, // Valid: positive duration
-        &5,     // Valid: between 2 and 100
+        &token,
+        &1000,                        // Valid: positive amount
+        &CycleSchedule::Fixed(86400), // Valid: positive duration
+        &5,                           // Valid: between 2 and 100
+        &3600u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_grace_period_must_be_shorter_than_cycle() {
+    let (_env, client, creator, token) = setup_test();
+
+    // A grace period as long as the cycle would skip every other window
+    let result = client.try_create_group(
+        &creator,
+        &token,
+        &1000,
+        &CycleSchedule::Fixed(86400),
+        &5,
         &86400u64,
         &5u32,
+        &0u32,
+        &0u32,
     );
+    assert_eq!(result, Err(Ok(AjoError::InvalidGracePeriod)));
 
+    // Within the configured maximum but longer than an hourly cycle
+    let result = client.try_create_group(
+        &creator,
+        &token,
+        &1000,
+        &CycleSchedule::Fixed(3600),
+        &5,
+        &7200u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    assert_eq!(result, Err(Ok(AjoError::InvalidGracePeriod)));
+
+    let result = client.try_create_group(
+        &creator,
+        &token,
+        &1000,
+        &CycleSchedule::Fixed(86400),
+        &5,
+        &86399u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
     assert!(result.is_ok());
 }
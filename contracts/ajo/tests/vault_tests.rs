@@ -0,0 +1,434 @@
+#![cfg(test)]
+
+//! Tests for parking idle group funds in a vault and sharing the yield
+
+use soroban_ajo::{
+    AjoContract, AjoContractClient, AjoError, CycleSchedule, PokeAction, VaultInterface,
+    YieldPolicy,
+};
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    vec, Address, Env,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+
+#[contracttype]
+enum DataKey {
+    Token,
+    Holding(Address),
+}
+
+/// A vault that holds deposits as-is and credits yield when told to
+#[contract]
+pub struct MockVault;
+
+#[contractimpl]
+impl MockVault {
+    pub fn init(env: Env, token: Address) {
+        env.storage().instance().set(&DataKey::Token, &token);
+    }
+
+    /// Credit `amount` of yield to `id` (the tokens must be minted to the vault)
+    pub fn accrue(env: Env, id: Address, amount: i128) {
+        let holding = Self::balance(env.clone(), id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Holding(id), &(holding + amount));
+    }
+
+    /// Write down `amount` of what is held for `id`
+    pub fn lose(env: Env, id: Address, amount: i128) {
+        let holding = Self::balance(env.clone(), id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Holding(id), &(holding - amount));
+    }
+}
+
+#[contractimpl]
+impl VaultInterface for MockVault {
+    fn deposit(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        let token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        TokenClient::new(&env, &token).transfer(&from, &env.current_contract_address(), &amount);
+        Self::accrue(env, from, amount);
+    }
+
+    fn withdraw(env: Env, to: Address, amount: i128) {
+        to.require_auth();
+        let holding = Self::balance(env.clone(), to.clone());
+        if holding < amount {
+            panic!("insufficient holding");
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Holding(to.clone()), &(holding - amount));
+        let token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        TokenClient::new(&env, &token).transfer(&env.current_contract_address(), &to, &amount);
+    }
+
+    fn balance(env: Env, id: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Holding(id))
+            .unwrap_or(0)
+    }
+}
+
+/// Helper function to create a contract, a registered token and an approved vault
+fn setup_test_env() -> (
+    Env,
+    AjoContractClient<'static>,
+    Address,
+    MockVaultClient<'static>,
+) {
+    let env = Env::default();
+    // The vault pulls deposits from the contract in a nested call
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    client.add_supported_token(&token, &1i128, &i128::MAX);
+
+    let vault_id = env.register_contract(None, MockVault);
+    let vault = MockVaultClient::new(&env, &vault_id);
+    vault.init(&token);
+    client.set_vault_approved(&vault_id, &true);
+
+    (env, client, token, vault)
+}
+
+/// Helper function to create a two-member group that uses the vault
+fn setup_group(
+    env: &Env,
+    client: &AjoContractClient,
+    token: &Address,
+    vault: &Address,
+    yield_policy: YieldPolicy,
+) -> (u64, Address, Address) {
+    let creator = Address::generate(env);
+    let member = Address::generate(env);
+    for addr in [&creator, &member] {
+        StellarAssetClient::new(env, token).mint(addr, &1_000_000_000_000);
+    }
+
+    let group_id = client.create_group(
+        &creator,
+        token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(604_800u64),
+        &2u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    client.join_group(&member, &group_id, &1u32);
+    client.set_group_vault(&creator, &group_id, &Some(vault.clone()), &yield_policy);
+
+    (group_id, creator, member)
+}
+
+/// Helper function to have every member contribute and park the pot
+fn contribute_and_park(client: &AjoContractClient, group_id: u64, members: [&Address; 2]) {
+    for member in members {
+        client.contribute(member, &group_id);
+    }
+    assert_eq!(client.park_funds(&group_id), CONTRIBUTION * 2);
+}
+
+/// Helper function to credit yield to the contract's vault position
+fn accrue_yield(
+    env: &Env,
+    client: &AjoContractClient,
+    token: &Address,
+    vault: &MockVaultClient,
+    amount: i128,
+) {
+    StellarAssetClient::new(env, token).mint(&vault.address, &amount);
+    vault.accrue(&client.address, &amount);
+}
+
+/// Helper function to move past the current cycle's grace period
+fn advance_past_grace(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 86400 + 1;
+    });
+}
+
+#[test]
+fn test_parked_funds_recalled_with_yield_for_recipient() {
+    let (env, client, token, vault) = setup_test_env();
+    let token_client = TokenClient::new(&env, &token);
+    let (group_id, creator, member) = setup_group(
+        &env,
+        &client,
+        &token,
+        &vault.address,
+        YieldPolicy::Recipient,
+    );
+
+    contribute_and_park(&client, group_id, [&creator, &member]);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(token_client.balance(&vault.address), CONTRIBUTION * 2);
+    assert_eq!(client.park_funds(&group_id), 0);
+
+    let position = client.get_group_vault(&group_id).unwrap();
+    assert_eq!(position.principal, CONTRIBUTION * 2);
+
+    accrue_yield(&env, &client, &token, &vault, 3_000_000);
+    advance_past_grace(&env);
+    let balance_before = token_client.balance(&creator);
+    client.execute_payout(&group_id);
+
+    assert_eq!(
+        token_client.balance(&creator),
+        balance_before + CONTRIBUTION * 2 + 3_000_000
+    );
+    let position = client.get_group_vault(&group_id).unwrap();
+    assert_eq!(position.principal, 0);
+    assert_eq!(position.shares, 0);
+}
+
+#[test]
+fn test_member_yield_shared_when_rotation_ends() {
+    let (env, client, token, vault) = setup_test_env();
+    let token_client = TokenClient::new(&env, &token);
+    let (group_id, creator, member) =
+        setup_group(&env, &client, &token, &vault.address, YieldPolicy::Members);
+    let balance_before = token_client.balance(&member);
+
+    contribute_and_park(&client, group_id, [&creator, &member]);
+    accrue_yield(&env, &client, &token, &vault, 1_000_001);
+    advance_past_grace(&env);
+    client.execute_payout(&group_id);
+    assert_eq!(
+        client.get_group_vault(&group_id).unwrap().member_yield,
+        1_000_001
+    );
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member, &group_id);
+    advance_past_grace(&env);
+    client.execute_payout(&group_id);
+
+    // Each seat gets half, and the rounding dust goes to the insurance pool
+    assert!(client.is_complete(&group_id));
+    assert_eq!(token_client.balance(&member), balance_before + 500_000);
+    assert_eq!(client.get_group_vault(&group_id).unwrap().member_yield, 0);
+    assert_eq!(client.get_insurance_pool(&token).balance, 1);
+}
+
+#[test]
+fn test_insurance_policy_funds_pool() {
+    let (env, client, token, vault) = setup_test_env();
+    let (group_id, creator, member) = setup_group(
+        &env,
+        &client,
+        &token,
+        &vault.address,
+        YieldPolicy::Insurance,
+    );
+
+    contribute_and_park(&client, group_id, [&creator, &member]);
+    accrue_yield(&env, &client, &token, &vault, 2_000_000);
+    advance_past_grace(&env);
+    client.execute_payout(&group_id);
+
+    assert_eq!(client.get_insurance_pool(&token).balance, 2_000_000);
+}
+
+#[test]
+fn test_shared_vault_attributes_yield_per_group() {
+    let (env, client, token, vault) = setup_test_env();
+    let token_client = TokenClient::new(&env, &token);
+    let (first, creator1, member1) = setup_group(
+        &env,
+        &client,
+        &token,
+        &vault.address,
+        YieldPolicy::Recipient,
+    );
+    contribute_and_park(&client, first, [&creator1, &member1]);
+
+    // Yield earned before the second group arrives belongs to the first, which
+    // then holds two thirds of the shares
+    accrue_yield(&env, &client, &token, &vault, CONTRIBUTION * 2);
+    let (second, creator2, member2) = setup_group(
+        &env,
+        &client,
+        &token,
+        &vault.address,
+        YieldPolicy::Recipient,
+    );
+    contribute_and_park(&client, second, [&creator2, &member2]);
+    accrue_yield(&env, &client, &token, &vault, 3_000_000);
+
+    advance_past_grace(&env);
+    let balance_before = token_client.balance(&creator2);
+    client.execute_payout(&second);
+    assert_eq!(
+        token_client.balance(&creator2),
+        balance_before + CONTRIBUTION * 2 + 1_000_000
+    );
+
+    let balance_before = token_client.balance(&creator1);
+    client.execute_payout(&first);
+    assert_eq!(
+        token_client.balance(&creator1),
+        balance_before + CONTRIBUTION * 4 + 2_000_000
+    );
+}
+
+#[test]
+fn test_payout_fails_when_vault_cannot_return_principal() {
+    let (env, client, token, vault) = setup_test_env();
+    let (group_id, creator, member) = setup_group(
+        &env,
+        &client,
+        &token,
+        &vault.address,
+        YieldPolicy::Recipient,
+    );
+
+    contribute_and_park(&client, group_id, [&creator, &member]);
+    vault.lose(&client.address, &1);
+    advance_past_grace(&env);
+
+    let result = client.try_execute_payout(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::TransferFailed)));
+    assert_eq!(client.get_group(&group_id).payout_index, 0);
+    assert_eq!(
+        client.get_group_vault(&group_id).unwrap().principal,
+        CONTRIBUTION * 2
+    );
+}
+
+#[test]
+fn test_cancel_recalls_parked_funds() {
+    let (env, client, token, vault) = setup_test_env();
+    let token_client = TokenClient::new(&env, &token);
+    let (group_id, creator, member) = setup_group(
+        &env,
+        &client,
+        &token,
+        &vault.address,
+        YieldPolicy::Recipient,
+    );
+    let balance_before = token_client.balance(&member);
+
+    contribute_and_park(&client, group_id, [&creator, &member]);
+    accrue_yield(&env, &client, &token, &vault, 1_000_000);
+    client.cancel_group(&creator, &group_id);
+
+    // With no payout to receive it, the yield is shared across the members
+    assert_eq!(
+        client.get_refund_record(&group_id, &member).amount,
+        CONTRIBUTION
+    );
+    assert_eq!(token_client.balance(&member), balance_before + 500_000);
+    assert_eq!(token_client.balance(&vault.address), 0);
+}
+
+#[test]
+fn test_set_group_vault_requires_organizer_and_approved_vault() {
+    let (env, client, token, vault) = setup_test_env();
+    let (group_id, creator, member) = setup_group(
+        &env,
+        &client,
+        &token,
+        &vault.address,
+        YieldPolicy::Recipient,
+    );
+
+    let result = client.try_set_group_vault(
+        &member,
+        &group_id,
+        &Some(vault.address.clone()),
+        &YieldPolicy::Members,
+    );
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+
+    let unapproved = env.register_contract(None, MockVault);
+    assert!(!client.is_vault_approved(&unapproved));
+    let result = client.try_set_group_vault(
+        &creator,
+        &group_id,
+        &Some(unapproved),
+        &YieldPolicy::Members,
+    );
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+
+    // Clearing the vault recalls what was parked
+    contribute_and_park(&client, group_id, [&creator, &member]);
+    client.set_group_vault(&creator, &group_id, &None, &YieldPolicy::Recipient);
+    assert_eq!(client.get_group_vault(&group_id), None);
+    assert_eq!(
+        TokenClient::new(&env, &token).balance(&client.address),
+        CONTRIBUTION * 2
+    );
+}
+
+#[test]
+fn test_member_yield_paid_in_batches_for_large_groups() {
+    let (env, client, token, vault) = setup_test_env();
+    let token_client = TokenClient::new(&env, &token);
+    let members: std::vec::Vec<Address> = (0..7).map(|_| Address::generate(&env)).collect();
+    for member in &members {
+        StellarAssetClient::new(&env, &token).mint(member, &1_000_000_000_000);
+    }
+    let group_id = client.create_group(
+        &members[0],
+        &token,
+        &CONTRIBUTION,
+        &CycleSchedule::Fixed(604_800u64),
+        &7u32,
+        &86400u64,
+        &5u32,
+        &0u32,
+        &0u32,
+    );
+    for member in &members[1..] {
+        client.join_group(member, &group_id, &1u32);
+    }
+    client.set_group_vault(
+        &members[0],
+        &group_id,
+        &Some(vault.address.clone()),
+        &YieldPolicy::Members,
+    );
+    for member in &members {
+        client.contribute(member, &group_id);
+    }
+    client.park_funds(&group_id);
+    accrue_yield(&env, &client, &token, &vault, 7_000_000);
+    let balances: std::vec::Vec<i128> = members.iter().map(|m| token_client.balance(m)).collect();
+
+    // Dropping the vault pays the first five members straight away
+    client.set_group_vault(&members[0], &group_id, &None, &YieldPolicy::Members);
+    for (member, balance) in members.iter().zip(&balances).take(5) {
+        assert_eq!(token_client.balance(member), balance + 1_000_000);
+    }
+    for (member, balance) in members.iter().zip(&balances).skip(5) {
+        assert_eq!(token_client.balance(member), *balance);
+    }
+
+    // A poke pays the rest and does nothing else
+    let result = client.poke(&members[0], &group_id);
+    assert_eq!(result.actions, vec![&env, PokeAction::YieldDistributed(2)]);
+    for (member, balance) in members.iter().zip(&balances) {
+        assert_eq!(token_client.balance(member), balance + 1_000_000);
+    }
+    assert!(client.poke(&members[0], &group_id).actions.is_empty());
+}